@@ -16,12 +16,12 @@ pub use rslint_errors::{
 };
 
 use colored::*;
-use rslint_core::{autofix::recursively_apply_fixes, File};
+use rslint_core::{autofix::fix_file, File};
 use rslint_core::{lint_file, util::find_best_match_for_name, LintResult, RuleLevel};
 use rslint_lexer::Lexer;
 #[allow(unused_imports)]
 use std::process;
-use std::{fs::write, path::PathBuf};
+use std::{collections::HashMap, fs::write, path::PathBuf};
 use yastl::Pool;
 
 #[allow(unused_must_use, unused_variables)]
@@ -116,7 +116,11 @@ fn run_inner(
     let mut results = rx.into_iter().collect::<Vec<_>>();
 
     let fix_count = if fix {
-        apply_fixes(&mut results, &mut walker, dirty)
+        let counts = apply_fixes(&mut results, &mut walker, dirty);
+        if let Some(summary) = fix_summary(&counts) {
+            lint_note!("{}", summary);
+        }
+        counts.values().sum()
     } else {
         0
     };
@@ -135,8 +139,13 @@ fn run_inner(
     }
 }
 
-pub fn apply_fixes(results: &mut Vec<LintResult>, walker: &mut FileWalker, dirty: bool) -> usize {
-    let mut fix_count = 0;
+/// Apply the fixes of every result and return the amount of fixes each rule contributed.
+pub fn apply_fixes(
+    results: &mut Vec<LintResult>,
+    walker: &mut FileWalker,
+    dirty: bool,
+) -> HashMap<&'static str, usize> {
+    let mut fix_counts = HashMap::new();
     // TODO: should we aquire a file lock if we know we need to run autofix?
     for res in results {
         let file = walker.files.get_mut(&res.file_id).unwrap();
@@ -144,40 +153,53 @@ pub fn apply_fixes(results: &mut Vec<LintResult>, walker: &mut FileWalker, dirty
         if file.path.is_none() {
             continue;
         }
-        if res
-            .parser_diagnostics
-            .iter()
-            .any(|x| x.severity == Severity::Error)
-            && !dirty
-        {
+        if res.has_unfixable_syntax_errors() && !dirty {
             lint_note!(
                 "skipping autofix for `{}` because it contains syntax errors",
                 file.path.as_ref().unwrap().to_string_lossy()
             );
             continue;
         }
-        let original_problem_num = res
-            .rule_results
-            .iter()
-            .filter(|(_, x)| x.outcome() == Outcome::Warning || x.outcome() == Outcome::Failure)
-            .map(|(_, res)| res.diagnostics.len())
-            .sum::<usize>();
-        let fixed = recursively_apply_fixes(res, file);
-        let new_problem_num = res
-            .rule_results
-            .iter()
-            .filter(|(_, x)| x.outcome() == Outcome::Warning || x.outcome() == Outcome::Failure)
-            .map(|(_, res)| res.diagnostics.len())
-            .sum::<usize>();
+        let counts = fix_file(res, file);
+        let fixed = res.fixed_code.clone().unwrap_or_default();
         let path = file.path.as_ref().unwrap();
         if let Err(err) = write(path, fixed.clone()) {
             lint_err!("failed to write to `{:#?}`: {}", path, err.to_string());
         } else {
             file.update_src(fixed);
-            fix_count += original_problem_num.saturating_sub(new_problem_num);
+            for (rule, count) in counts {
+                *fix_counts.entry(rule).or_insert(0) += count;
+            }
         }
     }
-    fix_count
+    fix_counts
+}
+
+/// Describe the amount of fixes each rule contributed, such as `applied 3 fixes from no-extra-semi, 1 from eqeqeq`.
+pub fn fix_summary(counts: &HashMap<&'static str, usize>) -> Option<String> {
+    let mut counts = counts
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .collect::<Vec<_>>();
+    if counts.is_empty() {
+        return None;
+    }
+    counts.sort_by(|(a_rule, a), (b_rule, b)| b.cmp(a).then(a_rule.cmp(b_rule)));
+
+    let mut summary = String::from("applied ");
+    for (idx, (rule, count)) in counts.iter().enumerate() {
+        if idx == 0 {
+            summary.push_str(&format!(
+                "{} fix{} from {}",
+                count,
+                if **count == 1 { "" } else { "es" },
+                rule
+            ));
+        } else {
+            summary.push_str(&format!(", {} from {}", count, rule));
+        }
+    }
+    Some(summary)
 }
 
 pub fn dump_ast(globs: Vec<String>) {
@@ -404,14 +426,7 @@ fn output_overall(failures: usize, warnings: usize, successes: usize, fix_count:
 /// this leaves warnings untouched because rules should be able to emit errors and warnings for context without
 /// the warnings being remapped to errors.
 pub fn remap_diagnostics_to_level(diagnostics: &mut Vec<Diagnostic>, level: RuleLevel) {
-    for diagnostic in diagnostics.iter_mut() {
-        match diagnostic.severity {
-            Severity::Error if level == RuleLevel::Warning => {
-                diagnostic.severity = Severity::Warning
-            }
-            _ => {}
-        }
-    }
+    level.remap(diagnostics);
 }
 
 pub fn emit_diagnostic(diagnostic: &Diagnostic, walker: &dyn file::Files) {
@@ -4,6 +4,10 @@
 #![allow(clippy::field_reassign_with_default)]
 
 mod de;
+mod validate;
+
+pub use validate::{validate_config, ConfigDiagnostic};
+
 use dirs_next::config_dir;
 use rslint_core::{get_group_rules_by_name, CstRule, CstRuleStore, Diagnostic, RuleLevel};
 use rslint_errors::file::{Files, SimpleFile};
@@ -292,6 +296,15 @@ impl Config {
         };
 
         let mut warnings = vec![];
+        let allowed = rule_cfg
+            .allowed
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>();
+        for issue in CstRuleStore::new().builtins().validate_names(&allowed) {
+            warnings.push(ConfigDiagnostic::RuleName(issue).to_diagnostic());
+        }
+
         let rules = unique_rules(rule_cfg.errors.clone(), rule_cfg.warnings.clone());
         let mut rules = self
             .intersect_allowed(rules, &mut warnings)
@@ -303,8 +316,7 @@ impl Config {
                 let list = list.collect::<Vec<_>>();
                 rules = unique_rules(rules, list).collect();
             } else {
-                let d = Diagnostic::warning(1, "config", format!("unknown rule group '{}'", group));
-                warnings.push(d);
+                warnings.push(ConfigDiagnostic::UnknownGroup(group.clone()).to_diagnostic());
             }
         }
 
@@ -331,15 +343,8 @@ impl Config {
                 .any(|allowed| allowed == rule.name());
 
             if res {
-                let d = Diagnostic::warning(
-                    1,
-                    "config",
-                    format!(
-                        "ignoring configuration for '{}' because it is explicitly allowed",
-                        rule.name()
-                    ),
-                );
-                warnings.push(d)
+                let issue = ConfigDiagnostic::AllowedAndConfigured(rule.name().to_string());
+                warnings.push(issue.to_diagnostic())
             }
 
             !res
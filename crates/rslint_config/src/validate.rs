@@ -0,0 +1,206 @@
+//! Validation of a whole configuration.
+
+use crate::Config;
+use rslint_core::{
+    collect_directive_rules, get_group_rules_by_name, CstRuleStore, Diagnostic, File, NameIssue,
+};
+use std::fmt;
+
+/// An issue with a configuration found by [`validate_config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigDiagnostic {
+    /// A rule in `allowed` is unknown or deprecated.
+    RuleName(NameIssue),
+    /// A group in `groups` does not exist.
+    UnknownGroup(String),
+    /// A rule is configured in both `errors` and `warnings`, its `warnings` config is ignored.
+    ConflictingLevels(String),
+    /// A rule is configured in `errors` or `warnings` but it is also allowed, its config is ignored.
+    AllowedAndConfigured(String),
+    /// A directive in a file refers to a rule which is not enabled by the config.
+    DisabledRuleDirective {
+        /// The id of the file the directive is in.
+        file_id: usize,
+        /// The name of the file the directive is in.
+        file_name: String,
+        /// The name of the rule.
+        rule: String,
+    },
+}
+
+impl ConfigDiagnostic {
+    /// Convert this issue to a warning, issues with directives refer to the file of the directive
+    /// and the rest refer to the config file.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let file_id = match self {
+            ConfigDiagnostic::DisabledRuleDirective { file_id, .. } => *file_id,
+            _ => 1,
+        };
+        Diagnostic::warning(file_id, "config", self.to_string())
+    }
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigDiagnostic::RuleName(NameIssue::Unknown {
+                name,
+                suggestion: Some(suggestion),
+            }) => write!(
+                f,
+                "unknown allowed rule '{}', did you mean '{}'?",
+                name, suggestion
+            ),
+            ConfigDiagnostic::RuleName(NameIssue::Unknown { name, .. }) => {
+                write!(f, "unknown allowed rule '{}'", name)
+            }
+            ConfigDiagnostic::RuleName(NameIssue::Deprecated { name, replacement }) => write!(
+                f,
+                "allowed rule '{}' is deprecated, use '{}' instead",
+                name, replacement
+            ),
+            ConfigDiagnostic::UnknownGroup(group) => write!(f, "unknown rule group '{}'", group),
+            ConfigDiagnostic::ConflictingLevels(rule) => write!(
+                f,
+                "'{}' is configured as both an error and a warning, ignoring its warning configuration",
+                rule
+            ),
+            ConfigDiagnostic::AllowedAndConfigured(rule) => write!(
+                f,
+                "ignoring configuration for '{}' because it is explicitly allowed",
+                rule
+            ),
+            ConfigDiagnostic::DisabledRuleDirective {
+                file_name, rule, ..
+            } => write!(
+                f,
+                "a directive in '{}' refers to '{}', but the rule is not enabled",
+                file_name, rule
+            ),
+        }
+    }
+}
+
+/// Check a configuration and the directives of the files it is used for, and return every issue found.
+///
+/// The config is checked for unknown or deprecated allowed rules, unknown groups, and rules with
+/// conflicting configurations. Each file is checked for directives which refer to a rule which is
+/// not enabled by the config, since those directives have no effect.
+pub fn validate_config(config: &Config, files: &[File]) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = vec![];
+
+    if let Some(rules) = &config.repr.rules {
+        let allowed = rules
+            .allowed
+            .iter()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+        diagnostics.extend(
+            CstRuleStore::new()
+                .builtins()
+                .validate_names(&allowed)
+                .into_iter()
+                .map(ConfigDiagnostic::RuleName),
+        );
+
+        for rule in &rules.warnings {
+            if rules.errors.iter().any(|error| error.name() == rule.name()) {
+                diagnostics.push(ConfigDiagnostic::ConflictingLevels(rule.name().to_string()));
+            }
+        }
+        for rule in rules.errors.iter().chain(&rules.warnings) {
+            if allowed.contains(&rule.name()) {
+                diagnostics.push(ConfigDiagnostic::AllowedAndConfigured(
+                    rule.name().to_string(),
+                ));
+            }
+        }
+        for group in &rules.groups {
+            if get_group_rules_by_name(group).is_none() {
+                diagnostics.push(ConfigDiagnostic::UnknownGroup(group.clone()));
+            }
+        }
+    }
+
+    let (store, _) = config.rules_store();
+    for file in files {
+        for rule in collect_directive_rules(file, &store) {
+            if store.get(&rule).is_none() {
+                diagnostics.push(ConfigDiagnostic::DisabledRuleDirective {
+                    file_id: file.id,
+                    file_name: file.name.clone(),
+                    rule,
+                });
+            }
+        }
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigRepr;
+    use rslint_parser::FileKind;
+
+    fn config(source: &str) -> Config {
+        Config {
+            repr: toml::from_str::<ConfigRepr>(source).unwrap(),
+        }
+    }
+
+    #[test]
+    fn validate_config_reports_unknown_rules() {
+        let config = config(
+            r#"
+            [rules]
+            groups = ["errors", "foo"]
+            allowed = ["no-empy"]
+            "#,
+        );
+        assert_eq!(
+            validate_config(&config, &[]),
+            vec![
+                ConfigDiagnostic::RuleName(NameIssue::Unknown {
+                    name: "no-empy".to_string(),
+                    suggestion: Some("no-empty"),
+                }),
+                ConfigDiagnostic::UnknownGroup("foo".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_config_reports_conflicts_and_disabled_directives() {
+        let config = config(
+            r#"
+            [rules]
+            allowed = ["no-debugger"]
+
+            [rules.errors]
+            no-empty = {}
+            no-debugger = {}
+
+            [rules.warnings]
+            no-empty = {}
+            "#,
+        );
+        let file = File::from_string(
+            "// rslint-ignore no-empty, no-self-compare\n{}",
+            FileKind::Module,
+            "test.mjs",
+        );
+        assert_eq!(
+            validate_config(&config, std::slice::from_ref(&file)),
+            vec![
+                ConfigDiagnostic::ConflictingLevels("no-empty".to_string()),
+                ConfigDiagnostic::AllowedAndConfigured("no-debugger".to_string()),
+                ConfigDiagnostic::DisabledRuleDirective {
+                    file_id: file.id,
+                    file_name: "test.mjs".to_string(),
+                    rule: "no-self-compare".to_string(),
+                },
+            ]
+        );
+    }
+}
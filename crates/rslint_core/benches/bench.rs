@@ -1,7 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
-use rslint_core::{CstRuleStore, File};
+use rslint_core::{run_rule, run_rules, CstRuleStore, File};
 use rslint_lexer::Lexer;
-use rslint_parser::parse_text;
+use rslint_parser::{parse_module, parse_text};
+use std::sync::Arc;
 
 const ENGINE_262_URL: &str = "https://engine262.js.org/engine262/engine262.js";
 
@@ -36,5 +37,48 @@ fn engine262(c: &mut Criterion) {
     bench_source(c, &file);
 }
 
-criterion_group!(benches, engine262);
+const GENERATED_CHUNK: &str = r#"
+function foo(a, b) {
+    if (a == b) { return a + b; } else if (typeof a === "string") { a = a.trim(); }
+    for (let i = 0; i < a.length; i++) { const x = a[i] * 2; b.push(x, { key: x, [i]: "v" }); }
+    try { bar(a?.b?.c, ...b); } catch (e) { console.log(`err ${e}`); }
+    switch (a) { case 1: break; case 2: return 3; default: throw new Error("x"); }
+    return class extends Base { method() { return this.x; } };
+}
+"#;
+
+/// Compare running every builtin rule with its own traversal of the tree against
+/// running all of them in a single traversal.
+fn rule_traversal(c: &mut Criterion) {
+    let source = GENERATED_CHUNK.repeat(500);
+    let root = parse_module(&source, 0).syntax();
+    let src: Arc<str> = Arc::from(source.as_str());
+    let store = CstRuleStore::new().builtins();
+
+    let mut group = c.benchmark_group("rule_traversal");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("run_rule", |b| {
+        b.iter(|| {
+            for rule in &store.rules {
+                black_box(run_rule(&**rule, 0, root.clone(), false, &[], src.clone()));
+            }
+        })
+    });
+    group.bench_function("run_rules", |b| {
+        b.iter(|| {
+            black_box(run_rules(
+                &store.rules,
+                0,
+                root.clone(),
+                false,
+                &[],
+                src.clone(),
+            ))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, rule_traversal, engine262);
 criterion_main!(benches);
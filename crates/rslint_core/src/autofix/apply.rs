@@ -1,10 +1,14 @@
+use super::{attach_parser_fixes, parser_fix};
 use crate::{lint_file_inner, File, LintResult, RuleResult};
 use rslint_parser::*;
 use rslint_text_edit::{apply_indels, Indel};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub const MAX_FIX_ITERATIONS: usize = 10;
 
+/// The tag of the fixes for recoverable parser errors, which is not a valid rule name.
+const PARSER_TAG: &str = "<parser>";
+
 #[derive(Debug, Clone)]
 struct TaggedIndel {
     tag: &'static str,
@@ -31,13 +35,46 @@ fn get_runnable_indels(mut tagged: Vec<TaggedIndel>) -> Vec<TaggedIndel> {
 }
 
 pub fn recursively_apply_fixes(result: &mut LintResult, file: &File) -> String {
+    apply_fixes(result, file, &mut HashMap::new())
+}
+
+/// Recursively apply fixes like [`recursively_apply_fixes`], storing the fixed code in
+/// [`LintResult::fixed_code`] and returning the amount of fixes each rule contributed.
+///
+/// The amount of fixes of a rule is the amount of its diagnostics which were resolved by
+/// applying its fixes. Rules without any applied fixes and fixes for syntax errors are not included.
+pub fn fix_file(result: &mut LintResult, file: &File) -> HashMap<&'static str, usize> {
+    let mut counts = HashMap::new();
+    let fixed = apply_fixes(result, file, &mut counts);
+    result.fixed_code = Some(fixed);
+    counts
+}
+
+fn apply_fixes(
+    result: &mut LintResult,
+    file: &File,
+    counts: &mut HashMap<&'static str, usize>,
+) -> String {
+    // fixes are counted by the diagnostics they resolve, which must include the collapsed ones
+    result.expand_collapsed_rules();
     let script = result.parsed.kind() == SyntaxKind::SCRIPT;
     let mut parsed = result.parsed.clone();
     let file_id = result.file_id;
     let mut cur_results = result.rule_results.clone();
 
     for _ in 0..=MAX_FIX_ITERATIONS {
-        let indels = get_runnable_indels(rule_results_to_tagged_indels(&cur_results));
+        let mut tagged = rule_results_to_tagged_indels(&cur_results);
+        tagged.extend(
+            result
+                .parser_diagnostics
+                .iter()
+                .filter_map(parser_fix)
+                .map(|indel| TaggedIndel {
+                    tag: PARSER_TAG,
+                    indel,
+                }),
+        );
+        let indels = get_runnable_indels(tagged);
 
         if indels.is_empty() {
             break;
@@ -57,12 +94,31 @@ pub fn recursively_apply_fixes(result: &mut LintResult, file: &File) -> String {
             result.parser_diagnostics = res.errors().to_owned();
             res.syntax()
         };
+        attach_parser_fixes(&mut result.parser_diagnostics, &string);
 
         // TODO: should we panic on Err? autofix causing the linter to fail should always be incorrect
-        let res = lint_file_inner(parsed.clone(), vec![], file, result.store, result.verbose);
+        let res = lint_file_inner(
+            parsed.clone(),
+            vec![],
+            file,
+            result.store,
+            result.verbose,
+            true,
+            false,
+        );
+        for tag in indels.iter().map(|x| x.tag).collect::<HashSet<_>>() {
+            let diagnostics = |results: &HashMap<&str, RuleResult>| {
+                results.get(tag).map_or(0, |res| res.diagnostics.len())
+            };
+            let fixed = diagnostics(&cur_results).saturating_sub(diagnostics(&res.rule_results));
+            if tag != PARSER_TAG && fixed > 0 {
+                *counts.entry(tag).or_insert(0) += fixed;
+            }
+        }
         cur_results = res.rule_results;
     }
     result.rule_results = cur_results;
+    result.collapse_noisy_rules();
     parsed.text().to_string()
 }
 
@@ -80,3 +136,51 @@ fn rule_results_to_tagged_indels(results: &HashMap<&'static str, RuleResult>) ->
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::NoExtraSemi;
+    use crate::groups::style::NoUnneededComputedMember;
+    use crate::{lint_file, CstRule, CstRuleStore};
+    use rslint_parser::FileKind;
+
+    #[test]
+    fn fix_file_counts_fixes_per_rule() {
+        let file = File::from_string(
+            "foo['bar'];;\nfoo['baz'];\nfoo['qux'];\n;",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoExtraSemi::default()) as Box<dyn CstRule>,
+            Box::new(NoUnneededComputedMember::default()),
+        ]);
+        let mut result = lint_file(&file, &store, false);
+        let counts = fix_file(&mut result, &file);
+
+        assert_eq!(
+            result.fixed_code.as_deref(),
+            Some("foo.bar;\nfoo.baz;\nfoo.qux;\n")
+        );
+        assert_eq!(counts.get("no-extra-semi"), Some(&2));
+        assert_eq!(counts.get("no-unneeded-computed-member"), Some(&3));
+        assert_eq!(counts.len(), 2);
+    }
+
+    #[test]
+    fn fix_file_counts_collapsed_diagnostics() {
+        let file = File::from_string(";;;;;\nfoo;", FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoExtraSemi::default()) as Box<dyn CstRule>]);
+        let store = store.with_collapse_threshold(2);
+        let mut result = lint_file(&file, &store, false);
+        assert_eq!(result.diagnostics().count(), 3);
+
+        let counts = fix_file(&mut result, &file);
+        assert_eq!(result.fixed_code.as_deref(), Some("\nfoo;"));
+        assert_eq!(counts.get("no-extra-semi"), Some(&5));
+        assert_eq!(result.diagnostics().count(), 0);
+    }
+}
@@ -1,6 +1,7 @@
 //! Automatic rule fixing utilities
 
 mod apply;
+mod parser;
 
 use crate::{Span, SyntaxKind};
 use rslint_lexer::{Lexer, Token};
@@ -10,7 +11,8 @@ use rslint_text_edit::Indel;
 use std::borrow::Borrow;
 use std::sync::Arc;
 
-pub use apply::{recursively_apply_fixes, MAX_FIX_ITERATIONS};
+pub use apply::{fix_file, recursively_apply_fixes, MAX_FIX_ITERATIONS};
+pub use parser::{attach_parser_fixes, parser_fix, PARSER_FIX_MSG};
 
 /// A simple interface for applying changes to source code
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -164,6 +166,13 @@ impl Fixer {
         }
         self
     }
+
+    /// Whether an edit overlaps with any of the edits of this fixer.
+    pub fn overlaps(&self, indel: &Indel) -> bool {
+        self.indels.iter().any(|other| {
+            other.delete.start() < indel.delete.end() && indel.delete.start() < other.delete.end()
+        })
+    }
 }
 
 /// The different kinds of chars something could be wrapped inside of
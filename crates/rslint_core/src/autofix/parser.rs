@@ -0,0 +1,72 @@
+//! Fixes for recoverable syntax errors emitted by the parser.
+
+use crate::{Diagnostic, Span};
+use rslint_errors::{Applicability, SuggestionChange};
+use rslint_lexer::Lexer;
+use rslint_parser::{syntax::stmt::MISSING_SEMICOLON, SyntaxKind, T};
+use rslint_text_edit::Indel;
+
+/// The message of the suggestions attached by [`attach_parser_fixes`].
+pub const PARSER_FIX_MSG: &str = "insert a semicolon";
+
+/// Attach a fix suggestion to every parser diagnostic which has an obvious and safe fix.
+///
+/// Currently only missing semicolons are fixed, by inserting a semicolon after the last
+/// token of the statement. Diagnostics which already have a fix are left untouched.
+///
+/// A missing semicolon inside of parentheses or brackets is usually caused by an earlier
+/// error, such as a missing comma in `foo(a b)`, so it is only fixed if it is the only error.
+pub fn attach_parser_fixes(diagnostics: &mut [Diagnostic], src: &str) {
+    let only_error = diagnostics.len() == 1;
+    for diagnostic in diagnostics.iter_mut() {
+        if diagnostic.code.as_deref() != Some(MISSING_SEMICOLON) || parser_fix(diagnostic).is_some()
+        {
+            continue;
+        }
+        let start = match &diagnostic.primary {
+            Some(primary) if primary.span.range.start <= src.len() => primary.span.range.start,
+            _ => continue,
+        };
+        // the primary label is on the token after the statement, the semicolon goes before any whitespace
+        let offset = src[..start].trim_end().len();
+        if !only_error && !is_statement_level(&src[..offset]) {
+            continue;
+        }
+        *diagnostic = diagnostic.clone().suggestion(
+            offset..offset,
+            PARSER_FIX_MSG,
+            ";",
+            Applicability::Always,
+        );
+    }
+}
+
+/// Get the fix attached to a parser diagnostic by [`attach_parser_fixes`].
+pub fn parser_fix(diagnostic: &Diagnostic) -> Option<Indel> {
+    let suggestion = diagnostic
+        .suggestions
+        .iter()
+        .find(|suggestion| suggestion.msg == PARSER_FIX_MSG)?;
+    match &suggestion.substitution {
+        SuggestionChange::String(string) => Some(Indel::replace(
+            suggestion.span.range.as_text_range(),
+            string.to_owned(),
+        )),
+        SuggestionChange::Indels(_) => None,
+    }
+}
+
+/// Whether the end of some source code is outside of any unclosed delimiter other than braces.
+fn is_statement_level(src: &str) -> bool {
+    let mut delimiters = vec![];
+    for (token, _) in Lexer::from_str(src, 0) {
+        match token.kind {
+            T!['('] | T!['['] | T!['{'] | SyntaxKind::DOLLARCURLY => delimiters.push(token.kind),
+            T![')'] | T![']'] | T!['}'] => {
+                delimiters.pop();
+            }
+            _ => {}
+        }
+    }
+    delimiters.last().filter(|kind| **kind != T!['{']).is_none()
+}
@@ -0,0 +1,200 @@
+//! Editor quick fixes (code actions) computed from the result of linting a file.
+
+use crate::directives::DECLARATOR;
+use crate::{Diagnostic, LintResult, Span};
+use rslint_errors::SuggestionChange;
+use rslint_lexer::is_linebreak;
+use rslint_parser::{SyntaxKind, TextSize};
+use rslint_text_edit::Indel;
+
+/// The kind of a [`CodeAction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeActionKind {
+    /// An action which fixes the issue, either from a suggestion or from the rule's autofix.
+    QuickFix,
+    /// An action which inserts an ignore directive for the rule which emitted the diagnostic.
+    DisableRule,
+}
+
+/// An action which can be applied to the source code to deal with a diagnostic,
+/// such as a quick fix in an editor.
+#[derive(Debug, Clone)]
+pub struct CodeAction<'a> {
+    /// A short human readable title for the action.
+    pub title: String,
+    pub kind: CodeActionKind,
+    /// The diagnostic this action is for.
+    pub diagnostic: &'a Diagnostic,
+    /// The edits to apply to the source code, the ranges are relative to the original source code.
+    pub edits: Vec<Indel>,
+}
+
+impl LintResult<'_> {
+    /// Get the code actions for every diagnostic emitted by rules.
+    ///
+    /// Each diagnostic gets an action for each of its suggestions and an action which ignores the rule
+    /// for the line the diagnostic starts on. The first diagnostic of a rule with fixes also gets an
+    /// action with all of the rule's autofix edits. The actions are in the same order as the rules
+    /// in [`LintResult::diagnostics`].
+    pub fn code_actions(&self) -> Vec<CodeAction<'_>> {
+        let src = self.parsed.to_string();
+        let mut actions = vec![];
+
+        for rule in self.ordered_rule_names() {
+            let result = &self.rule_results[rule];
+            for (idx, diagnostic) in result.diagnostics.iter().enumerate() {
+                for suggestion in &diagnostic.suggestions {
+                    let edits = match &suggestion.substitution {
+                        SuggestionChange::Indels(indels) => indels.clone(),
+                        SuggestionChange::String(string) => vec![Indel::replace(
+                            suggestion.span.range.as_text_range(),
+                            string.to_owned(),
+                        )],
+                    };
+                    actions.push(CodeAction {
+                        title: suggestion.msg.to_owned(),
+                        kind: CodeActionKind::QuickFix,
+                        diagnostic,
+                        edits,
+                    });
+                }
+
+                if let Some(fixer) = result
+                    .fixer
+                    .as_ref()
+                    .filter(|f| idx == 0 && !f.indels.is_empty())
+                {
+                    actions.push(CodeAction {
+                        title: format!("fix all `{}` problems", rule),
+                        kind: CodeActionKind::QuickFix,
+                        diagnostic,
+                        edits: fixer.indels.clone(),
+                    });
+                }
+
+                if let Some(action) = self.ignore_action(rule, diagnostic, &src) {
+                    actions.push(action);
+                }
+            }
+        }
+        actions
+    }
+
+    /// An action which inserts an `rslint-ignore-next-line` comment above the line the diagnostic starts on.
+    fn ignore_action<'a>(
+        &self,
+        rule: &str,
+        diagnostic: &'a Diagnostic,
+        src: &str,
+    ) -> Option<CodeAction<'a>> {
+        // labels of nodes may include the line break before them
+        let start = diagnostic.primary.as_ref()?.span.range.start;
+        let rest = src.get(start..)?;
+        let offset = start + (rest.len() - rest.trim_start().len());
+        let line_start = src
+            .get(..offset)?
+            .char_indices()
+            .rev()
+            .find(|(_, c)| is_linebreak(*c))
+            .map_or(0, |(idx, c)| idx + c.len_utf8());
+        let indent = src[line_start..]
+            .chars()
+            .take_while(|c| c.is_whitespace() && !is_linebreak(*c))
+            .collect::<String>();
+
+        // a comment cannot be inserted inside of a token which spans multiple lines, such as a template literal
+        let code_start = TextSize::from((line_start + indent.len()) as u32);
+        if let Some(token) = self.parsed.token_at_offset(code_start).right_biased() {
+            if token.text_range().start() < code_start && token.kind() != SyntaxKind::WHITESPACE {
+                return None;
+            }
+        }
+
+        Some(CodeAction {
+            title: format!("ignore `{}` for this line", rule),
+            kind: CodeActionKind::DisableRule,
+            diagnostic,
+            edits: vec![Indel::insert(
+                TextSize::from(line_start as u32),
+                format!("{}// {}ignore-next-line {}\n", indent, DECLARATOR, rule),
+            )],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::{NoDebugger, NoEmpty};
+    use crate::{lint_file, CstRule, CstRuleStore, File};
+    use rslint_parser::FileKind;
+
+    fn ignore_fixed(source: &str) -> String {
+        let file = File::from_string(source, FileKind::Module, "foo.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoEmpty::default()) as Box<dyn CstRule>]);
+        let result = lint_file(&file, &store, false);
+
+        let actions = result.code_actions();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].kind, CodeActionKind::DisableRule);
+        assert_eq!(actions[0].title, "ignore `no-empty` for this line");
+
+        let mut fixed = file.source.to_string();
+        rslint_text_edit::apply_indels(&actions[0].edits, &mut fixed);
+        let relinted = File::from_string(fixed.clone(), FileKind::Module, "foo.mjs");
+        assert!(lint_file(&relinted, &store, false)
+            .diagnostics()
+            .next()
+            .is_none());
+        fixed
+    }
+
+    #[test]
+    fn code_actions_ignore_line() {
+        assert_eq!(
+            ignore_fixed("foo();\nif (a) {\n    {}\n}"),
+            "foo();\nif (a) {\n    // rslint-ignore-next-line no-empty\n    {}\n}"
+        );
+        assert_eq!(
+            ignore_fixed("{}\nfoo();"),
+            "// rslint-ignore-next-line no-empty\n{}\nfoo();"
+        );
+    }
+
+    #[test]
+    fn code_actions_ignore_line_with_code_before_the_statement() {
+        assert_eq!(
+            ignore_fixed("foo(); {}\nbar();"),
+            "// rslint-ignore-next-line no-empty\nfoo(); {}\nbar();"
+        );
+    }
+
+    #[test]
+    fn code_actions_fix_all_once_per_rule_in_rule_order() {
+        let file = File::from_string("{}\ndebugger;\ndebugger;", FileKind::Module, "foo.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoDebugger::default()) as Box<dyn CstRule>,
+            Box::new(NoEmpty::default()),
+        ]);
+        let result = lint_file(&file, &store, false);
+
+        let actions = result
+            .code_actions()
+            .into_iter()
+            .map(|action| action.title)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                "apply the automatic fix",
+                "fix all `no-debugger` problems",
+                "ignore `no-debugger` for this line",
+                "apply the automatic fix",
+                "ignore `no-debugger` for this line",
+                "ignore `no-empty` for this line",
+            ]
+        );
+    }
+}
@@ -16,13 +16,17 @@ pub(self) mod lexer;
 
 mod commands;
 mod parser;
+mod suppressions;
 
 pub use self::commands::*;
 pub use self::parser::*;
+pub use self::suppressions::*;
 
-use crate::{rule_tests, CstRule, CstRuleStore, Diagnostic, SyntaxNode};
+use crate::{rule_tests, CstRule, CstRuleStore, Diagnostic, File, SyntaxNode};
 use rslint_lexer::SyntaxKind;
 use rslint_parser::{util::*, SmolStr, TextRange, TextSize};
+use std::collections::BTreeSet;
+use std::sync::Arc;
 
 // TODO: More complex warnings, things like ignoring node directives because of file level directives
 
@@ -240,22 +244,85 @@ pub fn apply_node_directives(
 }
 
 pub fn skip_node(directives: &[Directive], node: &SyntaxNode, rule: &dyn CstRule) -> bool {
-    if let Some(comment) = node.first_token().and_then(|t| t.comment()) {
-        if let Some(directive) = directives.iter().find(|dir| dir.comment == comment) {
-            match &directive.command {
-                Some(Command::IgnoreNode(_)) => {
-                    return true;
-                }
-                Some(Command::IgnoreNodeRules(_, rules)) => {
-                    if rules.iter().any(|allowed| allowed.name() == rule.name()) {
-                        return true;
-                    }
+    match node_directive(directives, node) {
+        Some(directive) => directive_skips_rule(directive, rule),
+        None => false,
+    }
+}
+
+/// Get the directive attached to the first token of a node, if any.
+pub(crate) fn node_directive<'a>(
+    directives: &'a [Directive],
+    node: &SyntaxNode,
+) -> Option<&'a Directive> {
+    if directives.is_empty() {
+        return None;
+    }
+    let comment = node.first_token().and_then(|t| t.comment())?;
+    directives.iter().find(|dir| dir.comment == comment)
+}
+
+/// Whether a directive attached to a node makes a rule skip the node.
+pub(crate) fn directive_skips_rule(directive: &Directive, rule: &dyn CstRule) -> bool {
+    match &directive.command {
+        Some(Command::IgnoreNode(_)) => true,
+        Some(Command::IgnoreNodeRules(_, rules)) => {
+            rules.iter().any(|allowed| allowed.name() == rule.name())
+        }
+        _ => false,
+    }
+}
+
+/// Whether a comment is a directive using one of `prefixes`, such as `// rslint-ignore`.
+pub fn is_directive_comment(comment: &Comment, prefixes: &[impl AsRef<str>]) -> bool {
+    strip_declarator(comment.content.trim_start(), prefixes).is_some()
+}
+
+/// The directive prefixes of a rule context which is not run with a store.
+pub(crate) fn default_directive_prefixes() -> Arc<[String]> {
+    Arc::from(vec![DEFAULT_PREFIX.to_string()])
+}
+
+/// Collect the names of every rule referenced by the directives and line suppressions of a file
+/// which use one of the directive prefixes of `store`.
+///
+/// Directives are parsed like they are when linting, but their rules are looked up in every rule
+/// instead of `store`, which allows checking which rules a file refers to but `store` does not run.
+/// The rules of line suppressions are not validated, just like when linting.
+pub fn collect_directive_rules(file: &File, store: &CstRuleStore) -> BTreeSet<String> {
+    let root = file.parse();
+    let prefixes = store.directive_prefixes();
+    let directives = DirectiveParser::new(root.clone(), file)
+        .with_prefixes(prefixes.clone())
+        .get_file_directives()
+        .directives;
+
+    fn collect(components: &[Component], rules: &mut BTreeSet<String>) {
+        for component in components {
+            match &component.kind {
+                ComponentKind::Rule(rule) => {
+                    rules.insert(rule.name().to_string());
                 }
+                ComponentKind::Repetition(components) => collect(components, rules),
                 _ => {}
             }
         }
     }
-    false
+
+    let mut rules = BTreeSet::new();
+    for directive in &directives {
+        collect(&directive.components, &mut rules);
+    }
+    let comments = root
+        .descendants_with_tokens()
+        .filter_map(|elem| elem.into_token())
+        .filter(|token| token.kind() == SyntaxKind::COMMENT);
+    for comment in comments {
+        if let Some((_, Some(suppressed))) = line_suppression(&comment, &prefixes) {
+            rules.extend(suppressed);
+        }
+    }
+    rules
 }
 
 rule_tests! {
@@ -285,3 +352,87 @@ rule_tests! {
         "
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directives_resolve_deprecated_rule_names() {
+        let source = "
+        // rslint-ignore require_yield
+        function* foo() { return 1; }
+
+        function* bar() { return 1; } // rslint-ignore require_yield
+
+        // rslint-ignore-next-line require_yield
+        function* baz() { return 1; }
+        ";
+        let file = File::from_string(source, rslint_parser::FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(crate::groups::errors::RequireYield::default()) as Box<dyn CstRule>,
+        ]);
+
+        let undirected = File::from_string(
+            source.replace("require_yield", "foo"),
+            rslint_parser::FileKind::Module,
+            "test.mjs",
+        );
+        assert_eq!(
+            crate::lint_file(&undirected, &store, false)
+                .diagnostics_for("require-yield")
+                .len(),
+            3
+        );
+        let result = crate::lint_file(&file, &store, false);
+        assert_eq!(result.diagnostics().count(), 0);
+        assert!(result.directive_diagnostics.is_empty());
+        assert_eq!(
+            collect_directive_rules(&file, &store)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["require-yield"]
+        );
+    }
+
+    #[test]
+    fn collect_rules_from_directives() {
+        let source = "
+        // rslint-ignore no-empty, for-direction
+        {}
+
+        /* rslint-disable no-debugger */
+        debugger;
+        // rslint-enable
+
+        // not a directive no-cond-assign
+        // rslint-ignore no-await-in-loop until eof -- because no-this-before-super
+        // rslint-foo no-extra-semi
+        foo; // rslint-ignore no-sparse-arrays
+        // rslint-ignore-next-line no-self-compare
+        // eslint-ignore-next-line no-unsafe-finally
+        ";
+        let file = File::from_string(source, rslint_parser::FileKind::Module, "test.mjs");
+        let names = |store: &CstRuleStore| {
+            collect_directive_rules(&file, store)
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        let rules = vec![
+            "for-direction",
+            "no-await-in-loop",
+            "no-debugger",
+            "no-empty",
+            "no-self-compare",
+            "no-sparse-arrays",
+        ];
+        assert_eq!(names(&CstRuleStore::new()), rules);
+
+        let store = CstRuleStore::new().with_directive_prefix("eslint");
+        let mut rules = rules;
+        rules.push("no-unsafe-finally");
+        assert_eq!(names(&store), rules);
+    }
+}
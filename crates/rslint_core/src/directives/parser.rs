@@ -4,7 +4,7 @@ use super::{
     commands::Command,
     get_command_descriptors,
     lexer::{format_kind, Lexer, Token},
-    CommandDescriptor, Component, ComponentKind, Directive, Instruction,
+    line_suppression, CommandDescriptor, Component, ComponentKind, Directive, Instruction,
 };
 use rslint_errors::{file::line_starts, Diagnostic};
 use rslint_lexer::{SyntaxKind, T};
@@ -16,6 +16,19 @@ use std::ops::Range;
 /// A string that denotes that start of a directive (`rslint-`).
 pub const DECLARATOR: &str = "rslint-";
 
+/// The prefix of directives which is always honored, directives start with a prefix followed by `-`.
+pub const DEFAULT_PREFIX: &str = "rslint";
+
+/// Strip one of `prefixes` followed by `-` from the start of the content of a comment.
+pub(crate) fn strip_declarator<'a>(
+    content: &'a str,
+    prefixes: &[impl AsRef<str>],
+) -> Option<&'a str> {
+    prefixes
+        .iter()
+        .find_map(|prefix| content.strip_prefix(prefix.as_ref())?.strip_prefix('-'))
+}
+
 pub type Result<T, E = DirectiveError> = std::result::Result<T, E>;
 
 /// The result of a parsed directive.
@@ -72,6 +85,7 @@ pub struct DirectiveParser<'store, 'file> {
     store: Option<&'store CstRuleStore>,
     commands: Box<[CommandDescriptor]>,
     no_rewind: bool,
+    prefixes: Vec<&'store str>,
 }
 
 impl<'store, 'file> DirectiveParser<'store, 'file> {
@@ -100,9 +114,13 @@ impl<'store, 'file> DirectiveParser<'store, 'file> {
             SyntaxKind::SCRIPT | SyntaxKind::MODULE
         ));
 
+        let store = store.into();
         Self {
             line_starts: line_starts(&root.to_string()).collect(),
-            store: store.into(),
+            prefixes: store
+                .map(|store| store.directive_prefixes())
+                .unwrap_or_else(|| vec![DEFAULT_PREFIX]),
+            store,
             root,
             file,
             no_rewind: false,
@@ -110,6 +128,12 @@ impl<'store, 'file> DirectiveParser<'store, 'file> {
         }
     }
 
+    /// Recognize directives starting with one of `prefixes` instead of the prefixes of the store.
+    pub fn with_prefixes(mut self, prefixes: Vec<&'store str>) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
     fn err(&self, msg: &str) -> Diagnostic {
         Diagnostic::error(self.file.id, "directives", msg)
     }
@@ -123,15 +147,21 @@ impl<'store, 'file> DirectiveParser<'store, 'file> {
     pub fn get_file_directives(&mut self) -> DirectiveResult {
         let top_level = self.top_level_directives();
         let mut result = DirectiveResult::default();
+        let prefixes = self.prefixes.clone();
 
         for descendant in self.root.descendants().skip(1) {
             let comment = descendant
                 .first_token()
                 .and_then(|tok| tok.comment())
-                .filter(|c| c.content.trim_start().starts_with(DECLARATOR));
+                .filter(|c| strip_declarator(c.content.trim_start(), &prefixes).is_some());
 
             let comment = match comment {
-                Some(comment) if comment.token.parent().is::<ModuleItem>() => comment,
+                Some(comment)
+                    if comment.token.parent().is::<ModuleItem>()
+                        && line_suppression(&comment.token, &prefixes).is_none() =>
+                {
+                    comment
+                }
                 _ => continue,
             };
 
@@ -144,11 +174,15 @@ impl<'store, 'file> DirectiveParser<'store, 'file> {
 
     pub fn top_level_directives(&mut self) -> DirectiveResult {
         let mut result = DirectiveResult::default();
+        let prefixes = self.prefixes.clone();
 
         self.root
             .children_with_tokens()
             .flat_map(|item| item.into_token()?.comment())
-            .filter(|comment| comment.content.trim_start().starts_with(DECLARATOR))
+            .filter(|comment| {
+                strip_declarator(comment.content.trim_start(), &prefixes).is_some()
+                    && line_suppression(&comment.token, &prefixes).is_none()
+            })
             .map(|comment| self.parse_directive(comment, None, true))
             .for_each(|res| result.extend(res));
 
@@ -162,11 +196,7 @@ impl<'store, 'file> DirectiveParser<'store, 'file> {
         node: Option<SyntaxNode>,
         top_level: bool,
     ) -> Result<Directive> {
-        let text = comment
-            .content
-            .trim_start()
-            .strip_prefix(DECLARATOR)
-            .unwrap();
+        let text = strip_declarator(comment.content.trim_start(), &self.prefixes).unwrap();
 
         let decl_offset = comment.content.len() - text.len();
         let offset = usize::from(comment.token.text_range().start()) + decl_offset + 1;
@@ -319,7 +349,7 @@ impl<'store, 'file> DirectiveParser<'store, 'file> {
 
                 let end = lexer.abs_cur() as u32;
                 let name_range = TextRange::new(start, end.into());
-                let name = lexer.source_range(name_range);
+                let name = crate::store::current_rule_name(lexer.source_range(name_range));
 
                 let rule = self
                     .store
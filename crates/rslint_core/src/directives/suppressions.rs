@@ -0,0 +1,285 @@
+//! Line based suppressions, which ignore rules on a single line instead of a node.
+//!
+//! ```text
+//! foo; // rslint-ignore no-extra-semi
+//! // rslint-ignore-next-line no-empty, no-debugger
+//! ```
+//!
+//! An `rslint-ignore` comment only suppresses its own line if there is code before it on the line,
+//! otherwise it is a regular directive which ignores rules on the node after it.
+
+use super::{strip_declarator, DEFAULT_PREFIX};
+use crate::store::current_rule_name;
+use crate::{rule_tests, RuleResult};
+use rslint_lexer::is_linebreak;
+use rslint_parser::{SyntaxKind, SyntaxNode, SyntaxToken};
+use std::collections::HashMap;
+
+const IGNORE: &str = "ignore";
+const IGNORE_NEXT_LINE: &str = "ignore-next-line";
+
+/// The rules which are suppressed on each line of a file by line suppression comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineSuppressions {
+    /// The suppressed rules of each zero based line, `None` if every rule is suppressed.
+    lines: HashMap<usize, Option<Vec<String>>>,
+    line_starts: Vec<usize>,
+    text: String,
+}
+
+impl LineSuppressions {
+    /// Collect the line suppressions of every comment in a syntax tree.
+    pub fn new(root: &SyntaxNode) -> Self {
+        Self::with_prefixes(root, &[DEFAULT_PREFIX])
+    }
+
+    /// Collect the line suppressions of every comment in a syntax tree which start with one of `prefixes`.
+    pub fn with_prefixes(root: &SyntaxNode, prefixes: &[&str]) -> Self {
+        let text = root.to_string();
+        let mut chars = text.char_indices().peekable();
+        let mut line_starts = vec![0];
+        while let Some((idx, c)) = chars.next() {
+            // `\r\n` is a single line break
+            if is_linebreak(c) && !(c == '\r' && chars.peek().map(|(_, c)| *c) == Some('\n')) {
+                line_starts.push(idx + c.len_utf8());
+            }
+        }
+        let mut suppressions = Self {
+            lines: HashMap::new(),
+            line_starts,
+            text,
+        };
+
+        let comments = root
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .filter(|token| token.kind() == SyntaxKind::COMMENT);
+        for comment in comments {
+            let (next_line, rules) = match line_suppression(&comment, prefixes) {
+                Some(suppression) => suppression,
+                None => continue,
+            };
+            let line =
+                suppressions.line_of(comment.text_range().start().into()) + next_line as usize;
+            let entry = suppressions
+                .lines
+                .entry(line)
+                .or_insert_with(|| Some(vec![]));
+            match (entry, rules) {
+                (Some(suppressed), Some(rules)) => suppressed.extend(rules),
+                (entry, _) => *entry = None,
+            }
+        }
+        suppressions
+    }
+
+    /// Whether there are no suppressed lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Whether a rule is suppressed on the line of a byte offset.
+    pub fn is_suppressed(&self, offset: usize, rule: &str) -> bool {
+        match self.lines.get(&self.line_of(offset)) {
+            Some(Some(rules)) => rules.iter().any(|suppressed| suppressed == rule),
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// Remove the diagnostics of a rule whose primary label starts on a suppressed line, as well as
+    /// the fixes which start on a suppressed line.
+    ///
+    /// Whitespace at the start of a primary label is ignored, since labels of nodes may include the
+    /// line break before them.
+    pub fn apply(&self, rule: &str, result: &mut RuleResult) {
+        if self.is_empty() {
+            return;
+        }
+        result.diagnostics.retain(|diagnostic| {
+            diagnostic
+                .primary
+                .as_ref()
+                .filter(|primary| {
+                    self.is_suppressed(self.skip_whitespace(primary.span.range.start), rule)
+                })
+                .is_none()
+        });
+        if let Some(fixer) = result.fixer.as_mut() {
+            fixer
+                .indels
+                .retain(|indel| !self.is_suppressed(indel.delete.start().into(), rule));
+        }
+    }
+
+    fn skip_whitespace(&self, offset: usize) -> usize {
+        let rest = self.text.get(offset..).unwrap_or_default();
+        offset + (rest.len() - rest.trim_start().len())
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        }
+    }
+}
+
+/// Get whether a comment suppresses rules on the next line instead of its own line, and the rules it
+/// suppresses, `None` meaning every rule. Returns `None` if the comment is not a line suppression.
+pub(crate) fn line_suppression(
+    comment: &SyntaxToken,
+    prefixes: &[&str],
+) -> Option<(bool, Option<Vec<String>>)> {
+    let content = strip_declarator(comment.text().strip_prefix("//")?.trim_start(), prefixes)?;
+    let (next_line, rest) = match content.strip_prefix(IGNORE_NEXT_LINE) {
+        Some(rest) => (true, rest),
+        None if is_trailing(comment) => (false, content.strip_prefix(IGNORE)?),
+        None => return None,
+    };
+    if rest.chars().next().filter(|c| !c.is_whitespace()).is_some() {
+        return None;
+    }
+
+    let rules = rest
+        .split("--")
+        .next()
+        .unwrap_or_default()
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|rule| !rule.is_empty())
+        .map(|rule| current_rule_name(rule).to_string())
+        .collect::<Vec<_>>();
+    Some((next_line, Some(rules).filter(|rules| !rules.is_empty())))
+}
+
+/// Whether there is code before a comment on its line.
+fn is_trailing(comment: &SyntaxToken) -> bool {
+    let mut prev = comment.prev_token();
+    while let Some(token) = prev {
+        match token.kind() {
+            SyntaxKind::WHITESPACE | SyntaxKind::COMMENT
+                if token.text().chars().any(is_linebreak) =>
+            {
+                return false
+            }
+            SyntaxKind::WHITESPACE | SyntaxKind::COMMENT => prev = token.prev_token(),
+            _ => return true,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::{NoDebugger, NoEmpty};
+    use crate::{lint_file, CstRule, CstRuleStore, File};
+    use rslint_parser::{parse_module, FileKind};
+
+    #[test]
+    fn collects_suppressed_lines() {
+        let source = "
+foo; // rslint-ignore no-empty, no-debugger -- reasons
+// rslint-ignore-next-line
+bar;
+// rslint-ignore no-empty
+baz; // rslint-ignored no-empty
+";
+        let suppressions = LineSuppressions::new(&parse_module(source, 0).syntax());
+        let line = |idx: usize| source.split('\n').take(idx).map(|l| l.len() + 1).sum();
+
+        assert!(suppressions.is_suppressed(line(1), "no-empty"));
+        assert!(suppressions.is_suppressed(line(1) + 3, "no-debugger"));
+        assert!(!suppressions.is_suppressed(line(1), "reasons"));
+        assert!(!suppressions.is_suppressed(line(2), "no-empty"));
+        assert!(suppressions.is_suppressed(line(3), "anything"));
+        assert!(!suppressions.is_suppressed(line(5), "no-empty"));
+        assert!(!suppressions.is_suppressed(line(6), "no-empty"));
+    }
+
+    #[test]
+    fn line_suppressions_use_every_line_break() {
+        let source = "foo;\r\n// rslint-ignore-next-line\rbar;\u{2028}baz; // rslint-ignore\nqux;";
+        let suppressions = LineSuppressions::new(&parse_module(source, 0).syntax());
+        let offset = |text: &str| source.find(text).unwrap();
+
+        assert!(!suppressions.is_suppressed(offset("foo"), "no-empty"));
+        assert!(suppressions.is_suppressed(offset("bar"), "no-empty"));
+        assert!(suppressions.is_suppressed(offset("baz"), "no-empty"));
+        assert!(!suppressions.is_suppressed(offset("qux"), "no-empty"));
+    }
+
+    #[test]
+    fn next_line_suppressions_at_the_start_of_a_file() {
+        let file = File::from_string(
+            "// rslint-ignore-next-line no-empty\n{}\n{}",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoEmpty::default()) as Box<dyn CstRule>]);
+        let result = lint_file(&file, &store, false);
+
+        let lines = result
+            .diagnostics()
+            .map(|d| d.primary.as_ref().unwrap().span.range.start)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![39]);
+    }
+
+    #[test]
+    fn lint_file_filters_suppressed_lines() {
+        let file = File::from_string(
+            "{} debugger; // rslint-ignore no-empty\n// rslint-ignore-next-line\n{} debugger;\n{}",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoEmpty::default()) as Box<dyn CstRule>,
+            Box::new(NoDebugger::default()),
+        ]);
+        let result = lint_file(&file, &store, false);
+
+        let codes = result
+            .diagnostics()
+            .map(|d| d.code.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["no-empty", "no-debugger"]);
+        assert!(result.directive_diagnostics.is_empty());
+    }
+}
+
+rule_tests! {
+    crate::groups::errors::NoDuplicateCases::default(),
+    err: {
+        "
+        switch (a) {
+            case 1: break;
+            case 1: break; // rslint-ignore no-empty
+        }
+        ",
+        "
+        // rslint-ignore-next-line no-duplicate-cases
+        switch (a) {
+            case 1: break;
+            case 1: break;
+        }
+        "
+    },
+    ok: {
+        "
+        switch (a) {
+            case 1: break;
+            case 1: break; // rslint-ignore no-duplicate-cases
+        }
+        ",
+        "
+        switch (a) {
+            case 1: break;
+            // rslint-ignore-next-line
+            case 1: break;
+        }
+        "
+    }
+}
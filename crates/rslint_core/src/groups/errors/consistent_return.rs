@@ -0,0 +1,295 @@
+use crate::rule_prelude::*;
+use ast::{BlockStmt, Expr, Stmt, SwitchCase, UnaryOp};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require `return` statements to either always or never specify values.
+
+    Functions which return a value on some code paths but use `return;` or reach the end of
+    the function on other code paths are confusing, and this usually signals an oversight. This rule
+    requires every `return` statement in a function to either specify a value or not, and functions
+    which return a value to not be able to implicitly return `undefined` by reaching their end.
+
+    Nested functions are checked separately from the functions they are in.
+
+    ## Invalid Code Examples
+
+    ```js
+    function doSomething(condition) {
+        if (condition) {
+            return true;
+        } else {
+            return;
+        }
+    }
+
+    function doSomethingElse(condition) {
+        if (condition) {
+            return true;
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function doSomething(condition) {
+        if (condition) {
+            return true;
+        } else {
+            return false;
+        }
+    }
+
+    function doSomethingElse(condition) {
+        if (condition) {
+            throw new Error("bad condition");
+        }
+        return true;
+    }
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    ConsistentReturn,
+    errors,
+    "consistent-return",
+    /// Whether to treat `return undefined` and `return void 0` like a `return` without a value.
+    pub treat_undefined_as_unspecified: bool
+}
+
+const FUNCTIONS: [SyntaxKind; 7] = [
+    FN_DECL,
+    FN_EXPR,
+    ARROW_EXPR,
+    METHOD,
+    GETTER,
+    SETTER,
+    CONSTRUCTOR,
+];
+
+#[typetag::serde]
+impl CstRule for ConsistentReturn {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !FUNCTIONS.contains(&node.kind()) {
+            return None;
+        }
+        let body = node.child_with_ast::<BlockStmt>()?;
+
+        let mut returns = body
+            .syntax()
+            .descendants()
+            .filter(|child| child.kind() == RETURN_STMT)
+            .filter(|ret| {
+                ret.ancestors()
+                    .find(|ancestor| FUNCTIONS.contains(&ancestor.kind()))
+                    .as_ref()
+                    == Some(node)
+            })
+            .map(|ret| {
+                let value = ret.to::<ast::ReturnStmt>().value();
+                let specified = self.is_specified(value.as_ref());
+                (ret, specified)
+            });
+
+        let (first, first_specified) = returns.next()?;
+        let name = function_name(node);
+
+        for (ret, _) in returns.filter(|(_, specified)| *specified != first_specified) {
+            let (msg, label) = if first_specified {
+                (
+                    "expected a return value",
+                    "function first returns a value here",
+                )
+            } else {
+                (
+                    "expected no return value",
+                    "function first returns without a value here",
+                )
+            };
+            let err = ctx
+                .err(self.name(), format!("{} in {}", msg, name))
+                .primary(ret.trimmed_range(), "")
+                .secondary(first.trimmed_range(), label);
+            ctx.add_err(err);
+        }
+
+        if first_specified && !body.stmts().any(|stmt| terminates(&stmt)) {
+            let end = body.r_curly_token()?;
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("expected a return value at the end of {}", name),
+                )
+                .primary(end, "the function implicitly returns `undefined` here")
+                .secondary(first.trimmed_range(), "function returns a value here");
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+impl ConsistentReturn {
+    fn is_specified(&self, value: Option<&Expr>) -> bool {
+        match value {
+            None => false,
+            Some(_) if !self.treat_undefined_as_unspecified => true,
+            Some(Expr::NameRef(name)) => name.syntax().text() != "undefined",
+            Some(Expr::UnaryExpr(unary)) => unary.op() != Some(UnaryOp::Void),
+            Some(_) => true,
+        }
+    }
+}
+
+fn function_name(node: &SyntaxNode) -> String {
+    let name = match node.kind() {
+        FN_DECL => node.to::<ast::FnDecl>().name().map(|n| n.text()),
+        FN_EXPR => node.to::<ast::FnExpr>().name().map(|n| n.text()),
+        METHOD => node
+            .to::<ast::Method>()
+            .name()
+            .map(|n| n.syntax().trimmed_text().to_string()),
+        GETTER => node
+            .to::<ast::Getter>()
+            .key()
+            .map(|n| n.syntax().trimmed_text().to_string()),
+        SETTER => node
+            .to::<ast::Setter>()
+            .key()
+            .map(|n| n.syntax().trimmed_text().to_string()),
+        CONSTRUCTOR => return "the constructor".to_string(),
+        _ => None,
+    };
+    match name {
+        Some(name) => format!("function `{}`", name),
+        None => "the function".to_string(),
+    }
+}
+
+/// Whether a statement always returns or throws, meaning the code after it can never run.
+///
+/// This is a conservative approximation, statements which may not complete normally are
+/// treated as completing normally.
+fn terminates(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::ReturnStmt(_) | Stmt::ThrowStmt(_) => true,
+        Stmt::BlockStmt(block) => block.stmts().any(|stmt| terminates(&stmt)),
+        Stmt::IfStmt(if_stmt) => {
+            maybe_terminates(if_stmt.cons()) && maybe_terminates(if_stmt.alt())
+        }
+        Stmt::LabelledStmt(labelled) => maybe_terminates(labelled.stmt()),
+        Stmt::WhileStmt(while_stmt) => loops_forever(
+            while_stmt.syntax(),
+            while_stmt.condition().and_then(|cond| cond.condition()),
+        ),
+        Stmt::DoWhileStmt(do_while) => loops_forever(
+            do_while.syntax(),
+            do_while.condition().and_then(|cond| cond.condition()),
+        ),
+        Stmt::ForStmt(for_stmt) => match for_stmt.test().and_then(|test| test.expr()) {
+            Some(test) => loops_forever(for_stmt.syntax(), Some(test)),
+            None => !has_break(for_stmt.syntax()),
+        },
+        Stmt::TryStmt(try_stmt) => {
+            let finalizer = try_stmt
+                .finalizer()
+                .and_then(|finalizer| finalizer.cons())
+                .map(Stmt::BlockStmt);
+            let handler = match try_stmt.handler() {
+                Some(handler) => maybe_terminates(handler.cons().map(Stmt::BlockStmt)),
+                None => true,
+            };
+            maybe_terminates(finalizer)
+                || (maybe_terminates(try_stmt.test().map(Stmt::BlockStmt)) && handler)
+        }
+        Stmt::SwitchStmt(switch) => {
+            let cases = switch.cases().collect::<Vec<_>>();
+            let has_default = cases
+                .iter()
+                .any(|case| matches!(case, SwitchCase::DefaultClause(_)));
+            has_default
+                && !has_break(switch.syntax())
+                && cases.iter().enumerate().all(|(idx, case)| {
+                    let mut cons = match case {
+                        SwitchCase::CaseClause(clause) => clause.cons(),
+                        SwitchCase::DefaultClause(clause) => clause.cons(),
+                    }
+                    .peekable();
+                    // empty cases fall through to the next case
+                    (cons.peek().is_none() && idx != cases.len() - 1)
+                        || cons.any(|stmt| terminates(&stmt))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Whether a loop can only be left by returning or throwing, because its condition is always truthy
+/// and it does not contain a `break`.
+fn loops_forever(node: &SyntaxNode, condition: Option<Expr>) -> bool {
+    condition.and_then(util::simple_bool_coerce) == Some(true) && !has_break(node)
+}
+
+fn has_break(node: &SyntaxNode) -> bool {
+    node.descendants().any(|child| child.kind() == BREAK_STMT)
+}
+
+fn maybe_terminates(stmt: Option<Stmt>) -> bool {
+    match stmt {
+        Some(stmt) => terminates(&stmt),
+        None => false,
+    }
+}
+
+rule_tests! {
+    ConsistentReturn::default(),
+    err: {
+        "function foo() { if (a) { return 5; } else { return; } }",
+        "function foo() { if (a) { return; } return 5; }",
+        "function foo() { if (a) { return 5; } }",
+        "var foo = () => { if (a) return true; };",
+        "var foo = function() { if (a) return true; else return; };",
+        "class A { foo() { if (a) return 1; } }",
+        "function foo() { switch (a) { case 1: return 1; default: break; } }",
+        "function foo() { try { return 1; } catch (e) {} }",
+        "function foo() { function bar() { return; } if (a) return 1; }",
+        "function foo() { while (true) { if (a) break; return 1; } }",
+        "function foo() { for (;a;) { return 1; } }"
+    },
+    ok: {
+        "function foo() { if (a) { return 5; } else { return 6; } }",
+        "function foo() { if (a) { return; } foo(); }",
+        "function foo() { if (a) { throw new Error(); } return true; }",
+        "function foo() { return 5; }",
+        "function foo() {}",
+        "var foo = () => 5;",
+        "function foo() { function bar() { return; } return 5; }",
+        "function foo() { var bar = () => { return; }; return 5; }",
+        "function foo() { if (a) return 1; else return 2; }",
+        "function foo() { switch (a) { case 1: case 2: return 1; default: return 2; } }",
+        "function foo() { try { return 1; } catch (e) { return 2; } }",
+        "function foo() { try { bar(); } finally { return 1; } }",
+        "function foo() { label: { return 1; } }",
+        "function foo() { return undefined; }",
+        "function f() { while (true) { return 1; } }",
+        "function f() { for (;;) { return 1; } }",
+        "function f() { do { if (a) return 1; } while (true); }"
+    }
+}
+
+rule_tests! {
+    treat_undefined_valid,
+    treat_undefined_invalid,
+    ConsistentReturn {
+        treat_undefined_as_unspecified: true,
+    },
+    err: {
+        "function foo() { if (a) return 5; return undefined; }",
+        "function foo() { if (a) return 5; return void 0; }"
+    },
+    ok: {
+        "function foo() { if (a) return; return undefined; }",
+        "function foo() { if (a) return void 0; return; }"
+    }
+}
@@ -0,0 +1,132 @@
+use crate::rule_prelude::*;
+use ast::{ForInStmt, IfStmt, Stmt};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require `for...in` loops to check that each key is an own property of the object.
+
+    `for...in` loops iterate over every enumerable property of an object, including the properties
+    it inherits from its prototype chain. This often leads to bugs when properties are added to a prototype,
+    such as `Object.prototype`. This rule requires the body of a `for...in` loop to be wrapped in an `if`
+    which checks `hasOwnProperty` (or `Object.hasOwn`), or to start with such a check which `continue`s
+    the loop.
+
+    ## Invalid Code Examples
+
+    ```js
+    for (key in foo) {
+        doSomething(key);
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    for (key in foo) {
+        if (Object.prototype.hasOwnProperty.call(foo, key)) {
+            doSomething(key);
+        }
+    }
+
+    for (key in foo) {
+        if (!foo.hasOwnProperty(key)) {
+            continue;
+        }
+        doSomething(key);
+    }
+    ```
+    */
+    #[derive(Default)]
+    GuardForIn,
+    errors,
+    "guard-for-in"
+}
+
+#[typetag::serde]
+impl CstRule for GuardForIn {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let stmt = node.try_to::<ForInStmt>()?;
+        let guarded = match stmt.cons()? {
+            Stmt::EmptyStmt(_) => true,
+            Stmt::IfStmt(guard) => is_own_property_check(&guard),
+            Stmt::BlockStmt(block) => {
+                let stmts = block.stmts().collect::<Vec<_>>();
+                match stmts.as_slice() {
+                    [] => true,
+                    [Stmt::IfStmt(guard)] => is_own_property_check(guard),
+                    [Stmt::IfStmt(guard), ..] => {
+                        is_own_property_check(guard) && guard.alt().is_none() && continues(guard)
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+        if guarded {
+            return None;
+        }
+
+        let head = util::token_list_range(&[stmt.for_token()?, stmt.r_paren_token()?]);
+        let err = ctx
+            .err(
+                self.name(),
+                "the body of a `for...in` loop should be guarded by a `hasOwnProperty` check",
+            )
+            .primary(head, "this loop also iterates over inherited properties")
+            .footer_help(
+                "wrap the body in `if (Object.prototype.hasOwnProperty.call(obj, key)) {}`, or use `Object.keys` instead",
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Whether the condition of an `if` statement calls `hasOwnProperty` or `Object.hasOwn`.
+fn is_own_property_check(guard: &IfStmt) -> bool {
+    guard
+        .condition()
+        .map(|condition| {
+            condition
+                .syntax()
+                .descendants_with_tokens()
+                .filter_map(|elem| elem.into_token())
+                .any(|tok| {
+                    tok.kind() == IDENT
+                        && matches!(tok.text().as_str(), "hasOwnProperty" | "hasOwn")
+                })
+        })
+        .unwrap_or_default()
+}
+
+/// Whether the consequent of an `if` statement is only a `continue` statement.
+fn continues(guard: &IfStmt) -> bool {
+    match guard.cons() {
+        Some(Stmt::ContinueStmt(_)) => true,
+        Some(Stmt::BlockStmt(block)) => {
+            let stmts = block.stmts().collect::<Vec<_>>();
+            matches!(stmts.as_slice(), [Stmt::ContinueStmt(_)])
+        }
+        _ => false,
+    }
+}
+
+rule_tests! {
+    GuardForIn::default(),
+    err: {
+        "for (key in foo) { doSomething(key); }",
+        "for (key in foo) doSomething(key);",
+        "for (key in foo) { if (foo.hasOwnProperty(key)) {} doSomething(key); }",
+        "for (key in foo) { if (key) { doSomething(key); } }",
+        "for (key in foo) { if (!foo.hasOwnProperty(key)) { bar(); } doSomething(key); }"
+    },
+    ok: {
+        "for (key in foo);",
+        "for (key in foo) {}",
+        "for (key in foo) if (foo.hasOwnProperty(key)) doSomething(key);",
+        "for (key in foo) { if (Object.prototype.hasOwnProperty.call(foo, key)) { doSomething(key); } }",
+        "for (key in foo) { if (!Object.hasOwn(foo, key)) continue; doSomething(key); }",
+        "for (key in foo) { if (!foo.hasOwnProperty(key)) { continue; } doSomething(key); }",
+        "for (const key of foo) { doSomething(key); }"
+    }
+}
@@ -35,5 +35,20 @@ group! {
     no_confusing_arrow::NoConfusingArrow,
     constructor_super::ConstructorSuper,
     no_this_before_super::NoThisBeforeSuper,
-    require_yield::RequireYield
+    require_yield::RequireYield,
+    no_iterator::NoIterator,
+    no_proto::NoProto,
+    no_useless_call::NoUselessCall,
+    no_empty_function::NoEmptyFunction,
+    consistent_return::ConsistentReturn,
+    radix::Radix,
+    no_unsafe_optional_chaining::NoUnsafeOptionalChaining,
+    no_irregular_identifiers::NoIrregularIdentifiers,
+    no_nonoctal_decimal_escape::NoNonoctalDecimalEscape,
+    no_loss_of_precision::NoLossOfPrecision,
+    no_duplicate_logical_operands::NoDuplicateLogicalOperands,
+    guard_for_in::GuardForIn,
+    no_unreachable::NoUnreachable,
+    no_self_compare::NoSelfCompare,
+    no_dupe_args::NoDupeArgs,
 }
@@ -82,7 +82,7 @@ fn issue_err(expr: ast::Expr, ctx: &mut RuleCtx, op: SyntaxToken, parent: &Synta
             Applicability::MaybeIncorrect,
         );
 
-    ctx.fix()
+    ctx.fixer()
         .replace(parent, format!("Object.is({}, -0)", expr.text()));
 
     ctx.add_err(err);
@@ -1,5 +1,5 @@
 use crate::rule_prelude::*;
-use ast::Expr;
+use ast::{AssignExpr, AssignOp, Expr};
 use SyntaxKind::*;
 
 declare_lint! {
@@ -55,9 +55,12 @@ const COND_CHECKED: [SyntaxKind; 5] = [IF_STMT, WHILE_STMT, DO_WHILE_STMT, FOR_S
 #[typetag::serde]
 impl CstRule for NoCondAssign {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !COND_CHECKED.contains(&node.kind()) {
+            return None;
+        }
         let cond = condition(node)?;
-        if COND_CHECKED.contains(&node.kind()) && check(&cond, self.allow_parens) {
-            let err = ctx
+        if let Some(assign) = check(&cond, self.allow_parens) {
+            let mut err = ctx
                 .err(
                     self.name(),
                     format!(
@@ -69,16 +72,18 @@ impl CstRule for NoCondAssign {
                     cond.syntax(),
                     "this condition results in unexpected behavior",
                 )
-                .suggestion(
-                    cond.range(),
-                    "try using `===` to compare instead",
-                    "===",
-                    Applicability::MaybeIncorrect,
-                )
                 .footer_note(format!(
                     "this makes the condition equivalent to `{}`",
                     color(&help_expr(cond.syntax()))
                 ));
+            if let Some((op, AssignOp::Assign)) = assign.op_details() {
+                err = err.suggestion(
+                    op,
+                    "try using `===` to compare instead",
+                    "===",
+                    Applicability::MaybeIncorrect,
+                );
+            }
 
             ctx.add_err(err);
         }
@@ -101,35 +106,26 @@ fn condition(node: &SyntaxNode) -> Option<Expr> {
         .condition()
 }
 
-fn check(expr: &ast::Expr, allow_parens: bool) -> bool {
+/// Find an assignment in a condition which is not allowed, returns `None` if the condition is fine.
+fn check(expr: &ast::Expr, allow_parens: bool) -> Option<AssignExpr> {
     match expr {
-        Expr::AssignExpr(_) => {
+        Expr::AssignExpr(assign) => {
             if expr
                 .syntax()
                 .parent()
                 .map(|x| x.kind() == GROUPING_EXPR && allow_parens)
                 .unwrap_or_default()
             {
-                return false;
-            }
-            true
-        }
-        Expr::GroupingExpr(group) => {
-            if let Some(inner) = group.inner() {
-                return check(&inner, allow_parens);
+                return None;
             }
-            false
+            Some(assign.clone())
         }
-        Expr::BinExpr(bin) if bin.conditional() => {
-            bin.lhs()
-                .map(|e| check(&e, allow_parens))
-                .unwrap_or_default()
-                || bin
-                    .rhs()
-                    .map(|e| check(&e, allow_parens))
-                    .unwrap_or_default()
-        }
-        _ => false,
+        Expr::GroupingExpr(group) => check(&group.inner()?, allow_parens),
+        Expr::BinExpr(bin) if bin.conditional() => bin
+            .lhs()
+            .and_then(|e| check(&e, allow_parens))
+            .or_else(|| bin.rhs().and_then(|e| check(&e, allow_parens))),
+        _ => None,
     }
 }
 
@@ -174,7 +170,32 @@ rule_tests! {
         "
         for(;foo = 4; bar) {}
         ",
-        "if (bar = 5 ? foo : bar) {}"
+        "if (bar = 5 ? foo : bar) {}",
+        "if (x = 5) {}",
+        "if (a = b || c) {}",
+        "if (x += 5) {}"
+    },
+    ok: {
+        "if ((x = 5)) {}",
+        "while (a && (b = c)) {}",
+        "while ((node = node.parent)) {}",
+        "do {} while ((a = b))",
+        "for (;(a = b);) {}",
+        "if (x === 5) {}",
+        "if (x) { x = 5; }",
+        "for (x = 0; x < 5; x++) {}"
+    }
+}
+
+rule_tests! {
+    disallow_parens_valid,
+    disallow_parens_invalid,
+    NoCondAssign { allow_parens: false },
+    err: {
+        "if ((x = 5)) {}",
+        "while ((node = node.parent)) {}"
     },
-    ok: {}
+    ok: {
+        "if (x === 5) {}"
+    }
 }
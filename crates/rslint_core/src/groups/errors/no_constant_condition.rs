@@ -36,17 +36,29 @@ declare_lint! {
     }
     ```
     */
-    #[derive(Default)]
+    #[serde(default)]
     NoConstantCondition,
     errors,
     tags(Recommended),
-    "no-constant-condition"
+    "no-constant-condition",
+    /// Whether to check the conditions of loops, this can be disabled to allow
+    /// infinite loops such as `while (true) {}` (true by default)
+    pub check_loops: bool
+}
+
+impl Default for NoConstantCondition {
+    fn default() -> Self {
+        Self { check_loops: true }
+    }
 }
 
 #[typetag::serde]
 impl CstRule for NoConstantCondition {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
         let mut notes = vec![];
+        if !self.check_loops && matches!(node.kind(), DO_WHILE_STMT | WHILE_STMT | FOR_STMT) {
+            return None;
+        }
         let cond = match node.kind() {
             IF_STMT | DO_WHILE_STMT | WHILE_STMT => {
                 if let Some(cond) = node.children().find_map(|node| node.try_to::<Condition>()) {
@@ -108,3 +120,18 @@ rule_tests! {
         "for(var i = 5; foo; i++) {}"
     }
 }
+
+rule_tests! {
+    no_check_loops_valid,
+    no_check_loops_invalid,
+    NoConstantCondition { check_loops: false },
+    err: {
+        "if (true) {}",
+        "true ? foo : bar"
+    },
+    ok: {
+        "while (true) {}",
+        "do {} while (1)",
+        "for (;true;) {}"
+    }
+}
@@ -1,4 +1,5 @@
 use crate::rule_prelude::*;
+use SyntaxKind::*;
 
 declare_lint! {
     /**
@@ -27,22 +28,51 @@ declare_lint! {
 #[typetag::serde]
 impl CstRule for NoDebugger {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
-        if node.kind() == SyntaxKind::DEBUGGER_STMT {
+        if node.kind() == DEBUGGER_STMT {
             let err = ctx
                 .err(self.name(), "debugger statements are not allowed")
-                .primary(node, "");
+                .primary(node, "remove this `debugger` statement");
 
+            // removing the statement is only valid if it is in a list of statements, `if (a) debugger;`
+            // would otherwise become `if (a)`
+            let in_list = node
+                .parent()
+                .filter(|parent| {
+                    matches!(
+                        parent.kind(),
+                        SCRIPT | MODULE | BLOCK_STMT | CASE_CLAUSE | DEFAULT_CLAUSE
+                    )
+                })
+                .is_some();
+            if in_list && !node.contains_comments() && !starts_asi_hazard(node.next_sibling()) {
+                ctx.fixer().delete(node.trimmed_range());
+            }
             ctx.add_err(err);
         }
         None
     }
 }
 
+/// Whether a statement starts with a token which would continue the statement before a removed `debugger`
+/// statement, such as `foo()\ndebugger\n[1]` becoming `foo()\n[1]`, which is `foo()[1]`.
+fn starts_asi_hazard(next: Option<SyntaxNode>) -> bool {
+    next.and_then(|stmt| stmt.first_token())
+        .filter(|token| {
+            matches!(
+                token.kind(),
+                T!['('] | T!['['] | BACKTICK | T![+] | T![-] | T![/] | T![/=] | REGEX
+            )
+        })
+        .is_some()
+}
+
 rule_tests! {
     NoDebugger::default(),
     err: {
         "debugger",
-        "debugger;"
+        "debugger;",
+        "function foo() { debugger; bar(); }",
+        "if (foo) debugger;"
     },
     ok: {}
 }
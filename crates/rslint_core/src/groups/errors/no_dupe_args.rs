@@ -0,0 +1,85 @@
+use crate::rule_prelude::*;
+use crate::util::pattern_names;
+use ast::ParameterList;
+
+declare_lint! {
+    /**
+    Disallow functions which have multiple parameters with the same name.
+
+    Outside of strict mode, a function can have multiple parameters with the same name, and the
+    last one shadows the others. This is almost always a typo, since the earlier parameters cannot be
+    used. Names bound by destructuring patterns are also checked.
+
+    ## Invalid Code Examples
+
+    ```js
+    function foo(a, b, a) {}
+
+    const bar = function(a, { b, c: a }) {};
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo(a, b, c) {}
+
+    const bar = function(a, { b, c: d }) {};
+    ```
+    */
+    #[derive(Default)]
+    NoDupeArgs,
+    errors,
+    tags(Recommended),
+    "no-dupe-args"
+}
+
+#[typetag::serde]
+impl CstRule for NoDupeArgs {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let params = node.try_to::<ParameterList>()?;
+        let mut names = vec![];
+        for param in params.parameters() {
+            pattern_names(param, &mut names);
+        }
+
+        for (idx, name) in names.iter().enumerate() {
+            let text = name.text();
+            let first = match names[..idx].iter().find(|other| other.text() == text) {
+                Some(first) => first,
+                None => continue,
+            };
+
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("duplicate parameter name `{}`", text),
+                )
+                .secondary(first, format!("`{}` is first declared here", text))
+                .primary(name, format!("`{}` is then redeclared here", text))
+                .footer_note("the last parameter with a name shadows the other parameters with the same name");
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoDupeArgs::default(),
+    err: {
+        "function foo(a, b, a) {}",
+        "const foo = function(a, a) {};",
+        "function foo(a, { b, c: [a] }) {}",
+        "function foo(a, ...a) {}",
+        "(a, a) => {};",
+        "class A { foo(a, a) {} }",
+        "function f(a = 1, a) {}",
+        "function f(a, a = 1) {}"
+    },
+    ok: {
+        "function foo(a, b, c) {}",
+        "function foo(a, { b, c: d }) {}",
+        "function foo(a) {} function bar(a) {}",
+        "function foo(a) { function bar(a) {} }",
+        "function foo({ a: b }, a) {}"
+    }
+}
@@ -1,13 +1,14 @@
 use crate::rule_prelude::*;
 use ast::*;
-use rslint_parser::{NodeOrToken, SyntaxToken};
 
 declare_lint! {
     /**
     Disallow duplicate keys in object literals.
 
     Object literals allow keys to be declared multiple times, however this causes unwanted
-    behavior by shadowing the first declaration.
+    behavior by shadowing the first declaration. Keys are compared by their value, therefore
+    `a`, `"a"`, and `["a"]` are the same key, and so are `1` and `0x1`. A getter and a setter
+    for the same key are not duplicates.
 
     ## Invalid Code Examples
 
@@ -17,6 +18,20 @@ declare_lint! {
         baz: 2,
         bar: 3
     }
+
+    let bar = {
+        1: "foo",
+        0x1: "bar"
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let foo = {
+        get bar() {},
+        set bar(value) {}
+    }
     ```
     */
     #[derive(Default)]
@@ -26,45 +41,40 @@ declare_lint! {
     "no-dupe-keys"
 }
 
-// FIXME: this should consider the value of a number key, aka 1 and 0x1
+/// The kind of a property, a getter and a setter for the same key are not duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PropKind {
+    Getter,
+    Setter,
+    Value,
+}
+
 #[typetag::serde]
 impl CstRule for NoDupeKeys {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
         if let Expr::ObjectExpr(obj) = node.try_to()? {
-            // String based equality is evil! using tokens is the correct way,
-            // because it counts `foo . bar` and `foo.bar` as the same expr, string eq does not.
-            let mut declared: Vec<(Vec<SyntaxToken>, std::string::String)> = vec![];
-
-            for prop in obj.props().filter_map(|prop| prop.key_element()) {
-                let tokens = match prop.clone() {
-                    NodeOrToken::Node(node) => node.lossy_tokens(),
-                    NodeOrToken::Token(tok) => vec![tok],
-                };
-
-                let text = match prop {
-                    NodeOrToken::Node(node) => node.trimmed_text().to_string(),
-                    NodeOrToken::Token(tok) => tok.text().to_string(),
-                };
-                if let Some((found, text)) = declared
-                    .iter()
-                    .find(|(decl_tokens, _)| util::string_token_eq(decl_tokens, &tokens))
-                {
-                    let range = util::token_list_range(found);
+            let mut declared: Vec<(std::string::String, PropKind, SyntaxNode)> = vec![];
 
+            for (name, kind, key) in obj.props().filter_map(|prop| prop_key(&prop)) {
+                let first = declared.iter().find(|(declared_name, declared_kind, _)| {
+                    *declared_name == name && !is_accessor_pair(*declared_kind, kind)
+                });
+                if let Some((_, _, first)) = first {
+                    let text = key.trimmed_text();
                     let err = ctx
                         .err(
                             self.name(),
                             format!("duplicate property definition `{}`", text),
                         )
-                        .secondary(range, format!("`{}` is first declared here", text))
-                        .primary(
-                            util::token_list_range(tokens),
-                            format!("`{}` is then redeclared here", text),
-                        );
+                        .secondary(
+                            first,
+                            format!("`{}` is first declared here", first.trimmed_text()),
+                        )
+                        .primary(&key, format!("`{}` is then redeclared here", text));
 
                     ctx.add_err(err);
                 } else {
-                    declared.push((tokens, text));
+                    declared.push((name, kind, key));
                 }
             }
         }
@@ -72,6 +82,54 @@ impl CstRule for NoDupeKeys {
     }
 }
 
+fn is_accessor_pair(first: PropKind, second: PropKind) -> bool {
+    matches!(
+        (first, second),
+        (PropKind::Getter, PropKind::Setter) | (PropKind::Setter, PropKind::Getter)
+    )
+}
+
+/// Get the static name, kind, and key node of a property.
+fn prop_key(prop: &ObjectProp) -> Option<(std::string::String, PropKind, SyntaxNode)> {
+    let ((name, key), kind) = match prop {
+        ObjectProp::IdentProp(prop) => (name_key(prop.name()?)?, PropKind::Value),
+        ObjectProp::InitializedProp(prop) => (name_key(prop.key()?)?, PropKind::Value),
+        ObjectProp::LiteralProp(prop) => (prop_name_key(prop.key()?)?, PropKind::Value),
+        ObjectProp::Method(prop) => (prop_name_key(prop.name()?)?, PropKind::Value),
+        ObjectProp::Getter(prop) => (prop_name_key(prop.key()?)?, PropKind::Getter),
+        ObjectProp::Setter(prop) => (prop_name_key(prop.key()?)?, PropKind::Setter),
+        ObjectProp::SpreadProp(_) => return None,
+    };
+    Some((name, kind, key))
+}
+
+fn name_key(name: Name) -> Option<(std::string::String, SyntaxNode)> {
+    Some((name.text(), name.syntax().clone()))
+}
+
+/// Get the static name of a property key and its node, `1`, `0x1`, `"1"`, and `["1"]` all have the name `1`.
+/// Computed keys which are not literals have no static name.
+fn prop_name_key(name: PropName) -> Option<(std::string::String, SyntaxNode)> {
+    match name {
+        PropName::Ident(name) => name_key(name),
+        PropName::Literal(lit) => literal_key(&lit).map(|key| (key, lit.syntax().clone())),
+        PropName::Computed(computed) => match computed.prop()? {
+            Expr::Literal(lit) => literal_key(&lit).map(|key| (key, computed.syntax().clone())),
+            _ => None,
+        },
+    }
+}
+
+fn literal_key(lit: &Literal) -> Option<std::string::String> {
+    if let Some(num) = lit.as_number() {
+        Some(num.to_string())
+    } else if lit.is_string() {
+        Some(lit.inner_string_text()?.to_string())
+    } else {
+        None
+    }
+}
+
 rule_tests! {
     NoDupeKeys::default(),
     err: {
@@ -84,16 +142,15 @@ rule_tests! {
             }
         }
         ",
-        "
-        let foo = {
-            get bar() {
-
-            },
-            set bar(foo)  {
-
-            }
-        }
-        "
+        "let foo = { a: 1, a: 2 }",
+        "let foo = { a: 1, 'a': 2 }",
+        "let foo = { \"a\": 1, ['a']: 2 }",
+        "let foo = { 1: 1, 1: 2 }",
+        "let foo = { 1: 1, 0x1: 2 }",
+        "let foo = { 1: 1, '1': 2 }",
+        "let foo = { a() {}, a: 1 }",
+        "let foo = { get a() {}, get a() {} }",
+        "let foo = { a: 1, get a() {}, set a(v) {} }"
     },
     ok: {
         "
@@ -104,6 +161,21 @@ rule_tests! {
             },
             baz: {}
         }
+        ",
         "
+        let foo = {
+            get bar() {
+
+            },
+            set bar(foo)  {
+
+            }
+        }
+        ",
+        "let foo = { a: 1, b: 2 }",
+        "let foo = { 1: 1, 2: 2 }",
+        "let foo = { [a]: 1, [a]: 2 }",
+        "let foo = { ...a, ...a }",
+        "let foo = { a: 1, ...{ a: 2 } }"
     }
 }
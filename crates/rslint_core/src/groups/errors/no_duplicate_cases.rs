@@ -1,5 +1,8 @@
 use crate::rule_prelude::*;
 use ast::SwitchStmt;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
 declare_lint! {
     /**
@@ -44,10 +47,13 @@ declare_lint! {
 impl CstRule for NoDuplicateCases {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
         if let Some(switch) = node.try_to::<SwitchStmt>() {
-            let mut seen: Vec<SyntaxNode> = vec![];
+            // tests are keyed by a hash of their tokens so large switches do not compare every pair of tests
+            let mut seen: HashMap<u64, Vec<SyntaxNode>> = HashMap::new();
             for case in switch.cases().filter_map(|case| case.into_case()) {
                 if let Some(expr) = case.test() {
-                    if let Some(old) = seen.iter().find(|clause| clause.lexical_eq(expr.syntax())) {
+                    let tests = seen.entry(lexical_hash(expr.syntax())).or_default();
+                    if let Some(old) = tests.iter().find(|clause| clause.lexical_eq(expr.syntax()))
+                    {
                         let err = ctx
                             .err(
                                 self.name(),
@@ -67,7 +73,7 @@ impl CstRule for NoDuplicateCases {
 
                         ctx.add_err(err)
                     } else {
-                        seen.push(expr.syntax().clone());
+                        tests.push(expr.syntax().clone());
                     }
                 }
             }
@@ -76,6 +82,15 @@ impl CstRule for NoDuplicateCases {
     }
 }
 
+/// Hash the non-trivia tokens of a node, nodes which are [`lexical_eq`](SyntaxNodeExt::lexical_eq) have the same hash.
+fn lexical_hash(node: &SyntaxNode) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for token in node.lossy_tokens() {
+        token.text().as_str().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
 rule_tests! {
     NoDuplicateCases::default(),
     err: {
@@ -101,5 +116,15 @@ rule_tests! {
         }
         "
     },
-    ok: {}
+    ok: {
+        "
+        switch (foo) {
+            case 1:
+            break;
+
+            default:
+            break;
+        }
+        "
+    }
 }
@@ -0,0 +1,150 @@
+use crate::rule_prelude::*;
+use ast::{BinExpr, BinOp, Expr};
+
+declare_lint! {
+    /**
+    Disallow duplicate operands in chains of `&&`, `||`, and `??` expressions.
+
+    An operand which is repeated in a chain of the same logical operator is redundant, because its
+    value was already checked by the first occurrence. This is usually a typo, for example the programmer
+    copied an operand and forgot to change it. Operands are compared lexically, therefore `a.b` and `a . b`
+    are considered the same.
+
+    ## Invalid Code Examples
+
+    ```js
+    if (a || a) {}
+
+    const b = x && y && x;
+
+    const c = foo.bar ?? baz ?? foo.bar;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    if (a || b) {}
+
+    const b = x && y || x;
+
+    const c = foo.bar ?? foo.baz;
+    ```
+    */
+    #[derive(Default)]
+    NoDuplicateLogicalOperands,
+    errors,
+    "no-duplicate-logical-operands"
+}
+
+#[typetag::serde]
+impl CstRule for NoDuplicateLogicalOperands {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let expr = node.try_to::<BinExpr>()?;
+        let op = expr.op().filter(|op| is_logical(*op))?;
+        // only check the outermost expression of a chain
+        if node
+            .parent()
+            .and_then(|parent| parent.try_to::<BinExpr>())
+            .and_then(|parent| parent.op())
+            == Some(op)
+        {
+            return None;
+        }
+
+        let mut operands = vec![];
+        flatten(Expr::BinExpr(expr), op, &mut operands);
+        for (idx, operand) in operands.iter().enumerate() {
+            let first = match operands[..idx]
+                .iter()
+                .find(|prev| prev.syntax().lexical_eq(operand.syntax()))
+            {
+                Some(first) => first,
+                None => continue,
+            };
+
+            let text = operand.syntax().trimmed_text();
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("duplicate operand `{}` in logical expression", text),
+                )
+                .secondary(first.syntax(), format!("`{}` is first used here", text))
+                .primary(operand.syntax(), "...and is then used again here");
+
+            // the operand can only be removed if it directly follows the previous operand, which
+            // is not the case for operands in different parentheses such as `(a || b) || a`
+            let prev_end = operands[idx - 1].syntax().trimmed_range().end();
+            let between = &ctx.src
+                [usize::from(prev_end)..usize::from(operand.syntax().trimmed_range().start())];
+            if between.trim() == op_text(op) && !node.contains_comments() {
+                ctx.fixer()
+                    .delete(prev_end..operand.syntax().trimmed_range().end());
+            }
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+fn is_logical(op: BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::LogicalAnd | BinOp::LogicalOr | BinOp::NullishCoalescing
+    )
+}
+
+fn op_text(op: BinOp) -> &'static str {
+    match op {
+        BinOp::LogicalAnd => "&&",
+        BinOp::LogicalOr => "||",
+        _ => "??",
+    }
+}
+
+/// Collect the operands of a chain of the same logical operator in source order.
+fn flatten(expr: Expr, op: BinOp, operands: &mut Vec<Expr>) {
+    match expr {
+        Expr::BinExpr(bin) if bin.op() == Some(op) => {
+            if let Some(lhs) = bin.lhs() {
+                flatten(lhs, op, operands);
+            }
+            if let Some(rhs) = bin.rhs() {
+                flatten(rhs, op, operands);
+            }
+        }
+        // `a || (b || c)` is the same chain as `a || b || c`
+        Expr::GroupingExpr(grouping) => match grouping.inner() {
+            Some(inner) if is_chain(&inner, op) => flatten(inner, op, operands),
+            _ => operands.push(Expr::GroupingExpr(grouping)),
+        },
+        expr => operands.push(expr),
+    }
+}
+
+fn is_chain(expr: &Expr, op: BinOp) -> bool {
+    match expr {
+        Expr::BinExpr(bin) => bin.op() == Some(op),
+        _ => false,
+    }
+}
+
+rule_tests! {
+    NoDuplicateLogicalOperands::default(),
+    err: {
+        "a || a",
+        "x && y && x",
+        "a && a && b",
+        "foo.bar ?? baz ?? foo . bar",
+        "a || (b || a)",
+        "(a || b) || a",
+        "if (foo() && bar && foo()) {}"
+    },
+    ok: {
+        "a || b",
+        "x && y && z",
+        "x && y || x",
+        "(a || b) && (a || c)",
+        "(a ?? b) || a",
+        "foo.bar && foo.baz"
+    }
+}
@@ -7,7 +7,8 @@ declare_lint! {
 
     Block statements with nothing in them are very common when refactoring, however
     they can get confusing really quickly. This rule reports empty block statements and empty switch
-    case blocks if they do not have a comment.
+    case blocks if they do not have a comment. Empty `catch` clauses are allowed by default, because
+    ignoring an error is a common intentional pattern.
 
     ## Invalid Code Examples
 
@@ -29,7 +30,6 @@ declare_lint! {
     }
     ```
     */
-    #[derive(Default)]
     #[serde(default)]
     NoEmpty,
     errors,
@@ -38,10 +38,19 @@ declare_lint! {
     /// Whether to disallow empty block statements in function declarations, arrow functions,
     /// getters, setters, and methods.
     pub disallow_empty_functions: bool,
-    /// Whether to allow empty `catch` clauses without a comment.
+    /// Whether to allow empty `catch` clauses without a comment, `true` by default.
     pub allow_empty_catch: bool
 }
 
+impl Default for NoEmpty {
+    fn default() -> Self {
+        Self {
+            disallow_empty_functions: false,
+            allow_empty_catch: true,
+        }
+    }
+}
+
 const IGNORED: [SyntaxKind; 7] = [
     FN_DECL,
     FN_EXPR,
@@ -107,6 +116,8 @@ rule_tests! {
         /// ignore
         "{  }",
         "if (foo) {}",
+        "if (x) {}",
+        "try {} catch (e) { /* ignore */ }",
         "do { } while (scoot)",
         "for(let i = 5; i < 10; i++) {}",
         "switch (foo) {}",
@@ -119,6 +130,24 @@ rule_tests! {
         }",
         "if (foo) { /* */ }",
         "switch (bar) { /* */ }",
-        "class Foo { constructor() {} }"
+        "class Foo { constructor() {} }",
+        "function f() {}",
+        "try { foo(); } catch (e) { /* ignore */ }",
+        "try { foo(); } catch (e) {}"
+    }
+}
+
+rule_tests! {
+    disallow_empty_catch_valid,
+    disallow_empty_catch_invalid,
+    NoEmpty {
+        allow_empty_catch: false,
+        ..NoEmpty::default()
+    },
+    err: {
+        "try { foo(); } catch (e) {}"
+    },
+    ok: {
+        "try { foo(); } catch (e) { /* ignore */ }"
     }
 }
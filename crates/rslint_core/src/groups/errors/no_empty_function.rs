@@ -0,0 +1,189 @@
+use crate::rule_prelude::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow empty functions.
+
+    Empty functions can reduce readability because readers need to guess whether the function
+    is empty on purpose or not. Functions whose body only contains a comment, such as
+    `function foo() { /* noop */ }`, are not considered empty.
+
+    Certain kinds of functions can be allowed to be empty using the `allow` option, which takes
+    a list of `functions`, `arrowFunctions`, `generatorFunctions`, `asyncFunctions`, `methods`,
+    `generatorMethods`, `asyncMethods`, `getters`, `setters`, and `constructors`.
+
+    ## Invalid Code Examples
+
+    ```js
+    function foo() {}
+
+    const bar = () => {};
+
+    class Foo {
+        constructor() {}
+
+        get foo() {}
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo() {
+        // do nothing
+    }
+
+    const bar = () => {
+        /* noop */
+    };
+
+    class Foo {
+        constructor() {
+            // empty on purpose
+        }
+    }
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoEmptyFunction,
+    errors,
+    "no-empty-function",
+    /// The kinds of functions which are allowed to be empty.
+    pub allow: Vec<FunctionKind>
+}
+
+/// A kind of function which can be allowed to be empty.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FunctionKind {
+    Functions,
+    ArrowFunctions,
+    GeneratorFunctions,
+    AsyncFunctions,
+    Methods,
+    GeneratorMethods,
+    AsyncMethods,
+    Getters,
+    Setters,
+    Constructors,
+}
+
+impl FunctionKind {
+    fn of(node: &SyntaxNode) -> Option<Self> {
+        let has_token =
+            |kind: SyntaxKind| node.children_with_tokens().any(|elem| elem.kind() == kind);
+
+        Some(match node.kind() {
+            FN_DECL | FN_EXPR if has_token(STAR) => FunctionKind::GeneratorFunctions,
+            FN_DECL | FN_EXPR if has_token(ASYNC_KW) => FunctionKind::AsyncFunctions,
+            FN_DECL | FN_EXPR => FunctionKind::Functions,
+            ARROW_EXPR => FunctionKind::ArrowFunctions,
+            METHOD if has_token(STAR) => FunctionKind::GeneratorMethods,
+            METHOD if has_token(ASYNC_KW) => FunctionKind::AsyncMethods,
+            METHOD => FunctionKind::Methods,
+            GETTER => FunctionKind::Getters,
+            SETTER => FunctionKind::Setters,
+            CONSTRUCTOR => FunctionKind::Constructors,
+            _ => return None,
+        })
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            FunctionKind::Functions => "function",
+            FunctionKind::ArrowFunctions => "arrow function",
+            FunctionKind::GeneratorFunctions => "generator function",
+            FunctionKind::AsyncFunctions => "async function",
+            FunctionKind::Methods => "method",
+            FunctionKind::GeneratorMethods => "generator method",
+            FunctionKind::AsyncMethods => "async method",
+            FunctionKind::Getters => "getter",
+            FunctionKind::Setters => "setter",
+            FunctionKind::Constructors => "constructor",
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoEmptyFunction {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if node.kind() != BLOCK_STMT || node.first_child().is_some() {
+            return None;
+        }
+        // leading comments such as `function foo() /* a */ {}` are part of the block
+        let l_curly = node.token_with_kind(T!['{'])?;
+        let has_comment = node.tokens().iter().any(|tok| {
+            tok.kind() == COMMENT && tok.text_range().start() > l_curly.text_range().start()
+        });
+        if has_comment {
+            return None;
+        }
+        let kind = FunctionKind::of(&node.parent()?)?;
+        if self.allow.contains(&kind) {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!("unexpected empty {}", kind.description()),
+            )
+            .primary(node.trimmed_range(), "");
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    NoEmptyFunction::default(),
+    err: {
+        "function f(){}",
+        "function f() /* a */ {}",
+        "function* f() {}",
+        "async function f() {}",
+        "var foo = function() {};",
+        "var foo = () => {};",
+        "var foo = async () => {};",
+        "class A { constructor() {} }",
+        "class A { foo() {} }",
+        "class A { static *foo() {} }",
+        "class A { async foo() {} }",
+        "class A { get foo() {} }",
+        "class A { set foo(a) {} }",
+        "var obj = { foo() {} };",
+        "var obj = { get foo() {}, set foo(a) {} };"
+    },
+    ok: {
+        "function f(){ /* noop */ }",
+        "function f() {
+            // do nothing
+        }",
+        "function f() { return; }",
+        "var foo = () => {
+            /* empty */
+        };",
+        "var foo = () => bar;",
+        "class A { constructor() { /* empty */ } }",
+        "if (foo) {}",
+        "{}"
+    }
+}
+
+rule_tests! {
+    allow_valid,
+    allow_invalid,
+    NoEmptyFunction {
+        allow: vec![FunctionKind::ArrowFunctions, FunctionKind::Constructors],
+    },
+    err: {
+        "function f() {}",
+        "class A { foo() {} }"
+    },
+    ok: {
+        "var foo = () => {};",
+        "class A { constructor() {} }"
+    }
+}
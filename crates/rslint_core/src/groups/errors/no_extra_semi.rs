@@ -48,7 +48,7 @@ impl CstRule for NoExtraSemi {
                 .parent()
                 .map_or(true, |parent| !ALLOWED.contains(&parent.kind()))
         {
-            ctx.fix().delete(node);
+            ctx.fixer().delete(node);
             let err = ctx
                 .err(self.name(), "unnecessary semicolon")
                 .primary(node, "")
@@ -64,6 +64,8 @@ rule_tests! {
   NoExtraSemi::default(),
   err: {
     ";",
+    "var x = 5;;",
+    "if (foo) {};",
     "
       if (foo) {
         ;
@@ -82,6 +84,9 @@ rule_tests! {
   ok: {
     "
       class Foo {}
-      "
+      ",
+    "for (;;) {}",
+    "for (;;);",
+    "while (foo);"
   }
 }
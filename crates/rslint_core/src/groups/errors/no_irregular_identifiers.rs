@@ -0,0 +1,220 @@
+use crate::rule_prelude::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow invisible and confusable characters in identifiers.
+
+    Some unicode characters are invisible or change the order in which text is displayed, and some
+    look exactly like another letter. They can be used to make two different identifiers look the
+    same, or to hide what code actually does when reviewing it, which makes them a common tool for
+    hiding malicious code in source code.
+
+    This rule disallows identifiers which contain:
+    - Zero width characters, such as the zero width joiner (`U+200D`). A zero width space (`U+200B`)
+      is whitespace, therefore it is reported if it splits what looks like a single identifier into two.
+    - Cyrillic and Greek letters which look like latin letters, if the identifier also contains latin letters.
+
+    Characters written as unicode escapes in identifiers are checked too. Bidirectional control
+    characters such as the right to left override (`U+202E`) are not allowed in identifiers at all,
+    they are reported by the parser instead.
+
+    ## Invalid Code Examples
+
+    ```js
+    // `c‍d`, containing a zero width joiner
+    let c‍d = 1;
+
+    // `аpple`, the first letter is cyrillic
+    let аpple = 2;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    let cd = 1;
+
+    let apple = 2;
+
+    // identifiers which are entirely in another script are allowed
+    let яблоко = 3;
+    ```
+    */
+    #[derive(Default)]
+    NoIrregularIdentifiers,
+    errors,
+    "no-irregular-identifiers"
+}
+
+/// Invisible characters which are either allowed in identifiers or lexed as whitespace.
+const INVISIBLE_TABLE: [(char, &str); 4] = [
+    ('\u{200B}', "Zero Width Space"),
+    ('\u{200C}', "Zero Width Non-Joiner"),
+    ('\u{200D}', "Zero Width Joiner"),
+    ('\u{FEFF}', "Zero Width No-Break Space"),
+];
+
+/// Letters of other scripts which are rendered (almost) identically to a latin letter.
+const CONFUSABLE_TABLE: [(char, char); 24] = [
+    ('\u{0430}', 'a'),
+    ('\u{0441}', 'c'),
+    ('\u{0435}', 'e'),
+    ('\u{04BB}', 'h'),
+    ('\u{0456}', 'i'),
+    ('\u{0458}', 'j'),
+    ('\u{043E}', 'o'),
+    ('\u{0440}', 'p'),
+    ('\u{0455}', 's'),
+    ('\u{0445}', 'x'),
+    ('\u{0443}', 'y'),
+    ('\u{0410}', 'A'),
+    ('\u{0412}', 'B'),
+    ('\u{0421}', 'C'),
+    ('\u{0415}', 'E'),
+    ('\u{041D}', 'H'),
+    ('\u{041A}', 'K'),
+    ('\u{041C}', 'M'),
+    ('\u{041E}', 'O'),
+    ('\u{0420}', 'P'),
+    ('\u{0422}', 'T'),
+    ('\u{0425}', 'X'),
+    ('\u{03BF}', 'o'),
+    ('\u{039F}', 'O'),
+];
+
+#[typetag::serde]
+impl CstRule for NoIrregularIdentifiers {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        match token.kind() {
+            IDENT => self.check_ident(token, ctx),
+            WHITESPACE => self.check_split_ident(token, ctx),
+            _ => None,
+        }
+    }
+}
+
+impl NoIrregularIdentifiers {
+    fn check_ident(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        let chars = unescape(token.text());
+        let has_latin = chars.iter().any(|c| c.is_ascii_alphabetic());
+
+        for c in chars {
+            if let Some((_, name)) = INVISIBLE_TABLE.iter().find(|(ch, _)| *ch == c) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!(
+                            "identifier `{}` contains an invisible character (U+{:04X} {})",
+                            token.text().escape_default(),
+                            c as u32,
+                            name
+                        ),
+                    )
+                    .primary(token.text_range(), "")
+                    .footer_note(
+                        "invisible characters can make different identifiers look identical",
+                    );
+                ctx.add_err(err);
+                return None;
+            }
+            if !has_latin {
+                continue;
+            }
+            if let Some((_, latin)) = CONFUSABLE_TABLE.iter().find(|(ch, _)| *ch == c) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!(
+                            "identifier `{}` contains U+{:04X}, which looks like the latin letter `{}`",
+                            token.text(),
+                            c as u32,
+                            latin
+                        ),
+                    )
+                    .primary(token.text_range(), "")
+                    .footer_note("mixing scripts in identifiers can make different identifiers look identical");
+                ctx.add_err(err);
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Invisible whitespace such as zero width spaces splits what looks like a single identifier into two.
+    fn check_split_ident(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        let c = token.text().chars().next()?;
+        let (_, name) = INVISIBLE_TABLE.iter().find(|(ch, _)| *ch == c)?;
+        if token.text().chars().any(|other| other != c) {
+            return None;
+        }
+        let prev = token.prev_token()?;
+        let next = token.next_token()?;
+        if prev.kind() != IDENT || next.kind() != IDENT {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "`{}{}{}` contains an invisible character (U+{:04X} {}), which splits it into two identifiers",
+                    prev.text(),
+                    token.text().escape_default(),
+                    next.text(),
+                    c as u32,
+                    name
+                ),
+            )
+            .primary(prev.text_range().start()..next.text_range().end(), "")
+            .footer_note("invisible characters can make different code look identical");
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Get the characters of an identifier with any unicode escapes resolved.
+fn unescape(text: &str) -> Vec<char> {
+    let mut chars = vec![];
+    let mut rest = text;
+    while let Some(idx) = rest.find("\\u") {
+        chars.extend(rest[..idx].chars());
+        rest = &rest[idx + 2..];
+        let (hex, len) = if rest.starts_with('{') {
+            match rest.find('}') {
+                Some(end) => (&rest[1..end], end + 1),
+                None => break,
+            }
+        } else {
+            (rest.get(..4).unwrap_or(rest), 4.min(rest.len()))
+        };
+        if let Some(c) = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(std::char::from_u32)
+        {
+            chars.push(c);
+        }
+        rest = &rest[len..];
+    }
+    chars.extend(rest.chars());
+    chars
+}
+
+rule_tests! {
+    NoIrregularIdentifiers::default(),
+    err: {
+        "let a\u{200B}b = 1;",
+        "let c\u{200D}d = 1;",
+        "let c\\u200Dd = 1;",
+        "let c\\u{200c}d = 1;",
+        "let \u{0430}pple = 1;",
+        "foo.\u{0441}all();"
+    },
+    ok: {
+        "let apple = 1;",
+        "let $_foo123 = 1;",
+        "let \u{044F}\u{0431}\u{043B}\u{043E}\u{043A}\u{043E} = 1;",
+        "let a = '\u{200B}';",
+        "let caf\u{00E9} = 1;",
+        "let a\u{00E9}\\u0062 = 1;"
+    }
+}
@@ -0,0 +1,95 @@
+use crate::rule_prelude::*;
+use ast::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow the use of the `__iterator__` property.
+
+    The `__iterator__` property was a SpiderMonkey extension to JavaScript which allowed creating
+    custom iterators. It is obsolete and not supported by other engines, ES6 iterators and generators
+    should be used instead.
+
+    ## Invalid Code Examples
+
+    ```js
+    Foo.prototype.__iterator__ = function() {
+        return new FooIterator(this);
+    };
+
+    foo["__iterator__"] = function() {};
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    Foo.prototype[Symbol.iterator] = function*() {
+        yield* this.items;
+    };
+    ```
+    */
+    #[derive(Default)]
+    NoIterator,
+    errors,
+    "no-iterator"
+}
+
+#[typetag::serde]
+impl CstRule for NoIterator {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let prop = match node.kind() {
+            DOT_EXPR => {
+                let prop = node.to::<DotExpr>().prop()?;
+                if prop.syntax().text() != "__iterator__" {
+                    return None;
+                }
+                prop.syntax().clone()
+            }
+            BRACKET_EXPR => {
+                let prop = node.to::<BracketExpr>().prop()?;
+                let text = match &prop {
+                    Expr::Literal(lit) => lit.inner_string_text()?.to_string(),
+                    Expr::Template(tpl)
+                        if tpl.tag().is_none() && tpl.elements().next().is_none() =>
+                    {
+                        tpl.syntax()
+                            .trimmed_text()
+                            .to_string()
+                            .trim_matches('`')
+                            .to_string()
+                    }
+                    _ => return None,
+                };
+                if text != "__iterator__" {
+                    return None;
+                }
+                prop.syntax().clone()
+            }
+            _ => return None,
+        };
+
+        let err = ctx
+            .err(self.name(), "the `__iterator__` property is obsolete")
+            .primary(prop.trimmed_range(), "")
+            .footer_help("use ES6 iterators and generators with `Symbol.iterator` instead");
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    NoIterator::default(),
+    err: {
+        "a.__iterator__ = function() {}",
+        "var a = test.__iterator__;",
+        "foo['__iterator__'] = function() {}",
+        "foo[`__iterator__`] = null;"
+    },
+    ok: {
+        "var a = test.iterator;",
+        "foo[Symbol.iterator] = function() {}",
+        "foo['__iterator']",
+        "var __iterator__ = foo;",
+        "foo[__iterator__]"
+    }
+}
@@ -0,0 +1,226 @@
+use crate::rule_prelude::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow number literals which lose precision at runtime.
+
+    Numbers in JavaScript are double precision (64 bit) floats, which can only represent integers up to
+    2^53 exactly and have about 17 significant decimal digits. A number literal which has more precision
+    than that is silently rounded to the closest number which can be represented, therefore the value
+    at runtime is not the value which was written.
+
+    Decimal, exponential, hexadecimal, octal, and binary literals are checked, BigInt literals are not.
+
+    ## Invalid Code Examples
+
+    ```js
+    const a = 9007199254740993;
+
+    const b = 5123000000000000000000000000001;
+
+    const c = 1230000000000000000000000.0;
+
+    const d = .1230000000000000000000000;
+
+    const e = 0x20000000000001;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = 12345;
+
+    const b = 123.456;
+
+    const c = 123e34;
+
+    const d = 12300000000000000000000000;
+
+    const e = 0x1FFFFFFFFFFFFF;
+
+    const f = 9007199254740993n;
+    ```
+    */
+    #[derive(Default)]
+    NoLossOfPrecision,
+    errors,
+    "no-loss-of-precision"
+}
+
+#[typetag::serde]
+impl CstRule for NoLossOfPrecision {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if token.kind() != NUMBER || token.text().ends_with('n') {
+            return None;
+        }
+        let text = token.text().replace('_', "");
+        let (radix, digits) = radix_and_digits(&text);
+
+        let (exact, value) = if radix == 10 {
+            decimal_is_exact(digits)?
+        } else {
+            radix_is_exact(digits, radix)?
+        };
+        if exact {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                "this number literal loses precision when it is converted to a number",
+            )
+            .primary(
+                token.text_range(),
+                format!("this number is `{}` at runtime", display_number(value)),
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Split a number literal into its radix and its digits without the radix prefix.
+fn radix_and_digits(text: &str) -> (u32, &str) {
+    let prefix = text.get(..2).map(|prefix| prefix.to_ascii_lowercase());
+    match prefix.as_deref() {
+        Some("0x") => (16, &text[2..]),
+        Some("0o") => (8, &text[2..]),
+        Some("0b") => (2, &text[2..]),
+        // legacy octal literals such as `0777`, `089` is a decimal literal however
+        _ if text.len() > 1
+            && text.starts_with('0')
+            && text.chars().all(|c| ('0'..='7').contains(&c)) =>
+        {
+            (8, &text[1..])
+        }
+        _ => (10, text),
+    }
+}
+
+/// Whether a hexadecimal, octal, or binary integer can be represented exactly, and its value.
+///
+/// The radixes are powers of two, therefore the integer is exact if its set bits fit in the 53 bits
+/// of precision of a float.
+fn radix_is_exact(digits: &str, radix: u32) -> Option<(bool, f64)> {
+    let bits_per_digit = radix.trailing_zeros() as usize;
+    let mut bits = Vec::with_capacity(digits.len() * bits_per_digit);
+    let mut value = 0.0;
+    for c in digits.chars() {
+        let digit = c.to_digit(radix)?;
+        value = value * radix as f64 + digit as f64;
+        bits.extend((0..bits_per_digit).rev().map(|bit| digit >> bit & 1 == 1));
+    }
+
+    let highest = match bits.iter().position(|bit| *bit) {
+        Some(idx) => bits.len() - idx - 1,
+        None => return Some((true, 0.0)),
+    };
+    let lowest = bits.len() - bits.iter().rposition(|bit| *bit)? - 1;
+    Some((highest < 1024 && highest - lowest < 53, value))
+}
+
+/// Whether a decimal literal can be represented exactly, and its value.
+///
+/// The literal is exact if printing its value with as many significant digits as the literal has
+/// yields the same digits.
+fn decimal_is_exact(text: &str) -> Option<(bool, f64)> {
+    let value = text.parse::<f64>().ok()?;
+    let (digits, exponent) = normalize(text);
+    if digits.is_empty() {
+        return Some((true, value));
+    }
+    if !value.is_finite() {
+        return Some((false, value));
+    }
+
+    let printed = format!("{:.*e}", digits.len() - 1, value);
+    Some((normalize(&printed) == (digits, exponent), value))
+}
+
+/// Get the significant digits of a decimal number and the exponent `e` such that the number is
+/// `0.digits * 10^e`, zero has no significant digits.
+fn normalize(text: &str) -> (String, i64) {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(idx) => (&text[..idx], text[idx + 1..].parse::<i64>().unwrap_or(0)),
+        None => (text, 0),
+    };
+    let (int, fract) = match mantissa.find('.') {
+        Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+        None => (mantissa, ""),
+    };
+
+    let digits = format!("{}{}", int, fract);
+    let significant = digits.trim_start_matches('0');
+    let leading_zeros = (digits.len() - significant.len()) as i64;
+    // trailing zeros are significant if they are written after the decimal point
+    let significant = if mantissa.contains('.') {
+        significant
+    } else {
+        significant.trim_end_matches('0')
+    };
+    if significant.trim_end_matches('0').is_empty() {
+        return (String::new(), 0);
+    }
+    (
+        significant.to_string(),
+        exponent + int.len() as i64 - leading_zeros,
+    )
+}
+
+/// Display a number the way JavaScript does for very large or small numbers, such as `1.5e+300`.
+fn display_number(value: f64) -> String {
+    let abs = value.abs();
+    if abs != 0.0 && !(1e-6..1e21).contains(&abs) && value.is_finite() {
+        let printed = format!("{:e}", value);
+        match printed.find('e') {
+            Some(idx) if !printed[idx + 1..].starts_with('-') => {
+                format!("{}e+{}", &printed[..idx], &printed[idx + 1..])
+            }
+            _ => printed,
+        }
+    } else if value.is_infinite() {
+        "Infinity".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+rule_tests! {
+    NoLossOfPrecision::default(),
+    err: {
+        "var x = 9007199254740993;",
+        "var x = 5123000000000000000000000000001;",
+        "var x = 1230000000000000000000000.0;",
+        "var x = .1230000000000000000000000;",
+        "var x = 2e999;",
+        "var x = 9007_1992547409_93;",
+        "var x = 0x20000000000001;",
+        "var x = 0X20_000_000_000_001;",
+        "var x = 0b100000000000000000000000000000000000000000000000000001;",
+        "var x = 0o400000000000000001;",
+        "var x = 0400000000000000001;"
+    },
+    ok: {
+        "var x = 12345;",
+        "var x = 123.456;",
+        "var x = -123.456;",
+        "var x = 0;",
+        "var x = 0.000;",
+        "var x = 123e34;",
+        "var x = 123.0e34;",
+        "var x = 12300000000000000000000000;",
+        "var x = 0.00000000000000000000000123;",
+        "var x = 9007199254740991;",
+        "var x = 9007199254740992;",
+        "var x = 0x1FFFFFFFFFFFFF;",
+        "var x = 0x20000000000000;",
+        "var x = 0b11111111111111111111111111111111111111111111111111111;",
+        "var x = 0o377777777777777777;",
+        "var x = 0377777777777777777;",
+        "var x = 089;",
+        "var x = 9007199254740993n;",
+        "var x = 1_000_000;",
+        "var x = 5.;"
+    }
+}
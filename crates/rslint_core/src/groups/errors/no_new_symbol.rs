@@ -46,10 +46,11 @@ impl CstRule for NoNewSymbol {
                         Applicability::MaybeIncorrect,
                     );
 
+                let new_token = new_expr.new_token()?;
+                ctx.fixer()
+                    .delete(new_token.clone())
+                    .eat_trailing_whitespace(new_token);
                 ctx.add_err(err);
-                ctx.fix()
-                    .delete(new_expr.new_token()?)
-                    .eat_trailing_whitespace(new_expr.new_token()?);
             }
         }
         None
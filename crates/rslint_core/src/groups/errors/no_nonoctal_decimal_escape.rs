@@ -0,0 +1,124 @@
+use crate::rule_prelude::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow `\8` and `\9` escape sequences in strings.
+
+    `\8` and `\9` are not octal escapes, they evaluate to the characters `8` and `9`. They are only
+    allowed for web compatibility, are deprecated, and are syntax errors in templates and in strict mode
+    in some engines, therefore they should not be used.
+
+    ## Invalid Code Examples
+
+    ```js
+    "\8";
+
+    "\9";
+
+    var foo = "w\8less";
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    "8";
+
+    "9";
+
+    var foo = "w8less";
+
+    "\\8";
+
+    "\u0038";
+    ```
+    */
+    #[derive(Default)]
+    NoNonoctalDecimalEscape,
+    errors,
+    "no-nonoctal-decimal-escape"
+}
+
+#[typetag::serde]
+impl CstRule for NoNonoctalDecimalEscape {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if token.kind() != STRING {
+            return None;
+        }
+        let text = token.text();
+        let start = usize::from(token.text_range().start());
+
+        let mut chars = text.char_indices();
+        let mut prev_escape = None;
+        while let Some((idx, c)) = chars.next() {
+            if c != '\\' {
+                prev_escape = None;
+                continue;
+            }
+            let (_, escaped) = match chars.next() {
+                Some(next) => next,
+                None => break,
+            };
+            if escaped != '8' && escaped != '9' {
+                prev_escape = Some(escaped);
+                continue;
+            }
+
+            let range = start + idx..start + idx + 2;
+            let unicode = format!("\\u003{}", escaped);
+            // `\0\8` would turn into the octal escape `\08` if the backslash was removed
+            let followed_null = prev_escape == Some('0');
+            prev_escape = Some(escaped);
+
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    format!("don't use the `\\{}` escape sequence", escaped),
+                )
+                .primary(range.clone(), "");
+            if !followed_null {
+                err = err.suggestion(
+                    range.clone(),
+                    "remove the backslash",
+                    escaped.to_string(),
+                    Applicability::Always,
+                );
+            }
+            err = err.suggestion(
+                range.clone(),
+                "replace the escape with a unicode escape",
+                unicode.clone(),
+                Applicability::Always,
+            );
+
+            if followed_null {
+                ctx.fixer().replace(range, unicode);
+            } else {
+                ctx.fixer().replace(range, escaped);
+            }
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+rule_tests! {
+    NoNonoctalDecimalEscape::default(),
+    err: {
+        r#""\8";"#,
+        r#""\9";"#,
+        r#"var foo = "w\8less";"#,
+        r#"var foo = 'foo\9bar';"#,
+        r#""\0\8";"#,
+        r#""\\\8";"#
+    },
+    ok: {
+        r#""\n";"#,
+        r#""8";"#,
+        r#""\\8";"#,
+        r#""\u0039";"#,
+        r#""\x39";"#,
+        r#"`\\9`;"#,
+        r#"/\8/;"#
+    }
+}
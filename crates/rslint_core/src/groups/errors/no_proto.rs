@@ -0,0 +1,119 @@
+use crate::rule_prelude::*;
+use ast::{AssignExpr, AssignOp, BracketExpr, DotExpr, Expr, PatternOrExpr};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow the use of the `__proto__` property.
+
+    The `__proto__` property has been deprecated since ECMAScript 3.1 and should not be used,
+    `Object.getPrototypeOf` and `Object.setPrototypeOf` should be used instead.
+
+    Using `__proto__` as a key in an object literal (`{ __proto__: foo }`) is allowed, since it
+    is a standard way to set the prototype of the new object.
+
+    ## Invalid Code Examples
+
+    ```js
+    var a = obj.__proto__;
+
+    var a = obj["__proto__"];
+
+    obj.__proto__ = b;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    var a = Object.getPrototypeOf(obj);
+
+    Object.setPrototypeOf(obj, b);
+
+    var c = { __proto__: a };
+    ```
+    */
+    #[derive(Default)]
+    NoProto,
+    errors,
+    "no-proto"
+}
+
+#[typetag::serde]
+impl CstRule for NoProto {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let (object, prop) = match node.kind() {
+            DOT_EXPR => {
+                let expr = node.to::<DotExpr>();
+                let prop = expr.prop()?;
+                if prop.syntax().text() != "__proto__" {
+                    return None;
+                }
+                (expr.object()?, prop.syntax().clone())
+            }
+            BRACKET_EXPR => {
+                let expr = node.to::<BracketExpr>();
+                let prop = match expr.prop()? {
+                    Expr::Literal(lit) if lit.inner_string_text()? == "__proto__" => lit,
+                    _ => return None,
+                };
+                (expr.object()?, prop.syntax().clone())
+            }
+            _ => return None,
+        };
+
+        let mut err = ctx
+            .err(self.name(), "the `__proto__` property is deprecated")
+            .primary(prop.trimmed_range(), "");
+
+        let assign = node
+            .parent()
+            .and_then(|parent| parent.try_to::<AssignExpr>())
+            .filter(|assign| matches!(assign.lhs(), Some(PatternOrExpr::Expr(lhs)) if lhs.syntax() == node));
+
+        err = match assign {
+            Some(assign) => {
+                if let (Some(AssignOp::Assign), Some(rhs)) = (assign.op(), assign.rhs()) {
+                    err.suggestion(
+                        assign.syntax().trimmed_range(),
+                        "use `Object.setPrototypeOf` instead",
+                        format!(
+                            "Object.setPrototypeOf({}, {})",
+                            object.syntax().trimmed_text(),
+                            rhs.syntax().trimmed_text()
+                        ),
+                        Applicability::MaybeIncorrect,
+                    )
+                } else {
+                    err.footer_help("use `Object.setPrototypeOf` instead")
+                }
+            }
+            None => err.suggestion(
+                node.trimmed_range(),
+                "use `Object.getPrototypeOf` instead",
+                format!("Object.getPrototypeOf({})", object.syntax().trimmed_text()),
+                Applicability::MaybeIncorrect,
+            ),
+        };
+
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    NoProto::default(),
+    err: {
+        "var a = test.__proto__;",
+        "var a = test['__proto__'];",
+        "a.__proto__ = b;",
+        "a['__proto__'] += b;"
+    },
+    ok: {
+        "var a = Object.getPrototypeOf(test);",
+        "Object.setPrototypeOf(a, b);",
+        "var a = { __proto__: b };",
+        "var a = test.proto;",
+        "var a = test[__proto__];",
+        "var __proto__ = null;"
+    }
+}
@@ -0,0 +1,84 @@
+use crate::rule_prelude::*;
+use ast::BinExpr;
+
+declare_lint! {
+    /**
+    Disallow comparisons where both sides are exactly the same.
+
+    Comparing an expression to itself is pointless, the result is always the same unless the value is `NaN`.
+    This is almost always a mistake, such as a typo in one of the operands. If you are trying to check
+    whether a value is `NaN`, use `Number.isNaN` instead.
+
+    ## Invalid Code Examples
+
+    ```js
+    if (x === x) {}
+
+    if (foo.bar > foo.bar) {}
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    if (x === y) {}
+
+    if (x.a === x.b) {}
+
+    if (Number.isNaN(x)) {}
+    ```
+    */
+    #[derive(Default)]
+    NoSelfCompare,
+    errors,
+    "no-self-compare"
+}
+
+#[typetag::serde]
+impl CstRule for NoSelfCompare {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let expr = node.try_to::<BinExpr>()?;
+        if !expr.comparison() {
+            return None;
+        }
+        let (lhs, rhs) = (expr.lhs()?, expr.rhs()?);
+        if !lhs.syntax().lexical_eq(rhs.syntax()) {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                "comparing an expression to itself is pointless",
+            )
+            .primary(
+                node.trimmed_range(),
+                "both sides of this comparison are the same",
+            )
+            .footer_help("use `Number.isNaN` if you are trying to check whether a value is `NaN`");
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    NoSelfCompare::default(),
+    err: {
+        "x === x;",
+        "if (x == x) {}",
+        "x !== x;",
+        "foo.bar > foo.bar;",
+        "foo[bar] <= foo[bar];",
+        "foo() >= foo();",
+        "a + b < a + b;",
+        "x === /* x */ x;"
+    },
+    ok: {
+        "x === y;",
+        "x.a === x.b;",
+        "x === x.a;",
+        "x + x;",
+        "x = x;",
+        "x && x;",
+        "foo(a) === foo(b);"
+    }
+}
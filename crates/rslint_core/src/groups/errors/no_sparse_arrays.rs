@@ -33,9 +33,11 @@ impl CstRule for NoSparseArrays {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
         let elems = node.try_to::<ast::ArrayExpr>()?.sparse_elements();
         if !elems.is_empty() {
-            let mut err = ctx.err(self.name(), "sparse arrays are not allowed");
+            let mut err = ctx
+                .err(self.name(), "sparse arrays are not allowed")
+                .primary(node.trimmed_range(), "");
             for elem in elems {
-                err = err.primary(elem, "");
+                err = err.secondary(elem, "this comma makes an empty slot");
             }
             err = err.footer_note(
                 "the sparse elements will become elements with a value of `undefined`",
@@ -51,10 +53,15 @@ rule_tests! {
     err: {
         "[,]",
         "[...2,, 3]",
-        "[4,,]"
+        "[4,,]",
+        "[1, , 3]",
+        "[, a]",
+        "[a, /* hole */ , b]"
     },
     ok: {
         "[1, 2]",
-        "[3,]"
+        "[3,]",
+        "[1, 2,]",
+        "[]"
     }
 }
@@ -0,0 +1,130 @@
+use crate::rule_prelude::*;
+use ast::{Decl, Stmt, VarDecl};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow statements after `return`, `throw`, `break`, and `continue` statements.
+
+    Statements after a `return`, `throw`, `break`, or `continue` statement in the same block are
+    never executed. This is usually a mistake, such as forgetting to remove code while refactoring.
+
+    Function declarations and `var` declarations without an initializer are hoisted, therefore they
+    are allowed after a terminating statement.
+
+    ## Invalid Code Examples
+
+    ```js
+    function foo() {
+        return 5;
+        bar();
+    }
+
+    for (const item of items) {
+        continue;
+        console.log(item);
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo() {
+        return bar();
+
+        function bar() {
+            return 5;
+        }
+    }
+
+    function baz() {
+        if (qux) {
+            return 5;
+        }
+        return 6;
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoUnreachable,
+    errors,
+    "no-unreachable"
+}
+
+/// Nodes whose children are a list of statements.
+const STMT_LISTS: [SyntaxKind; 5] = [SCRIPT, MODULE, BLOCK_STMT, CASE_CLAUSE, DEFAULT_CLAUSE];
+
+const TERMINATORS: [SyntaxKind; 4] = [RETURN_STMT, THROW_STMT, BREAK_STMT, CONTINUE_STMT];
+
+#[typetag::serde]
+impl CstRule for NoUnreachable {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !STMT_LISTS.contains(&node.kind()) {
+            return None;
+        }
+        let mut stmts = node.children().filter_map(|child| child.try_to::<Stmt>());
+        let terminator = stmts.find(|stmt| TERMINATORS.contains(&stmt.syntax().kind()))?;
+        let unreachable = stmts.filter(|stmt| !is_hoisted(stmt)).collect::<Vec<_>>();
+        let (first, last) = (unreachable.first()?, unreachable.last()?);
+
+        let keyword = terminator.syntax().first_token()?;
+        let err = ctx
+            .err(self.name(), "unreachable code")
+            .primary(
+                first
+                    .syntax()
+                    .trimmed_range()
+                    .cover(last.syntax().trimmed_range()),
+                "this code is never executed...",
+            )
+            .secondary(
+                terminator.syntax().trimmed_range(),
+                format!(
+                    "...because this `{}` statement always executes before it",
+                    keyword.text()
+                ),
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Whether a statement is allowed after a terminator because it is hoisted or is a plain `;`.
+fn is_hoisted(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::EmptyStmt(_) | Stmt::Decl(Decl::FnDecl(_)) => true,
+        Stmt::Decl(Decl::VarDecl(decl)) => is_hoisted_var(decl),
+        _ => false,
+    }
+}
+
+fn is_hoisted_var(decl: &VarDecl) -> bool {
+    decl.is_var()
+        && decl
+            .declared()
+            .all(|declarator| declarator.value().is_none())
+}
+
+rule_tests! {
+    NoUnreachable::default(),
+    err: {
+        "function foo() { return 5; bar(); }",
+        "function foo() { throw new Error(); var a = 5; }",
+        "while (true) { break; foo(); }",
+        "for (;;) { continue; foo(); bar(); }",
+        "switch (a) { case 1: return; foo(); }",
+        "function foo() { return; let a; }",
+        "function foo() { return; class A {} }",
+        "function foo() { { return; foo(); } }"
+    },
+    ok: {
+        "function foo() { return 5; }",
+        "function foo() { return bar(); function bar() {} }",
+        "function foo() { return; var a, b; }",
+        "function foo() { return;; }",
+        "function foo() { if (a) { return; } bar(); }",
+        "function foo() { { return; } }",
+        "switch (a) { case 1: break; case 2: foo(); }",
+        "while (true) { if (a) break; foo(); }"
+    }
+}
@@ -1,6 +1,6 @@
 use crate::rule_prelude::*;
 use ast::{BinExpr, BinOp, Expr, UnaryOp};
-use rslint_parser::{TextRange, TextSize};
+use rslint_parser::TextRange;
 use SyntaxKind::*;
 
 declare_lint! {
@@ -43,12 +43,12 @@ impl CstRule for NoUnsafeNegation {
 
             if let Expr::UnaryExpr(unary) = expr.lhs()? {
                 if unary.op()? == UnaryOp::LogicalNot {
-                    let unary_node = unary.expr()?.syntax().clone();
-                    let no_op_text = &node.trimmed_text().to_string()[1..];
-                    let mut eq_expr = format!("(!{}", no_op_text);
-                    eq_expr.insert(usize::from(unary_node.trimmed_text().len()) + 2, ')');
+                    let operand = unary.expr()?.syntax().trimmed_text().to_string();
+                    let op = expr.op_token()?;
+                    let rhs = expr.rhs()?.syntax().trimmed_text().to_string();
+                    let negated = format!("!({} {} {})", operand, op.text(), rhs);
                     let rest_range = TextRange::new(
-                        node.trimmed_range().start() + TextSize::from(1),
+                        unary.op_token()?.text_range().end(),
                         node.trimmed_range().end(),
                     );
 
@@ -60,20 +60,30 @@ impl CstRule for NoUnsafeNegation {
                         .primary(
                             unary.op_token().unwrap(),
                             format!(
-                                "precedence makes this expression equivalent to `{}`",
-                                eq_expr
+                                "precedence makes this expression equivalent to `(!{}) {} {}`",
+                                operand,
+                                op.text(),
+                                rhs
                             ),
                         )
                         .secondary(rest_range, "`!` is not negating this expression")
                         .suggestion_with_labels(
                             expr.range(),
-                            "wrap the instanceof check in parentheses",
-                            format!("!({})", no_op_text),
+                            if op.kind() == T![in] {
+                                "wrap the `in` check in parentheses"
+                            } else {
+                                "wrap the `instanceof` check in parentheses"
+                            },
+                            negated.clone(),
                             Applicability::MaybeIncorrect,
-                            vec![1..2, no_op_text.len() + 2..no_op_text.len() + 3],
-                        );
+                            vec![1..2, negated.len() - 1..negated.len()],
+                        )
+                        .footer_note(format!(
+                            "to negate the whole expression, write `{}`",
+                            negated
+                        ));
 
-                    ctx.fix().wrap(node.add_start(1), Wrapping::Parens);
+                    ctx.fixer().wrap(node.add_start(1), Wrapping::Parens);
                     ctx.add_err(err);
                 }
             }
@@ -86,6 +96,9 @@ rule_tests! {
     NoUnsafeNegation::default(),
     err: {
         "!foo in bar",
+        "if (!key in obj) {}",
+        "! foo instanceof Foo",
+        "!!foo in bar",
         "![5] instanceof !4",
         /// ignore
         "!!!!!instanceof !!foo instanceof !!bar"
@@ -95,6 +108,8 @@ rule_tests! {
         "(!foo) instanceof bar",
         "key in bar",
         "bar instanceof bar",
+        "!(key in obj)",
+        "!(foo instanceof Foo)",
         /// ignore
         "1 in [1, 1, 1, ((!1) in [1111111111, 111])]"
     }
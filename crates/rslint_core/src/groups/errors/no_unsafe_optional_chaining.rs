@@ -0,0 +1,312 @@
+use crate::rule_prelude::*;
+use ast::{AssignExpr, AssignOp, BinExpr, BinOp, Pattern, PatternOrExpr, UnaryExpr, UnaryOp};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow the use of optional chaining in contexts where `undefined` is not allowed.
+
+    Optional chaining (`?.`) short circuits to `undefined` if the object is `null` or `undefined`.
+    Using the result of an optional chain in a place where `undefined` throws a `TypeError`, such as calling it,
+    accessing a property on it, or destructuring it, defeats the purpose of the optional chain.
+
+    By default arithmetic on the result of an optional chain is also disallowed, since it
+    produces `NaN` if the chain short circuits. This can be turned off with `disallowArithmeticOperators`.
+
+    ## Invalid Code Examples
+
+    ```js
+    (obj?.foo)();
+
+    (obj?.foo).bar;
+
+    new (obj?.foo)();
+
+    const { bar } = obj?.foo;
+
+    [...obj?.foo];
+
+    for (const bar of obj?.foo) {}
+
+    bar in obj?.foo;
+
+    class A extends obj?.foo {}
+
+    obj?.foo + 1;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    (obj?.foo)?.();
+
+    obj?.foo?.bar;
+
+    obj?.foo();
+
+    (obj?.foo ?? bar)();
+
+    const { bar } = obj?.foo || {};
+
+    ({ ...obj?.foo });
+    ```
+    */
+    #[serde(default)]
+    NoUnsafeOptionalChaining,
+    errors,
+    tags(Recommended),
+    "no-unsafe-optional-chaining",
+    /// Whether to disallow arithmetic operations on the result of an optional chain, `true` by default.
+    pub disallow_arithmetic_operators: bool
+}
+
+impl Default for NoUnsafeOptionalChaining {
+    fn default() -> Self {
+        Self {
+            disallow_arithmetic_operators: true,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoUnsafeOptionalChaining {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !is_chain_link(node) || !is_optional_chain(node) {
+            return None;
+        }
+        // only check the outermost expression of the chain, `a?.b.c` short circuits as a whole
+        let parent = node.parent()?;
+        if is_chain_link(&parent) && parent.first_child().as_ref() == Some(node) {
+            return None;
+        }
+
+        let (parent, child) = short_circuit_context(node)?;
+        let unsafe_use = match parent.kind() {
+            CALL_EXPR | DOT_EXPR | BRACKET_EXPR
+                if parent.first_child().as_ref() == Some(&child)
+                    && parent.token_with_kind(QUESTIONDOT).is_none() =>
+            {
+                Some(if parent.kind() == CALL_EXPR {
+                    "calling"
+                } else {
+                    "accessing a property of"
+                })
+            }
+            NEW_EXPR | TEMPLATE if parent.first_child().as_ref() == Some(&child) => {
+                Some(if parent.kind() == NEW_EXPR {
+                    "constructing"
+                } else {
+                    "using as a template tag"
+                })
+            }
+            SPREAD_ELEMENT => Some("spreading"),
+            FOR_OF_STMT
+                if parent
+                    .to::<ast::ForOfStmt>()
+                    .right()
+                    .map(|e| e.syntax().clone())
+                    .as_ref()
+                    == Some(&child) =>
+            {
+                Some("iterating over")
+            }
+            CLASS_DECL | CLASS_EXPR => Some("extending"),
+            DECLARATOR => {
+                let decl = parent.to::<ast::Declarator>();
+                match decl.pattern() {
+                    Some(Pattern::ObjectPattern(_)) | Some(Pattern::ArrayPattern(_))
+                        if decl.value().map(|e| e.syntax().clone()).as_ref() == Some(&child) =>
+                    {
+                        Some("destructuring")
+                    }
+                    _ => None,
+                }
+            }
+            ASSIGN_EXPR => self.check_assign(parent.to(), &child),
+            BIN_EXPR => self.check_bin(parent.to(), &child),
+            UNARY_EXPR => match parent.to::<UnaryExpr>().op() {
+                Some(UnaryOp::Plus) | Some(UnaryOp::Minus)
+                    if self.disallow_arithmetic_operators =>
+                {
+                    Some("doing arithmetic with")
+                }
+                _ => None,
+            },
+            _ => None,
+        }?;
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "unsafe usage of optional chaining, {} the result of it may throw or produce an unexpected value",
+                    unsafe_use
+                ),
+            )
+            .primary(
+                node.trimmed_range(),
+                "this short circuits to `undefined` when the object is `null` or `undefined`",
+            )
+            .secondary(parent.trimmed_range(), "");
+        ctx.add_err(err);
+        None
+    }
+}
+
+impl NoUnsafeOptionalChaining {
+    fn check_assign(&self, assign: AssignExpr, child: &SyntaxNode) -> Option<&'static str> {
+        if assign.rhs()?.syntax() != child {
+            return None;
+        }
+        match (assign.lhs()?, assign.op()?) {
+            (PatternOrExpr::Pattern(Pattern::ObjectPattern(_)), _)
+            | (PatternOrExpr::Pattern(Pattern::ArrayPattern(_)), _) => Some("destructuring"),
+            (
+                _,
+                AssignOp::AddAssign
+                | AssignOp::SubtractAssign
+                | AssignOp::TimesAssign
+                | AssignOp::RemainderAssign
+                | AssignOp::ExponentAssign,
+            ) if self.disallow_arithmetic_operators => Some("doing arithmetic with"),
+            _ => None,
+        }
+    }
+
+    fn check_bin(&self, bin: BinExpr, child: &SyntaxNode) -> Option<&'static str> {
+        match bin.op()? {
+            BinOp::In | BinOp::Instanceof if bin.rhs()?.syntax() == child => {
+                Some("checking the right hand side of")
+            }
+            BinOp::Plus
+            | BinOp::Minus
+            | BinOp::Times
+            | BinOp::Divide
+            | BinOp::Remainder
+            | BinOp::Exponent
+                if self.disallow_arithmetic_operators =>
+            {
+                Some("doing arithmetic with")
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_chain_link(node: &SyntaxNode) -> bool {
+    matches!(node.kind(), DOT_EXPR | BRACKET_EXPR | CALL_EXPR)
+}
+
+/// Whether the expression is part of a chain which contains an optional chain, such as `a?.b.c`.
+fn is_optional_chain(node: &SyntaxNode) -> bool {
+    let mut cur = node.clone();
+    while is_chain_link(&cur) {
+        if cur.token_with_kind(QUESTIONDOT).is_some() {
+            return true;
+        }
+        cur = match cur.first_child() {
+            Some(child) => child,
+            None => return false,
+        };
+    }
+    false
+}
+
+/// Go up through the expressions which may still evaluate to the short circuited `undefined`,
+/// such as parentheses or the right hand side of `||`, returning the parent which uses the value
+/// and the child it uses.
+fn short_circuit_context(node: &SyntaxNode) -> Option<(SyntaxNode, SyntaxNode)> {
+    let mut child = node.clone();
+    loop {
+        let parent = child.parent()?;
+        let passes_through = match parent.kind() {
+            GROUPING_EXPR | AWAIT_EXPR => true,
+            COND_EXPR => parent.first_child().as_ref() != Some(&child),
+            SEQUENCE_EXPR => parent.last_child().as_ref() == Some(&child),
+            BIN_EXPR => {
+                let bin = parent.to::<BinExpr>();
+                match bin.op()? {
+                    BinOp::LogicalAnd => true,
+                    BinOp::LogicalOr | BinOp::NullishCoalescing => {
+                        bin.rhs().map(|rhs| rhs.syntax().clone()).as_ref() == Some(&child)
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+        if !passes_through {
+            return Some((parent, child));
+        }
+        child = parent;
+    }
+}
+
+rule_tests! {
+    NoUnsafeOptionalChaining::default(),
+    err: {
+        "(a?.b)();",
+        "(a?.b).c;",
+        "(a?.b)[c];",
+        "(a.b?.c.d)();",
+        "(a?.b())();",
+        "new (a?.b)();",
+        "(a?.b)`foo`;",
+        "[...a?.b];",
+        "foo(...a?.b);",
+        "const { c } = a?.b;",
+        "const [c] = a?.b;",
+        "({ c } = a?.b);",
+        "for (const c of a?.b) {}",
+        "c in a?.b;",
+        "c instanceof a?.b;",
+        "class A extends a?.b {}",
+        "(c && a?.b)();",
+        "(c || a?.b).d;",
+        "(c ? a?.b : d)();",
+        "(c, a?.b)();",
+        "async function f() { (await a?.b)(); }",
+        "a?.b + 1;",
+        "1 - a?.b;",
+        "-a?.b;",
+        "c += a?.b;"
+    },
+    ok: {
+        "a?.b?.();",
+        "(a?.b)?.();",
+        "(a?.b)?.c;",
+        "a?.b.c();",
+        "a?.b();",
+        "a.b();",
+        "(a.b)();",
+        "(a?.b ?? c)();",
+        "(a?.b || c).d;",
+        "(a?.b, c)();",
+        "(a?.b ? c : d)();",
+        "({ ...a?.b });",
+        "const c = a?.b;",
+        "const { c } = a?.b || {};",
+        "for (const c in a?.b) {}",
+        "a?.b in c;",
+        "if (a?.b) {}",
+        "a?.b === 1;",
+        "foo(a?.b);",
+        "c[a?.b];"
+    }
+}
+
+rule_tests! {
+    allow_arithmetic_valid,
+    allow_arithmetic_invalid,
+    NoUnsafeOptionalChaining {
+        disallow_arithmetic_operators: false,
+    },
+    err: {
+        "(a?.b)();"
+    },
+    ok: {
+        "a?.b + 1;",
+        "-a?.b;",
+        "c += a?.b;"
+    }
+}
@@ -0,0 +1,123 @@
+use crate::rule_prelude::*;
+use ast::{CallExpr, DotExpr, Expr, UnaryOp};
+
+declare_lint! {
+    /**
+    Disallow unnecessary `.call()` and `.apply()`.
+
+    `Function.prototype.call` and `Function.prototype.apply` can be used to call a function with
+    a specific `this` value. However, if the `this` value is the same as the one the function would
+    be called with normally, the `.call()` or `.apply()` is useless and the function can be called directly.
+
+    ## Invalid Code Examples
+
+    ```js
+    // These are the same as `foo(1, 2, 3)`
+    foo.call(undefined, 1, 2, 3);
+    foo.apply(null, [1, 2, 3]);
+
+    // These are the same as `obj.foo(1, 2, 3)`
+    obj.foo.call(obj, 1, 2, 3);
+    obj.foo.apply(obj, [1, 2, 3]);
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    // The `this` binding is different
+    foo.call(obj, 1, 2, 3);
+    obj.foo.call(null, 1, 2, 3);
+    obj.foo.apply(otherObj, [1, 2, 3]);
+    ```
+    */
+    #[derive(Default)]
+    NoUselessCall,
+    errors,
+    "no-useless-call"
+}
+
+#[typetag::serde]
+impl CstRule for NoUselessCall {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        let callee = match call.callee()? {
+            Expr::DotExpr(dot) if dot.opt_chain_token().is_none() => dot,
+            _ => return None,
+        };
+        let method = callee.prop()?.syntax().text().to_string();
+        let args = call.arguments()?.syntax().children().collect::<Vec<_>>();
+
+        let valid_args = match method.as_str() {
+            "call" => !args.is_empty(),
+            "apply" => matches!(args.len(), 1 | 2),
+            _ => false,
+        };
+        if !valid_args {
+            return None;
+        }
+        let this_arg = args.first()?.try_to::<Expr>()?;
+
+        let func = callee.object()?;
+        let unchanged_this = match func.syntax().try_to::<DotExpr>() {
+            Some(member) => this_arg.syntax().lexical_eq(member.object()?.syntax()),
+            None => is_nullish(&this_arg),
+        };
+        if !unchanged_this {
+            return None;
+        }
+
+        let err = ctx
+            .err(self.name(), format!("unnecessary `.{}()`", method))
+            .primary(
+                callee.prop()?.syntax().trimmed_range(),
+                format!(
+                    "`{}` is already called with this `this` value",
+                    func.syntax().trimmed_text()
+                ),
+            )
+            .footer_help(format!(
+                "call `{}` directly instead",
+                func.syntax().trimmed_text()
+            ));
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Whether the expression is `null`, `undefined`, or `void <expr>`.
+fn is_nullish(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(lit) => lit.is_null(),
+        Expr::NameRef(name) => name.syntax().text() == "undefined",
+        Expr::UnaryExpr(unary) => unary.op() == Some(UnaryOp::Void),
+        _ => false,
+    }
+}
+
+rule_tests! {
+    NoUselessCall::default(),
+    err: {
+        "foo.call(undefined, a, b);",
+        "foo.call(null, a);",
+        "foo.call(void 0);",
+        "foo.apply(null, [a, b]);",
+        "foo.apply(undefined, args);",
+        "obj.foo.call(obj, a, b);",
+        "obj.foo.apply(obj, [a, b]);",
+        "a.b.c.call(a.b, 1);",
+        "a.b(x).c.apply(a.b(x), []);"
+    },
+    ok: {
+        "foo.call(obj, a);",
+        "foo.apply(obj, [a]);",
+        "obj.foo.call(null, a);",
+        "obj.foo.call(other, a);",
+        "obj.foo.apply(otherObj, [a, b]);",
+        "foo.call();",
+        "foo.apply(null, a, b);",
+        "foo.call(...args);",
+        "foo.bind(null, a);",
+        "a?.call(null, b);",
+        "foo(null, a);"
+    }
+}
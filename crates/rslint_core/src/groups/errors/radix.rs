@@ -0,0 +1,181 @@
+use crate::rule_prelude::*;
+use crate::util::is_parse_int;
+use ast::{CallExpr, Expr};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce the use of the radix argument in `parseInt()`.
+
+    `parseInt` takes an optional second argument which is the radix (base) to parse the
+    string with. If it is omitted, strings starting with `0x` are parsed as hexadecimal, and
+    some older engines parse strings starting with `0` as octal. Always specifying the radix
+    avoids this confusion.
+
+    With the `"as-needed"` mode, a radix of `10` is reported as redundant instead, since that
+    is what `parseInt` does for decimal strings by default.
+
+    ## Invalid Code Examples
+
+    ```js
+    const num = parseInt("071");
+
+    const num = Number.parseInt(foo);
+
+    const num = parseInt("071", "abc");
+
+    const num = parseInt("071", 37);
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const num = parseInt("071", 10);
+
+    const num = Number.parseInt(foo, 16);
+
+    const num = parseFloat(foo);
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    Radix,
+    errors,
+    "radix",
+    /// Either `"always"` (the default) to require a radix, or `"as-needed"` to disallow a radix of `10`.
+    pub mode: Mode
+}
+
+/// When a radix is required.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+    #[default]
+    Always,
+    AsNeeded,
+}
+
+#[typetag::serde]
+impl CstRule for Radix {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        if !is_parse_int(&call.callee()?) {
+            return None;
+        }
+        let args = call.arguments()?.syntax().children().collect::<Vec<_>>();
+        if args.iter().any(|arg| arg.kind() == SPREAD_ELEMENT) {
+            return None;
+        }
+
+        match args.as_slice() {
+            [] => {
+                let err = ctx
+                    .err(self.name(), "missing parameters in call to `parseInt`")
+                    .primary(node.trimmed_range(), "");
+                ctx.add_err(err);
+            }
+            [value] if self.mode != Mode::AsNeeded => {
+                let err = ctx
+                    .err(self.name(), "missing radix parameter in call to `parseInt`")
+                    .primary(node.trimmed_range(), "")
+                    .suggestion(
+                        value.trimmed_range(),
+                        "add a radix of 10 to parse the value as a decimal number",
+                        format!("{}, 10", value.trimmed_text()),
+                        Applicability::MaybeIncorrect,
+                    );
+                ctx.add_err(err);
+            }
+            [value, radix, ..] => {
+                let radix_value = match radix.try_to::<Expr>()? {
+                    Expr::Literal(lit) => Some(lit.as_number()),
+                    Expr::NameRef(name) if name.syntax().text() == "undefined" => Some(None),
+                    _ => None,
+                };
+
+                match radix_value {
+                    Some(Some(num)) if num == 10.0 && self.mode == Mode::AsNeeded => {
+                        let range = value.trimmed_range().end()..radix.trimmed_range().end();
+                        let err = ctx
+                            .err(
+                                self.name(),
+                                "redundant radix parameter in call to `parseInt`",
+                            )
+                            .primary(radix.trimmed_range(), "")
+                            .suggestion(
+                                range,
+                                "remove the radix, decimal numbers are parsed by default",
+                                "",
+                                Applicability::MaybeIncorrect,
+                            );
+                        ctx.add_err(err);
+                    }
+                    Some(Some(num)) if num.fract() == 0.0 && (2.0..=36.0).contains(&num) => {}
+                    Some(_) => {
+                        let err = ctx
+                            .err(self.name(), "invalid radix parameter in call to `parseInt`")
+                            .primary(
+                                radix.trimmed_range(),
+                                "the radix must be an integer between 2 and 36",
+                            );
+                        ctx.add_err(err);
+                    }
+                    // the radix may be any expression which we cannot check
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+rule_tests! {
+    Radix::default(),
+    err: {
+        "parseInt(\"10\");",
+        "parseInt();",
+        "Number.parseInt(foo);",
+        "Number.parseInt();",
+        "parseInt(\"10\", \"10\");",
+        "parseInt(\"10\", 1);",
+        "parseInt(\"10\", 37);",
+        "parseInt(\"10\", 10.5);",
+        "parseInt(\"10\", undefined);",
+        "parseInt(\"10\", null);"
+    },
+    ok: {
+        "parseInt(\"10\", 10);",
+        "parseInt(\"10\", 2);",
+        "parseInt(\"10\", 36);",
+        "parseInt(\"10\", radix);",
+        "parseInt(\"10\", foo.bar);",
+        "Number.parseInt(\"10\", 16);",
+        "parseInt(...args);",
+        "parseFloat(\"10\");",
+        "foo.parseInt(\"10\");",
+        "Number.parseFloat(foo);"
+    }
+}
+
+rule_tests! {
+    as_needed_valid,
+    as_needed_invalid,
+    Radix {
+        mode: Mode::AsNeeded,
+    },
+    err: {
+        "parseInt(\"10\", 10);",
+        "Number.parseInt(foo, 10);",
+        "parseInt();",
+        "parseInt(\"10\", 40);"
+    },
+    ok: {
+        "parseInt(\"10\");",
+        "parseInt(\"10\", 16);",
+        "Number.parseInt(foo);"
+    }
+}
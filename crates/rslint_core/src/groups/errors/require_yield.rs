@@ -31,7 +31,7 @@ declare_lint! {
   #[derive(Default)]
   RequireYield,
   errors,
-  "require_yield"
+  "require-yield"
 }
 
 #[typetag::serde]
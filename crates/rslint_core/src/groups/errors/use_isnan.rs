@@ -11,7 +11,7 @@ declare_lint! {
 
     In JavaScript, `NaN` is unique, it is not equal to anything, including itself! therefore
     any comparisons to it will either always yield `true` or `false`. Therefore you should
-    use `isNaN(/* num */)` instead to test if a value is `NaN`. This rule is aimed at removing this footgun.
+    use `Number.isNaN(/* num */)` instead to test if a value is `NaN`. This rule is aimed at removing this footgun.
 
     ## Invalid Code Examples
 
@@ -28,11 +28,11 @@ declare_lint! {
     ## Correct Code Examples
 
     ```js
-    if (isNaN(foo)) {
+    if (Number.isNaN(foo)) {
         /* */
     }
 
-    if (!isNaN(foo)) {
+    if (!Number.isNaN(foo)) {
         /* */
     }
     ```
@@ -69,9 +69,9 @@ impl CstRule for UseIsnan {
                     return None;
                 }
 
-                let opposite = if expr.lhs().filter(|e| e.text() == "NaN").is_some() {
+                let opposite = if expr.lhs().filter(is_nan).is_some() {
                     expr.rhs()?
-                } else if expr.rhs().filter(|e| e.text() == "NaN").is_some() {
+                } else if expr.rhs().filter(is_nan).is_some() {
                     expr.lhs()?
                 } else {
                     return None;
@@ -111,15 +111,15 @@ impl CstRule for UseIsnan {
                 if op == op!(==) || op == op!(===) {
                     err = err.suggestion(
                         expr.range(),
-                        "use `isNaN` instead",
-                        format!("isNaN({})", opposite),
+                        "use `Number.isNaN` instead",
+                        format!("Number.isNaN({})", opposite),
                         Applicability::Always,
                     );
                 } else if op == op!(!=) || op == op!(!==) {
                     err = err.suggestion(
                         expr.range(),
-                        "use `isNaN` instead",
-                        format!("!isNaN({})", opposite),
+                        "use `Number.isNaN` instead",
+                        format!("!Number.isNaN({})", opposite),
                         Applicability::Always,
                     );
                 }
@@ -170,8 +170,7 @@ impl CstRule for UseIsnan {
 
                 let second_arg_is_nan = expr
                     .arguments()
-                    .map(|a| a.args().nth(1).filter(|x| x.text() == "NaN"))
-                    .flatten()
+                    .and_then(|a| a.args().nth(1).filter(is_nan))
                     .is_some();
 
                 if (is_indexof_static_prop(&callee)
@@ -196,6 +195,11 @@ impl CstRule for UseIsnan {
     }
 }
 
+/// Whether an expression is a reference to the global `NaN`.
+fn is_nan(expr: &Expr) -> bool {
+    matches!(expr, Expr::NameRef(name) if name.syntax().text() == "NaN")
+}
+
 const INDEX_OF_NAMES: [&str; 2] = ["lastIndexOf", "indexOf"];
 
 fn is_indexof_static_prop(expr: &Expr) -> bool {
@@ -232,7 +236,9 @@ rule_tests! {
         "NaN <= \"abc\";",
         "\"abc\" <= NaN;",
         "NaN >= \"abc\";",
-        "\"abc\" >= NaN;"
+        "\"abc\" >= NaN;",
+        "if (x == NaN) {}",
+        "x !== NaN;"
     },
     ok: {
         "var x = NaN;",
@@ -240,6 +246,7 @@ rule_tests! {
         "isNaN(123) !== true;",
         "Number.isNaN(NaN) === true;",
         "Number.isNaN(123) !== true;",
+        "Number.isNaN(x);",
         "foo(NaN + 1);",
         "foo(1 + NaN);",
         "foo(NaN - 1)",
@@ -1,15 +1,16 @@
 use crate::rule_prelude::*;
-use ast::{BinExpr, Expr, Literal, UnaryExpr};
+use ast::{BinExpr, BinOp, Expr, Literal, UnaryExpr};
 
 declare_lint! {
     /**
     Enforce the use of valid string literals in a `typeof` comparison.
 
     `typeof` can only return a small set of strings, `undefined`, `object`,
-    `boolean`, `number`, `string` or `function`, and if you provide
+    `boolean`, `number`, `string`, `function`, `symbol` or `bigint`, and if you provide
     an invalid value, it's most likely a typo, and the comparison
     will always return `false`.
-    This behaviour will be denied by this rule.
+    This behaviour will be denied by this rule. Only equality comparisons
+    (`==`, `===`, `!=` and `!==`) are checked.
 
     ## Invalid Code Examples
     ```js
@@ -64,7 +65,10 @@ impl ValidTypeof {
 impl CstRule for ValidTypeof {
     fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
         let expr = node.try_to::<BinExpr>()?;
-        if !expr.comparison() {
+        if !matches!(
+            expr.op()?,
+            BinOp::Equality | BinOp::StrictEquality | BinOp::Inequality | BinOp::StrictInequality
+        ) {
             return None;
         }
         let (lhs, rhs) = (expr.lhs()?, expr.rhs()?);
@@ -110,10 +114,7 @@ impl CstRule for ValidTypeof {
                 .primary(literal_range, "");
 
             let err = if let Some(suggestion) = suggestion {
-                err.footer_help(format!(
-                    "a type with a similair name exists: `{}`",
-                    suggestion
-                ))
+                err.footer_help(format!("did you mean `{}`?", suggestion))
             } else {
                 err
             };
@@ -137,13 +138,19 @@ rule_tests! {
         r#"typeof foo === "strnig""#,
         r#"typeof foo == "undefimed""#,
         r#"typeof bar != "nunber""#,
-        r#"typeof bar !== "fucntion""#
+        r#"typeof bar !== "fucntion""#,
+        r#""strng" === typeof foo"#,
+        r#"typeof foo === "bigInt""#
     },
     ok: {
         r#"typeof foo === "string""#,
         r#"typeof bar == "undefined""#,
         "typeof foo === baz",
         "typeof foo === 4",
-        "typeof bar === typeof qux"
+        "typeof bar === typeof qux",
+        r#"typeof foo === "symbol""#,
+        r#"typeof foo !== "bigint""#,
+        r#"typeof foo < "strng""#,
+        r#"foo === "strng""#
     }
 }
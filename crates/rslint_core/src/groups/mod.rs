@@ -1,12 +1,86 @@
 //! All of the groups of built in rules in the linter.
 
-pub mod errors;
-pub mod regex;
-pub mod style;
+use crate::CstRule;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::RwLock;
 
-pub use errors::errors;
-pub use regex::regex;
-pub use style::style;
+/// A function returning all of the rules in a group.
+pub type GroupRules = fn() -> Vec<Box<dyn CstRule>>;
+
+/// Declare the modules of the built in groups, each module must have a function of the same name
+/// which returns the rules of the group, such as the one declared by [`group!`](crate::group).
+macro_rules! builtin_groups {
+    ($($group:ident),* $(,)?) => {
+        $(
+            pub mod $group;
+            pub use $group::$group;
+        )*
+
+        /// The name and rules of every built in group, in the order their rules are loaded by
+        /// [`CstRuleStore::builtins`](crate::CstRuleStore::builtins).
+        pub const BUILTIN_GROUPS: &[(&str, GroupRules)] = &[$((stringify!($group), $group as GroupRules)),*];
+    };
+}
+
+builtin_groups!(errors, style, regex);
+
+/// The groups which can be queried by name, the built in groups and any registered groups.
+#[derive(Debug, Clone)]
+pub(crate) struct GroupRegistry {
+    groups: HashMap<&'static str, GroupRules>,
+}
+
+impl GroupRegistry {
+    /// A registry with only the built in groups.
+    pub(crate) fn builtin() -> Self {
+        Self {
+            groups: BUILTIN_GROUPS.iter().copied().collect(),
+        }
+    }
+
+    pub(crate) fn register(&mut self, name: &'static str, rules: GroupRules) {
+        self.groups.insert(name, rules);
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<GroupRules> {
+        self.groups.get(name).copied()
+    }
+
+    /// The name and rules of every group, sorted by name.
+    pub(crate) fn all(&self) -> Vec<(&'static str, GroupRules)> {
+        let mut groups = self
+            .groups
+            .iter()
+            .map(|(name, rules)| (*name, *rules))
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|(name, _)| *name);
+        groups
+    }
+}
+
+static GROUPS: Lazy<RwLock<GroupRegistry>> = Lazy::new(|| RwLock::new(GroupRegistry::builtin()));
+
+/// Register a group of rules at runtime, which can then be queried using
+/// [`get_group_rules_by_name`](crate::get_group_rules_by_name).
+///
+/// This allows hosts to define their own categories of rules. Registering a group with
+/// the same name as an existing group, including a built in group, replaces it.
+pub fn register_group(name: &'static str, rules: GroupRules) {
+    GROUPS.write().unwrap().register(name, rules);
+}
+
+/// Get the name and rules of every built in and registered group, sorted by name.
+pub fn all_groups() -> impl Iterator<Item = (&'static str, Vec<Box<dyn CstRule>>)> {
+    let groups = GROUPS.read().unwrap().all();
+    groups.into_iter().map(|(name, rules)| (name, rules()))
+}
+
+/// Get the rules of a built in or registered group.
+pub(crate) fn group_rules(name: &str) -> Option<Vec<Box<dyn CstRule>>> {
+    let rules = GROUPS.read().unwrap().get(name)?;
+    Some(rules())
+}
 
 /// Macro for easily making a rule group hashmap.
 /// This will call `::new()` on each rule.  
@@ -13,27 +13,31 @@ use std::sync::Mutex;
 use std::{collections::HashMap, ops::Range};
 
 type RegexResult = Result<(Regex, Range<usize>), (Range<usize>, String)>;
+type RegexKey = (Range<usize>, String);
 
-pub(crate) static REGEX_MAP: Lazy<Mutex<HashMap<Range<usize>, RegexResult>>> =
+/// Parsed regexes keyed by their range and source text, the text is needed because the map is shared by every file.
+pub(crate) static REGEX_MAP: Lazy<Mutex<HashMap<RegexKey, RegexResult>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
 group! {
     /// Rules which relate to regular expressions.
     regex,
     no_invalid_regexp::NoInvalidRegexp,
-    simplify_regex::SimplifyRegex
+    simplify_regex::SimplifyRegex,
+    no_useless_backreference::NoUselessBackreference
 }
 
 pub(crate) fn maybe_parse_and_store_regex(
     node: &SyntaxNode,
     file_id: usize,
 ) -> Option<RegexResult> {
+    let key = (node.as_range(), node.text().to_string());
     let mut map_handle = REGEX_MAP.lock().unwrap();
-    if let Some(r) = map_handle.get(&node.as_range()) {
+    if let Some(r) = map_handle.get(&key) {
         return Some(r.to_owned());
     }
     let r = collect_regex_from_node(node, file_id)?;
-    map_handle.insert(node.as_range(), r.clone());
+    map_handle.insert(key, r.clone());
     Some(r)
 }
 
@@ -0,0 +1,278 @@
+use super::maybe_parse_and_store_regex;
+use crate::rule_prelude::*;
+use rslint_regex::{AssertionKind, Node};
+use std::ops::Range;
+
+declare_lint! {
+    /**
+    Disallow backreferences in regular expressions which always match an empty string.
+
+    A backreference which is reached before its group has matched anything, or in a path in
+    which its group cannot have matched anything, always matches an empty string.
+    This is usually a mistake, and it means the backreference can be removed.
+
+    A backreference is useless if:
+    - It is in a different alternative than its group, such as `/(a)|\1/`.
+    - It comes before its group, such as `/\1(a)/`. Inside of a lookbehind this is reversed
+      because lookbehinds are matched backwards.
+    - It is inside of its group, such as `/(a\1)/`.
+    - Its group is in a negative lookaround which the backreference is not in, such as `/(?!(a))\1/`.
+
+    ## Invalid Code Examples
+
+    ```js
+    /(a)|\1b/;
+
+    /\k<foo>(?<foo>a)/;
+
+    /(a\1)/;
+
+    /(?!(a))\1/;
+
+    /(?<=(a)\1)b/;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    /(a)\1/;
+
+    /(?<foo>a)\k<foo>/;
+
+    /(?:(a)|b)\1/;
+
+    /(?<=\1(a))b/;
+    ```
+    */
+    #[derive(Default)]
+    NoUselessBackreference,
+    regex,
+    "no-useless-backreference"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepKind {
+    Group,
+    Alternative(usize),
+    Lookahead { negative: bool },
+    Lookbehind { negative: bool },
+}
+
+/// A node containing a group or a backreference, identified by the order it was visited in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step {
+    id: usize,
+    kind: StepKind,
+}
+
+#[derive(Debug)]
+struct GroupInfo {
+    span: Range<usize>,
+    name: Option<String>,
+    path: Vec<Step>,
+    id: usize,
+}
+
+#[derive(Debug)]
+enum Target {
+    Index(u32),
+    Name(String),
+}
+
+#[derive(Debug)]
+struct BackrefInfo {
+    span: Range<usize>,
+    target: Target,
+    path: Vec<Step>,
+}
+
+#[derive(Debug, Default)]
+struct Collector {
+    next_id: usize,
+    path: Vec<Step>,
+    groups: Vec<GroupInfo>,
+    backrefs: Vec<BackrefInfo>,
+}
+
+impl Collector {
+    fn visit(&mut self, node: &Node) {
+        match node {
+            Node::Disjunction(_, alternatives) => {
+                let id = self.next_id();
+                for (idx, alternative) in alternatives.iter().enumerate() {
+                    self.visit_in(id, StepKind::Alternative(idx), alternative);
+                }
+            }
+            Node::Alternative(_, nodes) => nodes.iter().for_each(|node| self.visit(node)),
+            Node::Quantifier(_, node, _, _) => self.visit(node),
+            Node::Group(span, group) => {
+                let id = self.next_id();
+                if !group.noncapturing {
+                    self.groups.push(GroupInfo {
+                        span: span.as_range(),
+                        name: group.name.clone(),
+                        path: self.path.clone(),
+                        id,
+                    });
+                }
+                self.visit_in(id, StepKind::Group, &group.inner);
+            }
+            Node::Assertion(_, kind) => {
+                let (inner, kind) = match kind {
+                    AssertionKind::Lookahead(inner) => {
+                        (inner, StepKind::Lookahead { negative: false })
+                    }
+                    AssertionKind::NegativeLookahead(inner) => {
+                        (inner, StepKind::Lookahead { negative: true })
+                    }
+                    AssertionKind::Lookbehind(inner) => {
+                        (inner, StepKind::Lookbehind { negative: false })
+                    }
+                    AssertionKind::NegativeLookbehind(inner) => {
+                        (inner, StepKind::Lookbehind { negative: true })
+                    }
+                    _ => return,
+                };
+                let id = self.next_id();
+                self.visit_in(id, kind, inner);
+            }
+            Node::BackReference(span, idx) => self.backrefs.push(BackrefInfo {
+                span: span.as_range(),
+                target: Target::Index(*idx),
+                path: self.path.clone(),
+            }),
+            Node::NamedBackReference(span, name) => self.backrefs.push(BackrefInfo {
+                span: span.as_range(),
+                target: Target::Name(name.clone()),
+                path: self.path.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    fn visit_in(&mut self, id: usize, kind: StepKind, node: &Node) {
+        self.path.push(Step { id, kind });
+        self.visit(node);
+        self.path.pop();
+    }
+
+    fn next_id(&mut self) -> usize {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoUselessBackreference {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let (regex, _) = maybe_parse_and_store_regex(node, ctx.file_id)?.ok()?;
+        let mut collector = Collector::default();
+        collector.visit(&regex.node);
+
+        for backref in &collector.backrefs {
+            // groups are numbered in the order of their opening parentheses, which is the order they are visited in
+            let group = match &backref.target {
+                Target::Index(idx) => collector.groups.get((*idx as usize).checked_sub(1)?),
+                Target::Name(name) => collector
+                    .groups
+                    .iter()
+                    .find(|group| group.name.as_ref() == Some(name)),
+            };
+            let group = match group {
+                Some(group) => group,
+                None => continue,
+            };
+
+            if let Some(reason) = useless_reason(backref, group) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!(
+                            "the backreference `{}` is useless because {}",
+                            &ctx.src[backref.span.clone()],
+                            reason
+                        ),
+                    )
+                    .primary(backref.span.clone(), "this always matches an empty string")
+                    .secondary(group.span.clone(), "the group it references");
+                ctx.add_err(err);
+            }
+        }
+        None
+    }
+}
+
+fn useless_reason(backref: &BackrefInfo, group: &GroupInfo) -> Option<&'static str> {
+    if backref.path.iter().any(|step| step.id == group.id) {
+        return Some("it is inside of its group");
+    }
+
+    let common = backref
+        .path
+        .iter()
+        .zip(&group.path)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if let (Some(a), Some(b)) = (backref.path.get(common), group.path.get(common)) {
+        // the paths only diverge at the same node if they are in different alternatives
+        if a.id == b.id {
+            return Some("it is in a different alternative than its group");
+        }
+    }
+
+    let in_negative_lookaround = group.path[common..].iter().any(|step| {
+        matches!(
+            step.kind,
+            StepKind::Lookahead { negative: true } | StepKind::Lookbehind { negative: true }
+        )
+    });
+    if in_negative_lookaround {
+        return Some("its group is in a negative lookaround");
+    }
+
+    // lookbehinds are matched from right to left
+    let backwards = group.path[..common]
+        .iter()
+        .rev()
+        .find_map(|step| match step.kind {
+            StepKind::Lookahead { .. } => Some(false),
+            StepKind::Lookbehind { .. } => Some(true),
+            _ => None,
+        })
+        .unwrap_or(false);
+    if backwards && backref.span.start > group.span.start {
+        Some("it is matched before its group in a lookbehind")
+    } else if !backwards && backref.span.start < group.span.start {
+        Some("it comes before its group")
+    } else {
+        None
+    }
+}
+
+rule_tests! {
+    NoUselessBackreference::default(),
+    err: {
+        r"/(a)|\1b/;",
+        r"/\1(a)/;",
+        r"/(a\1)/;",
+        r"/(?!(a))\1/;",
+        r"/(?=(a))|\1/;",
+        r"/(?<=(a)\1)b/;",
+        r"/\k<foo>(?<foo>a)/;",
+        r"/(?:(a)|b)|\1/;",
+        r"RegExp('(a)|\1');"
+    },
+    ok: {
+        r"/(a)\1/;",
+        r"/(?<foo>a)\k<foo>/;",
+        r"/(a)(b)\2\1/;",
+        r"/(?:(a)|b)\1/;",
+        r"/((a)|b)\2/;",
+        r"/(?=(a))\1/;",
+        r"/(?!(a)\1)/;",
+        r"/(?<=\1(a))b/;",
+        r"/(a)|(b)\2/;",
+        r"/[\1](a)/;",
+        r"/\1/;"
+    }
+}
@@ -82,7 +82,7 @@ impl CstRule for SimplifyRegex {
 
         if !err.footers.is_empty() {
             let string = regex.node.to_string();
-            ctx.fix().replace(range, &string);
+            ctx.fixer().replace(range, &string);
             err.title.push_str(&format!(" to `{}`", string));
             ctx.add_err(err);
         }
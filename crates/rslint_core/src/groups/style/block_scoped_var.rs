@@ -0,0 +1,241 @@
+use crate::rule_prelude::*;
+use crate::util::pattern_names;
+use ast::VarDecl;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow using `var` declarations outside of the block they are declared in.
+
+    Variables declared with `var` are function scoped and hoisted, so they can be used outside of
+    the block they are declared in. This is confusing to readers who expect variables to be block
+    scoped like they are in most other languages, and it is usually a sign that the declaration
+    should be moved or replaced by `let`.
+
+    References inside of nested functions which declare a variable with the same name, and
+    references shadowed by `let`, `const`, or `class` declarations of the same name are not reported.
+
+    ## Invalid Code Examples
+
+    ```js
+    function doIf() {
+        if (true) {
+            var build = true;
+        }
+
+        console.log(build);
+    }
+
+    function doLoop() {
+        for (var i = 0; i < 10; i++) {}
+        return i;
+    }
+
+    function doTry() {
+        try {
+            var build = 1;
+        } catch (e) {
+            var f = build;
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function doIf() {
+        var build;
+
+        if (true) {
+            build = true;
+        }
+
+        console.log(build);
+    }
+
+    function doLoop() {
+        for (var i = 0; i < 10; i++) {
+            console.log(i);
+        }
+    }
+    ```
+    */
+    #[derive(Default)]
+    BlockScopedVar,
+    style,
+    "block-scoped-var"
+}
+
+const FUNCTIONS: [SyntaxKind; 7] = [
+    FN_DECL,
+    FN_EXPR,
+    ARROW_EXPR,
+    METHOD,
+    GETTER,
+    SETTER,
+    CONSTRUCTOR,
+];
+
+const BLOCKS: [SyntaxKind; 6] = [
+    BLOCK_STMT,
+    FOR_STMT,
+    FOR_IN_STMT,
+    FOR_OF_STMT,
+    SWITCH_STMT,
+    CATCH_CLAUSE,
+];
+
+#[typetag::serde]
+impl CstRule for BlockScopedVar {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let decl = node.try_to::<VarDecl>()?;
+        decl.var_token()?;
+
+        let block = node
+            .ancestors()
+            .skip(1)
+            .find(|ancestor| BLOCKS.contains(&ancestor.kind()) || is_scope(ancestor))?;
+        // the body of a function is the scope of the variable itself
+        if is_scope(&block) || block.parent().map(|parent| is_scope(&parent)) == Some(true) {
+            return None;
+        }
+        let scope = block.ancestors().find(is_scope)?;
+
+        for declarator in decl.declared() {
+            let mut names = vec![];
+            if let Some(pattern) = declarator.pattern() {
+                pattern_names(pattern, &mut names);
+            }
+
+            for name in names {
+                let text = name.trimmed_text().to_string();
+                let block_range = block.text_range();
+                let references = scope.descendants().filter(|node| {
+                    is_reference(node, &text)
+                        && !block_range.contains_range(node.text_range())
+                        && !node
+                            .ancestors()
+                            .take_while(|ancestor| *ancestor != scope)
+                            .any(|ancestor| shadows(&ancestor, &text))
+                });
+
+                for reference in references {
+                    let err = ctx
+                        .err(
+                            self.name(),
+                            format!("`{}` is used outside of the block it is declared in", text),
+                        )
+                        .primary(reference.trimmed_range(), "")
+                        .secondary(name.trimmed_range(), "the variable is declared here...")
+                        .secondary(block.trimmed_range(), "...inside of this block")
+                        .footer_help(
+                            "consider declaring the variable outside of the block, or using `let` instead",
+                        );
+                    ctx.add_err(err);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn is_scope(node: &SyntaxNode) -> bool {
+    FUNCTIONS.contains(&node.kind()) || matches!(node.kind(), SCRIPT | MODULE)
+}
+
+fn is_reference(node: &SyntaxNode, name: &str) -> bool {
+    match node.kind() {
+        NAME_REF => node.text() == name,
+        // shorthand properties such as `{ a }`
+        NAME => {
+            node.parent().map(|parent| parent.kind()) == Some(IDENT_PROP) && node.text() == name
+        }
+        _ => false,
+    }
+}
+
+/// Whether a scope between a reference and the scope of a variable declares a different variable
+/// with the same name.
+fn shadows(node: &SyntaxNode, name: &str) -> bool {
+    match node.kind() {
+        kind if FUNCTIONS.contains(&kind) => node.descendants().any(|child| {
+            child.kind() == NAME
+                && child.text() == name
+                && child.parent().map(|parent| {
+                    matches!(
+                        parent.kind(),
+                        SINGLE_PATTERN | FN_DECL | FN_EXPR | CLASS_DECL | CLASS_EXPR
+                    )
+                }) == Some(true)
+        }),
+        BLOCK_STMT | CASE_CLAUSE | DEFAULT_CLAUSE => {
+            node.children().any(|stmt| lexically_declares(&stmt, name))
+        }
+        FOR_STMT | FOR_IN_STMT | FOR_OF_STMT => node
+            .children()
+            .filter(|child| child.kind() == FOR_STMT_INIT)
+            .flat_map(|init| init.children())
+            .any(|decl| lexically_declares(&decl, name)),
+        CATCH_CLAUSE => node
+            .children()
+            .filter_map(|child| child.try_to::<ast::Pattern>())
+            .any(|pattern| {
+                let mut names = vec![];
+                pattern_names(pattern, &mut names);
+                names.iter().any(|binding| binding.text() == name)
+            }),
+        _ => false,
+    }
+}
+
+/// Whether a statement is a `let`, `const`, `class`, or function declaration of a name.
+fn lexically_declares(stmt: &SyntaxNode, name: &str) -> bool {
+    match stmt.kind() {
+        VAR_DECL => {
+            let decl = stmt.to::<VarDecl>();
+            if decl.var_token().is_some() {
+                return false;
+            }
+            decl.declared().any(|declarator| {
+                let mut names = vec![];
+                if let Some(pattern) = declarator.pattern() {
+                    pattern_names(pattern, &mut names);
+                }
+                names.iter().any(|binding| binding.text() == name)
+            })
+        }
+        FN_DECL | CLASS_DECL => {
+            stmt.child_with_kind(NAME)
+                .map(|binding| binding.text() == name)
+                == Some(true)
+        }
+        _ => false,
+    }
+}
+
+rule_tests! {
+    BlockScopedVar::default(),
+    err: {
+        "function f() { if (a) { var b = 1; } return b; }",
+        "if (a) { var b = 1; } console.log(b);",
+        "function f() { for (var i = 0; i < 10; i++) {} return i; }",
+        "function f() { for (var item of items) {} return item; }",
+        "function f() { try { var a = 1; } catch (e) { a = 2; } }",
+        "function f() { if (a) { var { b, c: [d] } = e; } return d; }",
+        "function f() { { var b = 1; } function g() { return b; } }",
+        "function f() { switch (a) { case 1: var b = 1; } return { b }; }"
+    },
+    ok: {
+        "function f() { var b; if (a) { b = 1; } return b; }",
+        "function f() { if (a) { var b = 1; console.log(b); } }",
+        "function f() { var a = 1; return a; }",
+        "var a = 1; console.log(a);",
+        "function f() { for (var i = 0; i < 10; i++) { console.log(i); } }",
+        "function f() { if (a) { var b = 1; } function g(b) { return b; } }",
+        "function f() { if (a) { var b = 1; } { let b = 2; console.log(b); } }",
+        "function f() { if (a) { let b = 1; } return b; }",
+        "function f() { if (a) { var b = 1; } return c.b; }",
+        "function f() { try { bar(); } catch (b) { var b = 1; } }",
+        "if (a) { function f() { var b = 1; return b; } }"
+    }
+}
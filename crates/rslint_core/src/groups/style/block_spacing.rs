@@ -91,13 +91,13 @@ impl CstRule for BlockSpacing {
         {
             let err = ctx.err(self.name(), msg("after", "{")).primary(node, "");
 
-            ctx.add_err(err);
             let fix = ctx
-                .fix()
+                .fixer()
                 .delete_multiple(open_token.trailing_whitespace(false));
             if self.style == "always" {
                 fix.insert_after(open_token, " ");
             }
+            ctx.add_err(err);
         }
 
         if !(close_token.leading_trivia_has_linebreak(true)
@@ -105,13 +105,13 @@ impl CstRule for BlockSpacing {
         {
             let err = ctx.err(self.name(), msg("before", "}")).primary(node, "");
 
-            ctx.add_err(err);
             let fix = ctx
-                .fix()
+                .fixer()
                 .delete_multiple(close_token.leading_whitespace(false));
             if self.style == "always" {
                 fix.insert_before(close_token, " ");
             }
+            ctx.add_err(err);
         }
         None
     }
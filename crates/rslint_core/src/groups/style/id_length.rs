@@ -0,0 +1,208 @@
+use crate::rule_prelude::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a minimum and maximum length for identifiers.
+
+    Very short identifiers such as `e` or `x` rarely explain what a value is, and very long identifiers
+    make code hard to read. This rule checks the length of declared identifiers, such as variables,
+    functions, classes, parameters, and imports. Property names in object literals, classes, and
+    member assignments are checked too, unless `properties` is set to `"never"`.
+
+    Identifiers in the `exceptions` list are always allowed, which is commonly used for loop counters
+    such as `i` and `j`.
+
+    ## Invalid Code Examples
+
+    ```js
+    // with a `min` of 2
+    let a = 5;
+
+    function f(b) {}
+
+    let obj = { c: 1 };
+
+    try {} catch (e) {}
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    // with a `min` of 2
+    let num = 5;
+
+    function foo(bar) {}
+
+    let obj = { count: 1 };
+
+    // `a` is not declared here
+    foo(a.b);
+    ```
+    */
+    #[serde(default)]
+    IdLength,
+    style,
+    "id-length",
+    /// The minimum length of identifiers, `2` by default.
+    pub min: usize,
+    /// The maximum length of identifiers, identifiers may be of any length by default.
+    pub max: Option<usize>,
+    /// Identifiers which are allowed regardless of their length.
+    pub exceptions: Vec<String>,
+    /// Whether property names are checked, either `"always"` (default) or `"never"`.
+    pub properties: Properties
+}
+
+impl Default for IdLength {
+    fn default() -> Self {
+        Self {
+            min: 2,
+            max: None,
+            exceptions: vec![],
+            properties: Properties::Always,
+        }
+    }
+}
+
+/// Whether property names are checked by [`IdLength`].
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Properties {
+    Always,
+    Never,
+}
+
+#[typetag::serde]
+impl CstRule for IdLength {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if node.kind() != NAME {
+            return None;
+        }
+        let is_property = match declaration_kind(node)? {
+            Declaration::Binding => false,
+            Declaration::Property => true,
+        };
+        if is_property && self.properties == Properties::Never {
+            return None;
+        }
+
+        let text = node.text().to_string();
+        if self.exceptions.contains(&text) {
+            return None;
+        }
+        let len = text.chars().count();
+        let kind = if is_property {
+            "property name"
+        } else {
+            "identifier"
+        };
+
+        let msg = if len < self.min {
+            format!(
+                "{} `{}` is too short ({} characters), the minimum length is {}",
+                kind, text, len, self.min
+            )
+        } else if let Some(max) = self.max.filter(|max| len > *max) {
+            format!(
+                "{} `{}` is too long ({} characters), the maximum length is {}",
+                kind, text, len, max
+            )
+        } else {
+            return None;
+        };
+
+        let err = ctx.err(self.name(), msg).primary(node, "");
+        ctx.add_err(err);
+        None
+    }
+}
+
+enum Declaration {
+    Binding,
+    Property,
+}
+
+/// Whether a name declares a binding or a property, names which do neither, such as the
+/// imported name of `import { a as b }` or labels are not checked.
+fn declaration_kind(name: &SyntaxNode) -> Option<Declaration> {
+    let parent = name.parent()?;
+    match parent.kind() {
+        SINGLE_PATTERN | FN_DECL | FN_EXPR | CLASS_DECL | CLASS_EXPR | ARROW_EXPR | IMPORT_DECL => {
+            Some(Declaration::Binding)
+        }
+        // only the local name of an import specifier is declared by the module
+        SPECIFIER
+            if parent.parent()?.kind() == NAMED_IMPORTS
+                && parent.children().last().as_ref() == Some(name) =>
+        {
+            Some(Declaration::Binding)
+        }
+        METHOD | GETTER | SETTER | CLASS_PROP | LITERAL_PROP | IDENT_PROP | PRIVATE_NAME => {
+            Some(Declaration::Property)
+        }
+        DOT_EXPR => {
+            let assign = parent.parent()?;
+            if assign.kind() == ASSIGN_EXPR && assign.first_child().as_ref() == Some(&parent) {
+                Some(Declaration::Property)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+rule_tests! {
+    IdLength::default(),
+    err: {
+        "let a = 1;",
+        "function f() {}",
+        "function foo(b) {}",
+        "let foo = x => x;",
+        "class C {}",
+        "try {} catch (e) {}",
+        "for (let i = 0; i < 10; i++) {}",
+        "let { a } = foo;",
+        "let [a, ...b] = foo;",
+        "import a from 'foo';",
+        "import { foo as a } from 'foo';",
+        "let foo = { a: 1 };",
+        "class Foo { a() {} }",
+        "class Foo { #a = 1 }",
+        "foo.a = 1;"
+    },
+    ok: {
+        "let ab = 1;",
+        "foo(a);",
+        "foo.a();",
+        "let foo = foo.a;",
+        "import { a as ab } from 'foo';",
+        "let { a: ab } = foo;",
+        "label: for (;;) {}"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    IdLength {
+        min: 2,
+        max: Some(5),
+        exceptions: vec!["i".to_string()],
+        properties: Properties::Never,
+    },
+    err: {
+        "let a = 1;",
+        "let foobarbaz = 1;",
+        "function foo(a) {}"
+    },
+    ok: {
+        "for (let i = 0; i < 10; i++) {}",
+        "let foo = { a: 1 };",
+        "foo.a = 1;",
+        "class Foo { a() {} }",
+        "let fooba = 1;"
+    }
+}
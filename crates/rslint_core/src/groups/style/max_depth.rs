@@ -0,0 +1,185 @@
+use crate::rule_prelude::*;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum depth that blocks can be nested in.
+
+    Deeply nested code is hard to read and understand, and it is usually a sign that some of the
+    code should be extracted to separate functions or that early returns should be used.
+
+    The depth is counted separately for every function. `if`, `switch`, `try`, `with`, and loop
+    statements increase the depth, `else if` does not. Only the deepest statement of a chain of
+    statements which exceeds the maximum depth is reported.
+
+    ## Invalid Code Examples
+
+    ```js
+    function foo() {
+        for (;;) { // depth 1
+            while (true) { // depth 2
+                if (true) { // depth 3
+                    if (true) { // depth 4
+                        if (true) { // depth 5
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo() {
+        for (;;) { // depth 1
+            while (true) { // depth 2
+                if (true) { // depth 3
+                    if (true) { // depth 4
+                    } else if (true) { // depth 4
+                    }
+                }
+            }
+        }
+    }
+    ```
+    */
+    #[serde(default)]
+    MaxDepth,
+    style,
+    "max-depth",
+    /// The maximum depth blocks can be nested in, `4` by default.
+    pub max: usize
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+const NESTING: [SyntaxKind; 9] = [
+    IF_STMT,
+    SWITCH_STMT,
+    TRY_STMT,
+    WITH_STMT,
+    WHILE_STMT,
+    DO_WHILE_STMT,
+    FOR_STMT,
+    FOR_IN_STMT,
+    FOR_OF_STMT,
+];
+
+const FUNCTIONS: [SyntaxKind; 7] = [
+    FN_DECL,
+    FN_EXPR,
+    ARROW_EXPR,
+    METHOD,
+    GETTER,
+    SETTER,
+    CONSTRUCTOR,
+];
+
+#[typetag::serde]
+impl CstRule for MaxDepth {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !increases_depth(node) || depth(node) != self.max + 1 {
+            return None;
+        }
+
+        // report the deepest statement of this nesting chain instead of every statement in it
+        let function = enclosing_function(node);
+        let (deepest_depth, deepest) = node
+            .descendants()
+            .filter(|child| increases_depth(child) && enclosing_function(child) == function)
+            .map(|child| (depth(&child), child))
+            .fold((0, node.clone()), |(max, deepest), (depth, child)| {
+                if depth > max {
+                    (depth, child)
+                } else {
+                    (max, deepest)
+                }
+            });
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "blocks are nested too deeply ({} levels), the maximum allowed depth is {}",
+                    deepest_depth, self.max
+                ),
+            )
+            .primary(deepest.first_token()?.text_range(), "")
+            .secondary(
+                node.first_token()?.text_range(),
+                "the maximum depth is first exceeded here",
+            )
+            .footer_help("consider extracting some of the code into a separate function");
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Whether a statement adds a level of nesting, `else if` is not counted as another level.
+fn increases_depth(node: &SyntaxNode) -> bool {
+    NESTING.contains(&node.kind())
+        && !(node.kind() == IF_STMT && node.parent().map(|parent| parent.kind()) == Some(IF_STMT))
+}
+
+fn enclosing_function(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors()
+        .find(|ancestor| FUNCTIONS.contains(&ancestor.kind()))
+}
+
+/// The amount of nesting statements a node is inside of in its function, including itself.
+fn depth(node: &SyntaxNode) -> usize {
+    node.ancestors()
+        .take_while(|ancestor| !FUNCTIONS.contains(&ancestor.kind()))
+        .filter(increases_depth)
+        .count()
+}
+
+rule_tests! {
+    MaxDepth::default(),
+    err: {
+        "function foo() { if (a) { if (b) { if (c) { if (d) { if (e) {} } } } } }",
+        "for (;;) { while (a) { do { switch (a) { case 1: try {} catch (e) {} } } while (a) } }",
+        "function foo() { if (a) { if (b) { if (c) { if (d) {} else { switch (e) {} } } } } }",
+        "
+        const foo = () => {
+            if (a) {
+                for (const b of c) {
+                    for (const d in b) {
+                        if (d) {
+                            if (e) {
+                                if (f) {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "
+    },
+    ok: {
+        "function foo() { if (a) { if (b) { if (c) { if (d) {} } } } }",
+        "function foo() { if (a) {} else if (b) {} else if (c) {} else if (d) {} else if (e) {} }",
+        "if (a) { if (b) { if (c) { function foo() { if (d) { if (e) {} } } } } }",
+        "if (a) { if (b) { if (c) { if (d) { foo(() => { if (e) {} }) } } } }"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    MaxDepth { max: 1 },
+    err: {
+        "if (a) { if (b) {} }",
+        "while (a) { for (;;) {} }"
+    },
+    ok: {
+        "if (a) {} else if (b) {}",
+        "if (a) { function foo() { if (b) {} } }"
+    }
+}
@@ -0,0 +1,179 @@
+use crate::rule_prelude::*;
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum depth that callbacks can be nested in.
+
+    Many JavaScript libraries use callbacks for asynchronous code, which can easily lead to deeply nested
+    callbacks (also known as "callback hell"). Such code is hard to read and maintain, it is usually better
+    written with promises and `async` functions, or by extracting the callbacks to named functions.
+
+    Function expressions and arrow functions which are passed as an argument to a call or `new` expression
+    are callbacks.
+
+    ## Invalid Code Examples
+
+    ```js
+    // with a `max` of 2
+    foo(function () {
+        bar(function () {
+            baz(function () {
+                qux();
+            });
+        });
+    });
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    // with a `max` of 2
+    foo(handleFoo);
+
+    function handleFoo() {
+        bar(handleBar);
+    }
+
+    function handleBar() {
+        baz(() => qux());
+    }
+    ```
+    */
+    #[serde(default)]
+    MaxNestedCallbacks,
+    style,
+    "max-nested-callbacks",
+    /// The maximum depth callbacks can be nested in, `10` by default.
+    pub max: usize
+}
+
+impl Default for MaxNestedCallbacks {
+    fn default() -> Self {
+        Self { max: 10 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for MaxNestedCallbacks {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !is_callback(node) {
+            return None;
+        }
+        let depth = node.ancestors().filter(is_callback).count();
+        if depth <= self.max {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "callbacks are nested too deeply ({} levels), the maximum allowed depth is {}",
+                    depth, self.max
+                ),
+            )
+            .primary(header_range(node), "")
+            .footer_help(
+                "consider using promises and `async` functions, or extracting the callbacks to named functions",
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn is_callback(node: &SyntaxNode) -> bool {
+    matches!(node.kind(), FN_EXPR | ARROW_EXPR)
+        && node
+            .parent()
+            .filter(|parent| parent.kind() == ARG_LIST)
+            .and_then(|args| args.parent())
+            .map(|call| matches!(call.kind(), CALL_EXPR | NEW_EXPR))
+            == Some(true)
+}
+
+/// The range of a function up to the end of its parameters, or the end of the `=>` for arrow functions.
+fn header_range(node: &SyntaxNode) -> TextRange {
+    let end = match node.kind() {
+        ARROW_EXPR => node
+            .token_with_kind(FAT_ARROW)
+            .map(|arrow| arrow.text_range().end()),
+        _ => node
+            .child_with_kind(PARAMETER_LIST)
+            .map(|params| params.text_range().end()),
+    };
+    TextRange::new(
+        node.text_range().start(),
+        end.unwrap_or_else(|| node.text_range().end()),
+    )
+}
+
+rule_tests! {
+    MaxNestedCallbacks::default(),
+    err: {
+        "
+        a(() => {
+            b(() => {
+                c(() => {
+                    d(() => {
+                        e(() => {
+                            f(() => {
+                                g(() => {
+                                    h(() => {
+                                        i(() => {
+                                            j(() => {
+                                                k(() => {});
+                                            });
+                                        });
+                                    });
+                                });
+                            });
+                        });
+                    });
+                });
+            });
+        });
+        "
+    },
+    ok: {
+        "
+        a(() => {
+            b(() => {
+                c(() => {
+                    d(() => {
+                        e(() => {
+                            f(() => {
+                                g(() => {
+                                    h(() => {
+                                        i(() => {
+                                            j(() => {});
+                                        });
+                                    });
+                                });
+                            });
+                        });
+                    });
+                });
+            });
+        });
+        "
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    MaxNestedCallbacks { max: 2 },
+    err: {
+        "foo(function () { bar(function () { baz(function () {}); }); });",
+        "new Promise(resolve => foo(() => bar(x => x)));",
+        "foo(function () { bar(async () => { baz(a => a); }); });"
+    },
+    ok: {
+        "foo(function () { bar(function () {}); });",
+        "foo(function () { bar(function () { function baz() {} baz(); }); });",
+        "foo(function () { bar(function () { var baz = () => qux(); }); });",
+        "foo(handleFoo); function handleFoo() { bar(() => baz(() => {})); }"
+    }
+}
@@ -0,0 +1,124 @@
+use crate::rule_prelude::*;
+use ast::ParameterList;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a maximum amount of parameters in function definitions.
+
+    Functions which take a lot of parameters are hard to call correctly since the order of the arguments
+    has to be remembered, and they are usually a sign that the function does too many things.
+    Consider passing an object with the options instead.
+
+    Destructured parameters count as a single parameter, and so does a rest parameter.
+
+    ## Invalid Code Examples
+
+    ```js
+    function foo(bar, baz, qux, quux) {}
+
+    const foo = (bar, baz, qux, quux) => {};
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    function foo(bar, baz, qux) {}
+
+    function foo({ bar, baz, qux, quux }) {}
+
+    function foo(bar, baz, ...rest) {}
+    ```
+    */
+    #[serde(default)]
+    MaxParams,
+    style,
+    "max-params",
+    /// The maximum amount of parameters a function may have, `3` by default.
+    pub max: usize
+}
+
+impl Default for MaxParams {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for MaxParams {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let list = node.try_to::<ParameterList>()?;
+        let function = node.parent()?;
+        if !matches!(
+            function.kind(),
+            FN_DECL | FN_EXPR | ARROW_EXPR | METHOD | SETTER | CONSTRUCTOR
+        ) {
+            return None;
+        }
+
+        let count = list.parameters().count();
+        if count <= self.max {
+            return None;
+        }
+
+        let kind = match function.kind() {
+            ARROW_EXPR => "arrow function",
+            METHOD | SETTER => "method",
+            CONSTRUCTOR => "constructor",
+            _ => "function",
+        };
+        let name = function
+            .child_with_kind(NAME)
+            .map(|name| format!(" `{}`", name.text()))
+            .unwrap_or_default();
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "{}{} has too many parameters ({}), the maximum allowed is {}",
+                    kind, name, count, self.max
+                ),
+            )
+            .primary(node, "")
+            .footer_help("consider passing an object containing the parameters instead");
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    MaxParams::default(),
+    err: {
+        "function foo(a, b, c, d) {}",
+        "let foo = function (a, b, c, d) {};",
+        "let foo = (a, b, c, d) => {};",
+        "let foo = { bar(a, b, c, d) {} };",
+        "class Foo { constructor(a, b, c, d) {} }",
+        "function foo(a, { b }, [c], d = 1) {}",
+        "function foo(a, b, c, ...d) {}"
+    },
+    ok: {
+        "function foo(a, b, c) {}",
+        "function foo() {}",
+        "let foo = (a, b, c) => {};",
+        "let foo = a => a;",
+        "function foo({ a, b, c, d }) {}",
+        "function foo(a, b, ...c) {}",
+        "foo(a, b, c, d);"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    MaxParams { max: 1 },
+    err: {
+        "function foo(a, b) {}",
+        "class Foo { bar(a, ...b) {} }"
+    },
+    ok: {
+        "function foo(a) {}",
+        "let foo = a => a;"
+    }
+}
@@ -5,5 +5,29 @@ use crate::group;
 group! {
     /// Rules which relate to code style and formatting.
     style,
-    block_spacing::BlockSpacing
+    block_spacing::BlockSpacing,
+    yoda::Yoda,
+    prefer_const::PreferConst,
+    no_restricted_properties::NoRestrictedProperties,
+    no_restricted_imports::NoRestrictedImports,
+    no_restricted_keywords::NoRestrictedKeywords,
+    prefer_numeric_literals::PreferNumericLiterals,
+    no_unneeded_computed_member::NoUnneededComputedMember,
+    no_duplicate_string::NoDuplicateString,
+    no_mixed_requires::NoMixedRequires,
+    block_scoped_var::BlockScopedVar,
+    no_extra_bind::NoExtraBind,
+    max_depth::MaxDepth,
+    max_params::MaxParams,
+    max_nested_callbacks::MaxNestedCallbacks,
+    id_length::IdLength,
+    one_var::OneVar,
+    no_useless_constructor::NoUselessConstructor,
+    prefer_exponentiation_operator::PreferExponentiationOperator,
+    no_extra_label::NoExtraLabel,
+    no_magic_numbers::NoMagicNumbers,
+    prefer_object_spread::PreferObjectSpread,
+    no_label_var::NoLabelVar,
+    sort_imports::SortImports,
+    quotes::Quotes,
 }
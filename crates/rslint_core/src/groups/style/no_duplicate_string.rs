@@ -0,0 +1,186 @@
+use crate::rule_prelude::*;
+use ast::{CallExpr, Expr, Literal};
+use std::collections::HashMap;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow the same string literal from appearing many times in a file.
+
+    Duplicated string literals make refactoring harder since every occurrence has to be found and
+    updated, and typos in one of the occurrences can easily go unnoticed. Such strings are better
+    extracted to a constant.
+
+    Strings which are shorter than `minLength`, import and export sources, `require` calls,
+    directives such as `"use strict"`, and object keys are ignored.
+
+    ## Invalid Code Examples
+
+    ```js
+    throw new Error("something went wrong");
+    throw new Error("something went wrong");
+    throw new Error("something went wrong");
+    throw new Error("something went wrong");
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const MESSAGE = "something went wrong";
+    throw new Error(MESSAGE);
+    throw new Error(MESSAGE);
+    throw new Error(MESSAGE);
+    throw new Error(MESSAGE);
+    ```
+    */
+    #[serde(default)]
+    NoDuplicateString,
+    style,
+    "no-duplicate-string",
+    /// The amount of times a string may appear in a file before it is reported, `3` by default.
+    pub threshold: usize,
+    /// The minimum length of strings to check, not including quotes, `10` by default.
+    pub min_length: usize
+}
+
+impl Default for NoDuplicateString {
+    fn default() -> Self {
+        Self {
+            threshold: 3,
+            min_length: 10,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoDuplicateString {
+    fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let mut occurrences: HashMap<String, Vec<SyntaxNode>> = HashMap::new();
+
+        for lit in root
+            .descendants()
+            .filter_map(|node| node.try_to::<Literal>())
+        {
+            let value = match lit.inner_string_text() {
+                Some(text) => text.to_string(),
+                None => continue,
+            };
+            if value.chars().count() < self.min_length || is_ignored(lit.syntax()) {
+                continue;
+            }
+            occurrences
+                .entry(value)
+                .or_default()
+                .push(lit.syntax().clone());
+        }
+
+        let mut duplicates = occurrences
+            .into_iter()
+            .filter(|(_, nodes)| nodes.len() > self.threshold)
+            .collect::<Vec<_>>();
+        duplicates.sort_by_key(|(_, nodes)| nodes[0].text_range().start());
+
+        for (value, nodes) in duplicates {
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    format!("the string `{}` is duplicated {} times", value, nodes.len()),
+                )
+                .primary(nodes[0].trimmed_range(), "first used here");
+            for node in &nodes[1..] {
+                err = err.secondary(node.trimmed_range(), "");
+            }
+            err = err.footer_help("consider extracting the string to a constant");
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+/// Whether a string is in a place where it cannot (or should not) be replaced by a constant.
+fn is_ignored(node: &SyntaxNode) -> bool {
+    let parent = match node.parent() {
+        Some(parent) => parent,
+        None => return false,
+    };
+    match parent.kind() {
+        IMPORT_DECL | EXPORT_DECL | EXPORT_NAMED | EXPORT_WILDCARD | EXPR_STMT => true,
+        LITERAL_PROP => parent.first_child().as_ref() == Some(node),
+        ARG_LIST => parent
+            .parent()
+            .and_then(|call| call.try_to::<CallExpr>())
+            .and_then(|call| call.callee())
+            .map(
+                |callee| matches!(callee, Expr::NameRef(name) if name.syntax().text() == "require"),
+            )
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
+rule_tests! {
+    NoDuplicateString::default(),
+    err: {
+        "
+        foo('hello world');
+        bar('hello world');
+        baz('hello world');
+        qux('hello world');
+        ",
+        "
+        let a = { foo: \"a long string\", bar: 'a long string' };
+        let b = [\"a long string\", \"a long string\"];
+        "
+    },
+    ok: {
+        "
+        foo('hello world');
+        bar('hello world');
+        ",
+        "
+        foo('hello world');
+        bar('hello world');
+        baz('hello world');
+        ",
+        "
+        foo('short');
+        bar('short');
+        baz('short');
+        qux('short');
+        ",
+        "
+        import a from 'some-long-module';
+        import { b } from 'some-long-module';
+        export * from 'some-long-module';
+        const c = require('some-long-module');
+        ",
+        "
+        ({ 'a long key name': 1 });
+        ({ 'a long key name': 1 });
+        ({ 'a long key name': 1 });
+        ({ 'a long key name': 1 });
+        ",
+        "
+        function a() { 'use strict'; }
+        function b() { 'use strict'; }
+        function c() { 'use strict'; }
+        function d() { 'use strict'; }
+        "
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    NoDuplicateString {
+        threshold: 1,
+        min_length: 2,
+    },
+    err: {
+        "foo('ab'); bar('ab');"
+    },
+    ok: {
+        "foo('a'); bar('a');",
+        "foo('ab'); bar('ac');"
+    }
+}
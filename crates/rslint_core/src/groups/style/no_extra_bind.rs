@@ -0,0 +1,142 @@
+use crate::rule_prelude::*;
+use ast::{CallExpr, Expr};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow unnecessary `.bind()` calls.
+
+    `.bind()` creates a function whose `this` is bound to a specific value. If the function
+    does not use `this`, or if it is an arrow function which cannot have its `this` changed,
+    the call has no effect and only adds overhead.
+
+    Calls with more than one argument are allowed since they also partially apply arguments.
+    The call is only automatically removed if the bound value cannot have side effects.
+
+    ## Invalid Code Examples
+
+    ```js
+    const a = function() {
+        foo();
+    }.bind(bar);
+
+    const b = (() => {
+        this.foo();
+    }).bind(bar);
+
+    const c = function() {
+        (function() {
+            this.foo();
+        })();
+    }.bind(bar);
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = function() {
+        this.foo();
+    }.bind(bar);
+
+    const b = function(a) {
+        return a + 1;
+    }.bind(foo, bar);
+
+    const c = function() {
+        return () => this.foo();
+    }.bind(bar);
+    ```
+    */
+    #[derive(Default)]
+    NoExtraBind,
+    style,
+    "no-extra-bind"
+}
+
+#[typetag::serde]
+impl CstRule for NoExtraBind {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        let dot = match call.callee()? {
+            Expr::DotExpr(dot) if dot.prop()?.text() == "bind" => dot,
+            _ => return None,
+        };
+        let args = call.arguments()?;
+        let mut bound = args.args();
+        let this = bound.next()?;
+        if bound.next().is_some() || args.syntax().child_with_kind(SPREAD_ELEMENT).is_some() {
+            return None;
+        }
+
+        let object = dot.object()?;
+        let function = unwrap_grouping(object.clone())?;
+        let useless = match &function {
+            Expr::ArrowExpr(_) => true,
+            Expr::FnExpr(func) => !uses_this(func.body()?.syntax()),
+            _ => false,
+        };
+        if !useless {
+            return None;
+        }
+
+        let bind_range = object.syntax().text_range().end()..node.text_range().end();
+        let mut err = ctx
+            .err(
+                self.name(),
+                "the function bound by this call does not use `this`",
+            )
+            .primary(bind_range.clone(), "this `.bind()` call has no effect")
+            .secondary(function.syntax().trimmed_range(), "");
+        if matches!(
+            this,
+            Expr::NameRef(_) | Expr::Literal(_) | Expr::ThisExpr(_)
+        ) {
+            err = err.suggestion(
+                bind_range.clone(),
+                "remove the call",
+                "",
+                Applicability::MaybeIncorrect,
+            );
+            ctx.fixer().delete(bind_range);
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn unwrap_grouping(expr: Expr) -> Option<Expr> {
+    match expr {
+        Expr::GroupingExpr(grouping) => unwrap_grouping(grouping.inner()?),
+        expr => Some(expr),
+    }
+}
+
+/// Whether a function body refers to its own `this`, which is also the `this` of any nested arrow functions.
+fn uses_this(body: &SyntaxNode) -> bool {
+    body.children().any(|child| match child.kind() {
+        THIS_EXPR => true,
+        FN_DECL | FN_EXPR | CLASS_DECL | CLASS_EXPR => false,
+        _ => uses_this(&child),
+    })
+}
+
+rule_tests! {
+    NoExtraBind::default(),
+    err: {
+        "(function() { return 1; }).bind(obj);",
+        "var a = function() { foo(); }.bind(bar);",
+        "var a = (() => { this.foo(); }).bind(bar);",
+        "var a = function() { (function() { this.foo(); })(); }.bind(bar);",
+        "var a = function() { class A { foo() { this.bar(); } } }.bind(bar);",
+        "var a = ((function() {})).bind(baz());"
+    },
+    ok: {
+        "var a = function() { this.foo(); }.bind(bar);",
+        "var a = function() { return () => this.foo(); }.bind(bar);",
+        "var a = function(b) { return b; }.bind(foo, bar);",
+        "var a = function() {}.bind(...args);",
+        "var a = foo.bind(bar);",
+        "var a = function() {}.call(bar);",
+        "var a = function() {}.bind();"
+    }
+}
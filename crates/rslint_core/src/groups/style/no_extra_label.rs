@@ -0,0 +1,149 @@
+use crate::rule_prelude::*;
+use ast::LabelledStmt;
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow labels on `break` and `continue` statements which target the innermost loop or `switch`.
+
+    A `break` or `continue` statement without a label already targets the innermost loop (or `switch`
+    for `break`), therefore a label which refers to that statement is unnecessary and only makes the code
+    harder to read. Labels are only needed to target an outer loop.
+
+    ## Invalid Code Examples
+
+    ```js
+    outer: while (a) {
+        break outer;
+    }
+
+    loop: for (const item of items) {
+        if (item) {
+            continue loop;
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    while (a) {
+        break;
+    }
+
+    outer: for (const row of rows) {
+        for (const item of row) {
+            if (item) {
+                continue outer;
+            }
+        }
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoExtraLabel,
+    style,
+    "no-extra-label"
+}
+
+const LOOPS: [SyntaxKind; 5] = [
+    FOR_STMT,
+    FOR_IN_STMT,
+    FOR_OF_STMT,
+    WHILE_STMT,
+    DO_WHILE_STMT,
+];
+
+const FUNCTIONS: [SyntaxKind; 7] = [
+    FN_DECL,
+    FN_EXPR,
+    ARROW_EXPR,
+    METHOD,
+    GETTER,
+    SETTER,
+    CONSTRUCTOR,
+];
+
+#[typetag::serde]
+impl CstRule for NoExtraLabel {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        if !matches!(node.kind(), BREAK_STMT | CONTINUE_STMT) {
+            return None;
+        }
+        let keyword = node.first_token()?;
+        let label = node
+            .children_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .find(|tok| tok.kind() == IDENT)?;
+
+        let target = node
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| !FUNCTIONS.contains(&ancestor.kind()))
+            .find(|ancestor| {
+                LOOPS.contains(&ancestor.kind())
+                    || (node.kind() == BREAK_STMT && ancestor.kind() == SWITCH_STMT)
+            })?;
+
+        let labelled = target
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| ancestor.kind() == LABELLED_STMT)
+            .filter_map(|ancestor| ancestor.try_to::<LabelledStmt>())
+            .find(|stmt| {
+                stmt.label()
+                    .filter(|name| name.syntax().text() == label.text().as_str())
+                    .is_some()
+            })?;
+
+        if !node.contains_comments() {
+            ctx.fixer().delete(TextRange::new(
+                keyword.text_range().end(),
+                label.text_range().end(),
+            ));
+        }
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "the label `{}` on this `{}` is unnecessary",
+                    label.text(),
+                    keyword.text()
+                ),
+            )
+            .primary(label.text_range(), "")
+            .secondary(
+                labelled.label()?.syntax().trimmed_range(),
+                format!(
+                    "`{}` already targets this statement without a label",
+                    keyword.text()
+                ),
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    NoExtraLabel::default(),
+    err: {
+        "a: while (a) { break a; }",
+        "a: while (a) { if (b) { continue a; } }",
+        "a: for (;;) { break a; }",
+        "a: do { break a; } while (b)",
+        "a: for (const b of c) { continue a; }",
+        "a: switch (a) { case 1: break a; }",
+        "a: b: while (a) { break a; }",
+        "a: while (a) { b: while (b) { break b; } }",
+        "a: while (a) { switch (b) { case 1: continue a; } }"
+    },
+    ok: {
+        "while (a) { break; }",
+        "a: { break a; }",
+        "a: while (a) { while (b) { break a; } }",
+        "a: while (a) { switch (b) { case 1: break a; } }",
+        "a: for (;;) { for (;;) { continue a; } }",
+        "a: if (b) { while (c) { break a; } }"
+    }
+}
@@ -0,0 +1,179 @@
+use crate::rule_prelude::*;
+use crate::util::pattern_names;
+use ast::{CatchClause, LabelledStmt, ParameterList, VarDecl};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow labels which have the same name as a variable in scope.
+
+    Labels and variables are in different namespaces, therefore a label can have the same name as a
+    variable. However this is confusing, because `break foo` or `continue foo` look like they refer to
+    the variable `foo`, and it is unclear which one is meant.
+
+    ## Invalid Code Examples
+
+    ```js
+    var x = foo;
+    x: while (true) {
+        break x;
+    }
+
+    function bar(items) {
+        items: for (const item of items) {
+            continue items;
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    var x = foo;
+    loop: while (true) {
+        break loop;
+    }
+
+    // `x` is not in scope of the label
+    function baz() {
+        var x = foo;
+    }
+    x: while (true) {
+        break x;
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoLabelVar,
+    style,
+    "no-label-var"
+}
+
+const FUNCTIONS: [SyntaxKind; 7] = [
+    FN_DECL,
+    FN_EXPR,
+    ARROW_EXPR,
+    METHOD,
+    GETTER,
+    SETTER,
+    CONSTRUCTOR,
+];
+
+const BLOCKS: [SyntaxKind; 5] = [BLOCK_STMT, FOR_STMT, FOR_IN_STMT, FOR_OF_STMT, SWITCH_STMT];
+
+#[typetag::serde]
+impl CstRule for NoLabelVar {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let label = node.try_to::<LabelledStmt>()?.label()?;
+        let text = label.syntax().text().to_string();
+        let binding = node
+            .ancestors()
+            .skip(1)
+            .find_map(|scope| binding_in_scope(&scope, &text))?;
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!("the label `{}` has the same name as a variable", text),
+            )
+            .primary(label.syntax().trimmed_range(), "")
+            .secondary(
+                binding.trimmed_range(),
+                format!("`{}` is declared here", text),
+            )
+            .footer_help("rename the label so it cannot be confused with the variable");
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Find the binding named `name` declared in a scope, if `scope` is a function, block, catch clause, or the whole file.
+fn binding_in_scope(scope: &SyntaxNode, name: &str) -> Option<SyntaxNode> {
+    let mut names = vec![];
+    if FUNCTIONS.contains(&scope.kind()) {
+        if let Some(params) = scope.child_with_ast::<ParameterList>() {
+            for param in params.parameters() {
+                pattern_names(param, &mut names);
+            }
+        }
+        // the parameter of `a => {}` and the name of a function expression
+        if matches!(scope.kind(), ARROW_EXPR | FN_EXPR) {
+            names.extend(scope.children().filter(|child| child.kind() == NAME));
+        }
+    } else if let Some(clause) = scope.try_to::<CatchClause>() {
+        if let Some(error) = clause.error() {
+            pattern_names(error, &mut names);
+        }
+    } else if !BLOCKS.contains(&scope.kind()) && !matches!(scope.kind(), SCRIPT | MODULE) {
+        return None;
+    }
+
+    for node in scope
+        .descendants()
+        .filter(|node| declaration_scope(node).as_ref() == Some(scope))
+    {
+        declared_names(&node, &mut names);
+    }
+    names.into_iter().find(|binding| binding.text() == name)
+}
+
+/// The scope a declaration declares its names in, `var` declarations are function scoped and the rest are block scoped.
+fn declaration_scope(node: &SyntaxNode) -> Option<SyntaxNode> {
+    let function_scoped = match node.kind() {
+        VAR_DECL => node.to::<VarDecl>().var_token().is_some(),
+        FN_DECL | CLASS_DECL => false,
+        IMPORT_DECL => return node.parent(),
+        _ => return None,
+    };
+    node.ancestors().skip(1).find(|ancestor| {
+        FUNCTIONS.contains(&ancestor.kind())
+            || matches!(ancestor.kind(), SCRIPT | MODULE)
+            || (!function_scoped && BLOCKS.contains(&ancestor.kind()))
+    })
+}
+
+fn declared_names(node: &SyntaxNode, names: &mut Vec<SyntaxNode>) {
+    match node.kind() {
+        VAR_DECL => {
+            for declarator in node.to::<VarDecl>().declared() {
+                if let Some(pattern) = declarator.pattern() {
+                    pattern_names(pattern, names);
+                }
+            }
+        }
+        FN_DECL | CLASS_DECL => names.extend(node.child_with_kind(NAME)),
+        // the local name of `a`, `{ a as b }`, and `* as a`
+        IMPORT_DECL => names.extend(node.descendants().filter(|name| {
+            name.kind() == NAME
+                && (name.parent().map(|parent| parent.kind()) != Some(SPECIFIER)
+                    || name.next_sibling().is_none())
+        })),
+        _ => {}
+    }
+}
+
+rule_tests! {
+    NoLabelVar::default(),
+    err: {
+        "var x = foo; x: while (true) { break x; }",
+        "let x; x: for (;;) { break x; }",
+        "function x() {} x: while (a) {}",
+        "function foo(x) { x: while (a) {} }",
+        "(x => { x: while (a) {} });",
+        "try {} catch (x) { x: while (a) {} }",
+        "for (const x of y) { x: while (a) {} }",
+        "function foo() { if (a) { x: while (a) {} } var x; }",
+        "import { y as x } from 'foo'; x: while (a) {}",
+        "class x {} x: while (a) {}",
+        "function f(a = 1) { a: ; }"
+    },
+    ok: {
+        "var x = foo; loop: while (true) { break loop; }",
+        "function foo() { var x; } x: while (true) { break x; }",
+        "{ let x; } x: while (a) {}",
+        "x: while (a) { break x; }",
+        "import { x as y } from 'foo'; x: while (a) {}",
+        "foo.x = 5; x: while (a) {}",
+        "x: while (a) {} function foo(x) {}"
+    }
+}
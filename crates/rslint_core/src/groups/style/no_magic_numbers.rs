@@ -0,0 +1,222 @@
+use crate::rule_prelude::*;
+use ast::{AssignExpr, BracketExpr, Expr, Literal, PatternOrExpr, UnaryExpr, UnaryOp, VarDecl};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow numbers which are used inline instead of being declared as a named constant.
+
+    "Magic numbers" are numbers which appear in code without any explanation of what they mean.
+    Assigning them to a named constant makes the code easier to read, and makes the number easier
+    to change later. Numbers which are the value of a variable declaration are always allowed.
+
+    Object properties and assignments to properties are allowed unless `detectObjects` is enabled.
+
+    ## Invalid Code Examples
+
+    ```js
+    const total = price * 1.08;
+
+    setTimeout(refresh, 60000);
+    ```
+
+    ```js
+    // with `enforceConst` enabled
+    let TAX = 1.08;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const TAX = 1.08;
+    const total = price * TAX;
+    ```
+
+    ```js
+    // with `ignore` set to `[1]`
+    const next = index + 1;
+    ```
+
+    ```js
+    // with `ignoreArrayIndexes` enabled
+    const first = items[0];
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoMagicNumbers,
+    style,
+    "no-magic-numbers",
+    /// Numbers which are always allowed, such as `0` or `1`, empty by default.
+    pub ignore: Vec<f64>,
+    /// Allow numbers used as array indexes, such as `items[0]` (false by default).
+    pub ignore_array_indexes: bool,
+    /// Require numbers to be declared with `const` instead of `let` or `var` (false by default).
+    pub enforce_const: bool,
+    /// Also disallow numbers in object properties and assignments to properties (false by default).
+    pub detect_objects: bool
+}
+
+#[typetag::serde]
+impl CstRule for NoMagicNumbers {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let mut value = node.try_to::<Literal>()?.as_number()?;
+        // `-1` is a unary expression, but it should be treated as the number `-1`
+        let number = match node.parent()?.try_to::<UnaryExpr>() {
+            Some(unary) if unary.op() == Some(UnaryOp::Minus) => {
+                value = -value;
+                unary.syntax().clone()
+            }
+            Some(unary) if unary.op() == Some(UnaryOp::Plus) => unary.syntax().clone(),
+            _ => node.clone(),
+        };
+        if self.ignore.contains(&value) {
+            return None;
+        }
+
+        let (child, parent) = parent_skipping_parens(&number)?;
+        if is_parse_int_radix(&child, &parent)
+            || (self.ignore_array_indexes && is_array_index(&child, &parent, value))
+        {
+            return None;
+        }
+
+        match parent.kind() {
+            DECLARATOR => {
+                let decl = parent.parent()?.try_to::<VarDecl>()?;
+                if !self.enforce_const || decl.is_const() {
+                    return None;
+                }
+                let err = ctx
+                    .err(
+                        self.name(),
+                        "number constants must be declared with `const`",
+                    )
+                    .primary(number.trimmed_range(), "")
+                    .secondary(
+                        decl.syntax().first_token()?.text_range(),
+                        "this declaration is not `const`",
+                    );
+                ctx.add_err(err);
+                return None;
+            }
+            LITERAL_PROP if !self.detect_objects => return None,
+            ASSIGN_EXPR if !self.detect_objects => {
+                // the target of an invalid assignment such as `x! += 1` is an error node
+                if parent.first_child()?.kind() == ERROR {
+                    return None;
+                }
+                let assign = parent.to::<AssignExpr>();
+                if !matches!(
+                    assign.lhs(),
+                    Some(PatternOrExpr::Expr(Expr::NameRef(_))) | None
+                ) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!("magic number: `{}`", number.trimmed_text()),
+            )
+            .primary(number.trimmed_range(), "")
+            .footer_help("assign this number to a constant with a name which explains what it is");
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Get the outermost parenthesized expression around a node and its parent.
+fn parent_skipping_parens(node: &SyntaxNode) -> Option<(SyntaxNode, SyntaxNode)> {
+    let mut child = node.clone();
+    let mut parent = node.parent()?;
+    while parent.kind() == GROUPING_EXPR {
+        child = parent;
+        parent = child.parent()?;
+    }
+    Some((child, parent))
+}
+
+/// Whether a number is the radix of `parseInt(str, radix)` or `Number.parseInt(str, radix)`.
+fn is_parse_int_radix(number: &SyntaxNode, parent: &SyntaxNode) -> bool {
+    parent.kind() == ARG_LIST
+        && parent.children().nth(1).as_ref() == Some(number)
+        && parent
+            .parent()
+            .and_then(|call| call.try_to::<ast::CallExpr>()?.callee())
+            .filter(|callee| {
+                let callee = callee.syntax();
+                callee.structural_lossy_token_eq(&["parseInt"])
+                    || callee.structural_lossy_token_eq(&["Number", ".", "parseInt"])
+            })
+            .is_some()
+}
+
+/// Whether a number is used as a valid array index, such as `items[0]`.
+fn is_array_index(number: &SyntaxNode, parent: &SyntaxNode, value: f64) -> bool {
+    parent
+        .try_to::<BracketExpr>()
+        .and_then(|expr| expr.prop())
+        .filter(|prop| prop.syntax() == number)
+        .is_some()
+        && value >= 0.0
+        && value.fract() == 0.0
+        && value < u32::MAX as f64
+}
+
+rule_tests! {
+    NoMagicNumbers::default(),
+    err: {
+        "const total = price * 1.08;",
+        "setTimeout(refresh, 60000);",
+        "foo(-1);",
+        "let a; a = 5;",
+        "items[0];",
+        "if (a > 10) {}",
+        "parseInt(a, 10, 5);",
+        "foo((5));"
+    },
+    ok: {
+        "const TAX = 1.08;",
+        "let count = 5;",
+        "var MINUS_ONE = -1;",
+        "const a = (5);",
+        "parseInt(a, 10);",
+        "Number.parseInt(a, 2);",
+        "obj = { foo: 5 };",
+        "obj.foo = 5;",
+        "foo('5');",
+        "x! += 1;"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    NoMagicNumbers {
+        ignore: vec![0.0, 1.0, -1.0],
+        ignore_array_indexes: true,
+        enforce_const: true,
+        detect_objects: true
+    },
+    err: {
+        "let TAX = 1.08;",
+        "var a = 5;",
+        "items[-5];",
+        "items[1.5];",
+        "obj = { foo: 5 };",
+        "obj.foo = 5;",
+        "foo(2);"
+    },
+    ok: {
+        "const TAX = 1.08;",
+        "items[5];",
+        "foo(0);",
+        "foo(index + 1);",
+        "foo(-1);",
+        "let a = 1;"
+    }
+}
@@ -0,0 +1,230 @@
+use crate::rule_prelude::*;
+use ast::{Expr, VarDecl};
+
+declare_lint! {
+    /**
+    Disallow `require` calls to be mixed with other variable declarations.
+
+    In Node.js, modules are usually loaded with `require` calls at the top of a file. Declaring
+    other variables in the same declaration as the `require` calls makes it harder to see what
+    the dependencies of a file are.
+
+    Uninitialized variables such as `var a;` are not considered to be mixed with `require` calls.
+
+    With the `grouping` option, `require` calls of different types are also not allowed in the
+    same declaration. The types are core modules (`require("fs")`), files (`require("./foo")`),
+    modules (`require("lodash")`), and computed paths (`require(path)`).
+
+    With the `allowCall` option, calling the result of `require` such as `require("debug")("app")`
+    is considered a `require` call.
+
+    ## Invalid Code Examples
+
+    ```js
+    var fs = require("fs"),
+        i = 0;
+
+    var async = require("async"),
+        debug = require("diagnostics")("my-module"),
+        eventEmitter = require("events").EventEmitter;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    var eventEmitter = require("events").EventEmitter,
+        myUtils = require("./utils"),
+        util = require("util"),
+        bar = require(getBarModuleName());
+
+    var foo = 42,
+        bar = "baz";
+
+    var fs = require("fs"),
+        path;
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoMixedRequires,
+    style,
+    "no-mixed-requires",
+    /// Whether to disallow mixing `require` calls of different types in a declaration.
+    pub grouping: bool,
+    /// Whether calling the result of `require` such as `require("debug")("app")` is considered a `require` call.
+    pub allow_call: bool
+}
+
+/// Node.js core modules, used for classifying `require` calls with `grouping`.
+const CORE_MODULES: [&str; 35] = [
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "timers",
+    "tls",
+    "tty",
+    "url",
+    "util",
+    "vm",
+    "zlib",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RequireKind {
+    Core,
+    File,
+    Module,
+    Computed,
+}
+
+#[typetag::serde]
+impl CstRule for NoMixedRequires {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let decl = node.try_to::<VarDecl>()?;
+        let values = decl
+            .declared()
+            .filter_map(|declarator| declarator.value())
+            .collect::<Vec<_>>();
+        if values.len() < 2 {
+            return None;
+        }
+
+        let kinds = values
+            .iter()
+            .map(|value| self.require_kind(value))
+            .collect::<Vec<_>>();
+        let (requires, others): (Vec<_>, Vec<_>) = values
+            .iter()
+            .zip(&kinds)
+            .partition(|(_, kind)| kind.is_some());
+
+        if !requires.is_empty() && !others.is_empty() {
+            let mut err = ctx
+                .err(
+                    self.name(),
+                    "do not mix `require` calls and other declarations",
+                )
+                .primary(node.trimmed_range(), "");
+            for (value, _) in &requires {
+                err = err.secondary(value.syntax().trimmed_range(), "this is a `require` call");
+            }
+            for (value, _) in &others {
+                err = err.secondary(value.syntax().trimmed_range(), "...but this is not");
+            }
+            ctx.add_err(err);
+            return None;
+        }
+
+        if self.grouping {
+            let first = requires.first().and_then(|(_, kind)| **kind);
+            if requires.iter().any(|(_, kind)| **kind != first) {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        "do not mix core, module, file, and computed `require` calls",
+                    )
+                    .primary(node.trimmed_range(), "");
+                ctx.add_err(err);
+            }
+        }
+        None
+    }
+}
+
+impl NoMixedRequires {
+    /// Get the kind of require an initializer is, or `None` if it is not a `require` call.
+    fn require_kind(&self, value: &Expr) -> Option<RequireKind> {
+        match value {
+            Expr::CallExpr(call) => match call.callee()? {
+                Expr::NameRef(name) if name.syntax().text() == "require" => {
+                    let arg = call.arguments()?.args().next();
+                    Some(match arg {
+                        Some(Expr::Literal(lit)) if lit.is_string() => {
+                            let path = lit.inner_string_text()?.to_string();
+                            if path.starts_with("./")
+                                || path.starts_with("../")
+                                || path.starts_with('/')
+                            {
+                                RequireKind::File
+                            } else if CORE_MODULES.contains(&path.trim_start_matches("node:")) {
+                                RequireKind::Core
+                            } else {
+                                RequireKind::Module
+                            }
+                        }
+                        _ => RequireKind::Computed,
+                    })
+                }
+                callee if self.allow_call => self.require_kind(&callee),
+                _ => None,
+            },
+            Expr::DotExpr(dot) => self.require_kind(&dot.object()?),
+            Expr::BracketExpr(bracket) => self.require_kind(&bracket.object()?),
+            _ => None,
+        }
+    }
+}
+
+rule_tests! {
+    NoMixedRequires::default(),
+    err: {
+        "var fs = require(\"fs\"), x = 1;",
+        "var fs = require('fs'), foo = bar();",
+        "var a = require('a').b, c = 5;",
+        "var a = require('a')('b'), c = require('c');",
+        "let a = 1, b = require('b');"
+    },
+    ok: {
+        "var fs = require(\"fs\"), path = require(\"path\");",
+        "var fs = require('fs'), a;",
+        "var a = 1, b = 2;",
+        "var a = require('a');",
+        "var a = require('a').b, c = require('./c')[d];",
+        "var a = require('a'), b = require(path);"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    NoMixedRequires {
+        grouping: true,
+        allow_call: true,
+    },
+    err: {
+        "var fs = require('fs'), a = require('./a');",
+        "var a = require('lodash'), b = require(path);",
+        "var debug = require('debug')('app'), a = 1;"
+    },
+    ok: {
+        "var fs = require('fs'), path = require('node:path');",
+        "var a = require('./a'), b = require('../b');",
+        "var a = require('lodash'), b = require('async');",
+        "var debug = require('debug')('app'), a = require('a');"
+    }
+}
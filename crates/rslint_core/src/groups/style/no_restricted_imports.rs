@@ -0,0 +1,296 @@
+use crate::rule_prelude::*;
+use ast::{ExportNamed, ExportWildcard, ImportClause, ImportDecl, Specifier};
+use rslint_parser::TextRange;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow importing certain modules or certain names from modules.
+
+    Some modules may be deprecated, too big, or have a better alternative in a codebase.
+    This rule takes a list of module `paths` which may not be imported, optionally restricting
+    only certain named imports from the module, as well as a list of glob `patterns` for
+    module names which may not be imported. Patterns may use `*` to match any sequence of
+    characters, and patterns starting with `!` re-allow modules matched by a previous pattern.
+
+    Re-exports from a module (`export { foo } from "lodash"`) are also checked.
+
+    ## Invalid Code Examples
+
+    ```js
+    /* paths: [{ "name": "lodash", "message": "use lodash-es instead" }] */
+
+    import _ from "lodash";
+    import { map } from "lodash";
+    export * from "lodash";
+    ```
+
+    ```js
+    /* paths: [{ "name": "lib", "importNames": ["deprecated"] }] */
+
+    import { deprecated } from "lib";
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    /* paths: [{ "name": "lodash" }, { "name": "lib", "importNames": ["deprecated"] }] */
+
+    import { map } from "lodash-es";
+    import { supported } from "lib";
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoRestrictedImports,
+    style,
+    "no-restricted-imports",
+    /// A list of modules which may not be imported.
+    pub paths: Vec<RestrictedPath>,
+    /// A list of glob patterns matching module names which may not be imported.
+    pub patterns: Vec<String>
+}
+
+/// A module, or named imports of a module, which may not be imported.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestrictedPath {
+    /// The name of the module, such as `lodash`.
+    pub name: String,
+    /// The names which may not be imported from the module, the whole module is restricted if this is empty.
+    /// A default import is named `default`.
+    #[serde(default)]
+    pub import_names: Vec<String>,
+    /// An optional message explaining why the module is restricted or what to use instead.
+    pub message: Option<String>,
+}
+
+#[typetag::serde]
+impl CstRule for NoRestrictedImports {
+    fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        for child in root.children() {
+            match child.kind() {
+                IMPORT_DECL => self.check_import(child.to(), ctx),
+                // `export { a } from "a"` is wrapped in an export declaration, `export * from "a"` is not
+                EXPORT_DECL => child
+                    .first_child()
+                    .and_then(|inner| self.check_export(&inner, ctx)),
+                EXPORT_WILDCARD => self.check_export(&child, ctx),
+                _ => None,
+            };
+        }
+        None
+    }
+}
+
+impl NoRestrictedImports {
+    fn check_import(&self, import: ImportDecl, ctx: &mut RuleCtx) -> Option<()> {
+        let (source, range) = import_source(&import)?;
+        let mut names = vec![];
+        for clause in import.imports() {
+            match clause {
+                ImportClause::Name(name) => {
+                    names.push(("default".to_string(), name.syntax().trimmed_range()))
+                }
+                ImportClause::NamedImports(named) => {
+                    names.extend(named.specifiers().filter_map(specifier_name))
+                }
+                ImportClause::WildcardImport(wildcard) => {
+                    names.push(("*".to_string(), wildcard.syntax().trimmed_range()))
+                }
+                ImportClause::ImportStringSpecifier(_) => {}
+            }
+        }
+        self.check(&source, range, names, ctx);
+        None
+    }
+
+    fn check_export(&self, export: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let names = match export.kind() {
+            EXPORT_NAMED => export
+                .to::<ExportNamed>()
+                .specifiers()
+                .filter_map(specifier_name)
+                .collect(),
+            EXPORT_WILDCARD => {
+                let star = export.to::<ExportWildcard>().star_token()?;
+                vec![("*".to_string(), star.text_range())]
+            }
+            _ => return None,
+        };
+        let source = export
+            .children()
+            .find_map(|x| x.try_to::<ast::Literal>().filter(|x| x.is_string()))?;
+        let text = source.inner_string_text()?.to_string();
+        self.check(&text, source.syntax().trimmed_range(), names, ctx);
+        None
+    }
+
+    fn check(
+        &self,
+        source: &str,
+        source_range: TextRange,
+        names: Vec<(String, TextRange)>,
+        ctx: &mut RuleCtx,
+    ) {
+        for path in self.paths.iter().filter(|path| path.name == source) {
+            if path.import_names.is_empty() {
+                let err = ctx
+                    .err(self.name(), format!("`{}` import is restricted", source))
+                    .primary(source_range, "");
+                ctx.add_err(with_message(err, &path.message));
+                continue;
+            }
+
+            for (name, range) in &names {
+                let err = if name == "*" {
+                    ctx.err(
+                        self.name(),
+                        format!(
+                            "wildcard import from `{}` includes restricted names",
+                            source
+                        ),
+                    )
+                    .primary(*range, "")
+                    .footer_note(format!(
+                        "restricted names are {}",
+                        path.import_names
+                            .iter()
+                            .map(|name| format!("`{}`", name))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                } else if path.import_names.contains(name) {
+                    ctx.err(
+                        self.name(),
+                        format!("`{}` import from `{}` is restricted", name, source),
+                    )
+                    .primary(*range, "")
+                } else {
+                    continue;
+                };
+                ctx.add_err(with_message(err, &path.message));
+            }
+        }
+
+        if self.matches_pattern(source) {
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!("`{}` import is restricted by a pattern", source),
+                )
+                .primary(source_range, "");
+            ctx.add_err(err);
+        }
+    }
+
+    fn matches_pattern(&self, source: &str) -> bool {
+        let mut restricted = false;
+        for pattern in &self.patterns {
+            match pattern.strip_prefix('!') {
+                Some(negated) if restricted && glob_match(negated, source) => restricted = false,
+                None if !restricted && glob_match(pattern, source) => restricted = true,
+                _ => {}
+            }
+        }
+        restricted
+    }
+}
+
+fn with_message(err: Diagnostic, message: &Option<String>) -> Diagnostic {
+    match message {
+        Some(message) => err.footer_help(message),
+        None => err,
+    }
+}
+
+/// The source of an import and its range, `ImportDecl::source` does not handle `import "foo"`.
+fn import_source(import: &ImportDecl) -> Option<(String, TextRange)> {
+    if let Some(lit) = import.source() {
+        return Some((
+            lit.inner_string_text()?.to_string(),
+            lit.syntax().trimmed_range(),
+        ));
+    }
+    let token = import
+        .syntax()
+        .child_with_kind(IMPORT_STRING_SPECIFIER)?
+        .first_token()?;
+    let text = token.text();
+    Some((text[1..text.len() - 1].to_string(), token.text_range()))
+}
+
+/// The imported name of a specifier, such as `a` in `{ a as b }`.
+fn specifier_name(specifier: Specifier) -> Option<(String, TextRange)> {
+    let name = specifier.name()?;
+    Some((name.trimmed_text().to_string(), name.trimmed_range()))
+}
+
+/// Match a module name against a glob where `*` matches any sequence of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match text.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let mut parts = parts.collect::<Vec<_>>();
+    let last = match parts.pop() {
+        Some(last) => last,
+        None => return rest.is_empty(),
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+rule_tests! {
+    NoRestrictedImports {
+        paths: vec![
+            RestrictedPath {
+                name: "lodash".to_string(),
+                import_names: vec![],
+                message: Some("use lodash-es instead".to_string()),
+            },
+            RestrictedPath {
+                name: "lib".to_string(),
+                import_names: vec!["deprecated".to_string(), "default".to_string()],
+                message: None,
+            },
+        ],
+        patterns: vec!["internal/*".to_string(), "!internal/public".to_string()],
+    },
+    err: {
+        "import _ from \"lodash\";",
+        "import { map } from 'lodash';",
+        "import \"lodash\";",
+        "import * as _ from \"lodash\";",
+        "export { map } from \"lodash\";",
+        "export * from \"lodash\";",
+        "import { deprecated } from \"lib\";",
+        "import { deprecated as old, supported } from \"lib\";",
+        "import lib from \"lib\";",
+        "import * as lib from \"lib\";",
+        "export { deprecated } from \"lib\";",
+        "export * from \"lib\";",
+        "import { a } from \"internal/foo\";",
+        "import \"internal/foo/bar\";"
+    },
+    ok: {
+        "import _ from \"lodash-es\";",
+        "import { map } from \"lodash/map\";",
+        "import { supported } from \"lib\";",
+        "import { supported as deprecated } from \"lib\";",
+        "export { supported } from \"lib\";",
+        "import { a } from \"internal/public\";",
+        "import { a } from \"internal\";",
+        "export { lodash };",
+        "const _ = require(\"lodash\");"
+    }
+}
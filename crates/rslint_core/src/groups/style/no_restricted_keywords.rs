@@ -0,0 +1,110 @@
+use crate::rule_prelude::*;
+
+declare_lint! {
+    /**
+    Disallow certain keywords.
+
+    Some keywords such as `debugger` or `with` may be unwanted in a codebase. This rule takes a list
+    of keywords which may not be used, each with an optional message explaining why the keyword is
+    restricted or what to use instead. Only actual keywords are matched, identifiers and strings
+    with the same text are allowed.
+
+    This rule only looks at tokens, which makes it very cheap to run.
+
+    ## Invalid Code Examples
+
+    ```js
+    /* keywords: [{ "keyword": "debugger" }, { "keyword": "with", "message": "use destructuring instead" }] */
+
+    debugger;
+
+    with (foo) {
+        bar();
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    /* keywords: [{ "keyword": "debugger" }] */
+
+    let debuggerEnabled = false;
+    foo.debugger = "debugger";
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoRestrictedKeywords,
+    style,
+    "no-restricted-keywords",
+    /// A list of keywords which may not be used, with an optional message to show.
+    pub keywords: Vec<RestrictedKeyword>
+}
+
+/// A keyword which may not be used.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RestrictedKeyword {
+    /// The restricted keyword, such as `debugger`.
+    pub keyword: String,
+    /// An optional message explaining why the keyword is restricted or what to use instead.
+    pub message: Option<String>,
+}
+
+#[typetag::serde]
+impl CstRule for NoRestrictedKeywords {
+    fn check_token(&self, token: &SyntaxToken, ctx: &mut RuleCtx) -> Option<()> {
+        if !token.kind().is_keyword() {
+            return None;
+        }
+        let restricted = self
+            .keywords
+            .iter()
+            .find(|restricted| restricted.keyword == token.text().as_str())?;
+
+        let mut err = ctx
+            .err(
+                self.name(),
+                format!("the `{}` keyword is restricted", restricted.keyword),
+            )
+            .primary(token.text_range(), "");
+        if let Some(message) = &restricted.message {
+            err = err.footer_help(message);
+        }
+        ctx.add_err(err);
+        None
+    }
+
+    fn token_only(&self) -> bool {
+        true
+    }
+}
+
+rule_tests! {
+    NoRestrictedKeywords {
+        keywords: vec![
+            RestrictedKeyword {
+                keyword: "debugger".to_string(),
+                message: None,
+            },
+            RestrictedKeyword {
+                keyword: "void".to_string(),
+                message: Some("use `undefined` instead".to_string()),
+            },
+        ]
+    },
+    err: {
+        "debugger;",
+        "if (foo) { debugger }",
+        "function foo() { debugger; }",
+        "let a = void 0;"
+    },
+    ok: {
+        "let debuggerEnabled = false;",
+        "foo.debugger = 'debugger';",
+        "({ debugger: 1, void: 2 })",
+        "// debugger",
+        "`debugger`",
+        "let a = undefined;"
+    }
+}
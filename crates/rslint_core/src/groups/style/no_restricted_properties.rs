@@ -0,0 +1,210 @@
+use crate::rule_prelude::*;
+use ast::{
+    AssignExpr, BracketExpr, Declarator, DotExpr, Expr, Literal, ObjectPattern, ObjectPatternProp,
+    Pattern, PatternOrExpr, PropName,
+};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow certain properties on certain objects.
+
+    Some properties of objects may be deprecated, or have a better alternative in a codebase.
+    This rule takes a list of objects and properties which may not be used, either through
+    member access (`Math.pow`, `Math["pow"]`) or through destructuring (`const { pow } = Math`).
+
+    Objects are matched by their source text ignoring whitespace, such as `Math` or `require("foo")`.
+    If `object` is omitted the property is restricted on every object, and if `property` is omitted
+    every property of the object is restricted.
+
+    ## Invalid Code Examples
+
+    ```js
+    /* restricted: [{ "object": "Math", "property": "pow", "message": "use the `**` operator instead" }] */
+
+    let a = Math.pow(2, 5);
+    let { pow } = Math;
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    NoRestrictedProperties,
+    style,
+    "no-restricted-properties",
+    /// A list of object and property pairs which are restricted, with an optional message to show.
+    pub restricted: Vec<RestrictedProperty>
+}
+
+/// An object and/or property which may not be used.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RestrictedProperty {
+    /// The object the property is restricted on, or any object if omitted.
+    pub object: Option<String>,
+    /// The restricted property, or any property if omitted.
+    pub property: Option<String>,
+    /// An optional message explaining why the property is restricted or what to use instead.
+    pub message: Option<String>,
+}
+
+impl RestrictedProperty {
+    fn matches(&self, object: &str, property: &str) -> bool {
+        match (&self.object, &self.property) {
+            (None, None) => false,
+            (Some(obj), prop) => {
+                obj.chars().filter(|c| !c.is_whitespace()).eq(object.chars())
+                    && prop.as_ref().filter(|p| *p != property).is_none()
+            }
+            (None, Some(prop)) => prop == property,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoRestrictedProperties {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        match node.kind() {
+            DOT_EXPR => {
+                let expr = node.to::<DotExpr>();
+                let prop = expr.prop()?;
+                self.check(&expr.object()?, prop.syntax(), &prop.text(), ctx);
+            }
+            BRACKET_EXPR => {
+                let expr = node.to::<BracketExpr>();
+                let prop = match expr.prop()? {
+                    Expr::Literal(lit) if lit.is_string() => lit,
+                    _ => return None,
+                };
+                let text = prop.inner_string_text()?.to_string();
+                self.check(&expr.object()?, prop.syntax(), &text, ctx);
+            }
+            DECLARATOR => {
+                let decl = node.to::<Declarator>();
+                if let Some(Pattern::ObjectPattern(pat)) = decl.pattern() {
+                    self.check_pattern(&decl.value()?, pat, ctx);
+                }
+            }
+            // the target of an invalid assignment such as `x! += 1` is an error node
+            ASSIGN_EXPR if node.first_child()?.kind() != ERROR => {
+                let expr = node.to::<AssignExpr>();
+                if let Some(PatternOrExpr::Pattern(Pattern::ObjectPattern(pat))) = expr.lhs() {
+                    self.check_pattern(&expr.rhs()?, pat, ctx);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+impl NoRestrictedProperties {
+    fn check_pattern(&self, object: &Expr, pattern: ObjectPattern, ctx: &mut RuleCtx) {
+        for prop in pattern.elements() {
+            let key = match prop {
+                ObjectPatternProp::SinglePattern(single) => {
+                    single.name().map(|n| n.syntax().clone())
+                }
+                ObjectPatternProp::AssignPattern(assign) => match assign.key() {
+                    Some(Pattern::SinglePattern(single)) => {
+                        single.name().map(|n| n.syntax().clone())
+                    }
+                    _ => None,
+                },
+                ObjectPatternProp::KeyValuePattern(kv) => match kv.key() {
+                    Some(PropName::Ident(name)) => Some(name.syntax().clone()),
+                    Some(PropName::Literal(lit)) if lit.is_string() => Some(lit.syntax().clone()),
+                    _ => None,
+                },
+                ObjectPatternProp::RestPattern(_) => None,
+            };
+
+            if let Some(key) = key {
+                let text = match key.try_to::<Literal>() {
+                    Some(lit) => lit.inner_string_text().map(|t| t.to_string()),
+                    None => Some(key.trimmed_text().to_string()),
+                };
+                if let Some(text) = text {
+                    self.check(object, &key, &text, ctx);
+                }
+            }
+        }
+    }
+
+    fn check(&self, object: &Expr, prop: &SyntaxNode, prop_text: &str, ctx: &mut RuleCtx) {
+        let object_text = object
+            .syntax()
+            .lossy_tokens()
+            .into_iter()
+            .map(|tok| tok.text().to_string())
+            .collect::<String>();
+
+        let restricted = match self
+            .restricted
+            .iter()
+            .find(|restricted| restricted.matches(&object_text, prop_text))
+        {
+            Some(restricted) => restricted,
+            None => return,
+        };
+
+        let mut err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "use of restricted property `{}.{}`",
+                    object.syntax().trimmed_text(),
+                    prop_text
+                ),
+            )
+            .primary(prop.trimmed_range(), "");
+
+        if let Some(message) = &restricted.message {
+            err = err.footer_help(message);
+        }
+        ctx.add_err(err);
+    }
+}
+
+rule_tests! {
+    NoRestrictedProperties {
+        restricted: vec![
+            RestrictedProperty {
+                object: Some("Math".to_string()),
+                property: Some("pow".to_string()),
+                message: Some("use the `**` operator instead".to_string()),
+            },
+            RestrictedProperty {
+                object: Some("require(\"foo\")".to_string()),
+                property: None,
+                message: None,
+            },
+            RestrictedProperty {
+                object: None,
+                property: Some("__defineGetter__".to_string()),
+                message: None,
+            },
+        ]
+    },
+    err: {
+        "Math.pow(2, 5)",
+        "Math['pow'](2, 5)",
+        "Math . pow",
+        "let { pow } = Math;",
+        "let { pow: power } = Math;",
+        "({ pow } = Math);",
+        "require(\"foo\").bar",
+        "require( \"foo\" ).baz()",
+        "foo.__defineGetter__('a', () => {})",
+        "bar['__defineGetter__']"
+    },
+    ok: {
+        "Math.max(2, 5)",
+        "Math[pow]",
+        "let { max } = Math;",
+        "let { pow } = foo;",
+        "foo.pow",
+        "pow(2, 5)",
+        "require(\"bar\").baz",
+        "x! += 1;"
+    }
+}
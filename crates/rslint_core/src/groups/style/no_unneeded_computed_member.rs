@@ -0,0 +1,159 @@
+use crate::rule_prelude::*;
+use ast::{BracketExpr, Expr};
+use rslint_lexer::{Lexer, Token};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow computed member access with a string literal which is a valid identifier.
+
+    Accessing a property with a string literal such as `foo["bar"]` is harder to read than the
+    equivalent `foo.bar`, computed member access should only be used for properties which are
+    not valid identifiers or which are dynamic.
+
+    Keywords such as `foo["class"]` are allowed as property names with dot notation since ES5.
+    If you need to support older environments, set `allowKeywords` to `false` to not flag them.
+
+    ## Invalid Code Examples
+
+    ```js
+    foo["bar"];
+
+    foo['baz'] = 5;
+
+    foo?.["bar"];
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    foo.bar;
+
+    foo["bar-baz"];
+
+    foo["5"];
+
+    foo[bar];
+    ```
+    */
+    #[serde(default)]
+    NoUnneededComputedMember,
+    style,
+    "no-unneeded-computed-member",
+    /// Whether keywords may be accessed with dot notation, which flags `foo["class"]`, `true` by default.
+    pub allow_keywords: bool
+}
+
+impl Default for NoUnneededComputedMember {
+    fn default() -> Self {
+        Self {
+            allow_keywords: true,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for NoUnneededComputedMember {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let expr = node.try_to::<BracketExpr>()?;
+        let lit = match expr.prop()? {
+            Expr::Literal(lit) => lit,
+            _ => return None,
+        };
+        let name = lit.inner_string_text()?.to_string();
+        if !self.is_dot_accessible(&name) {
+            return None;
+        }
+        // `1["foo"]` cannot be written as `1.foo`
+        if let Some(Expr::Literal(obj)) = expr.object() {
+            if obj.is_number() {
+                return None;
+            }
+        }
+
+        let start = expr.l_brack_token()?.text_range().start();
+        let end = expr.r_brack_token()?.text_range().end();
+        let replacement = if node.token_with_kind(QUESTIONDOT).is_some() {
+            name.clone()
+        } else {
+            format!(".{}", name)
+        };
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!("`[{}]` is better written in dot notation", lit.syntax()),
+            )
+            .primary(start..end, "")
+            .suggestion(
+                start..end,
+                "use dot notation instead",
+                replacement.clone(),
+                Applicability::MaybeIncorrect,
+            );
+
+        ctx.fixer().replace(start..end, replacement);
+        ctx.add_err(err);
+        None
+    }
+}
+
+impl NoUnneededComputedMember {
+    fn is_dot_accessible(&self, name: &str) -> bool {
+        // escapes are allowed in identifiers but they are confusing to rewrite
+        if name.is_empty() || name.contains('\\') {
+            return false;
+        }
+        let mut tokens = Lexer::from_str(name, 0).map(|(tok, _)| tok);
+        match (tokens.next(), tokens.next()) {
+            (Some(tok), None) | (Some(tok), Some(Token { kind: EOF, .. }))
+                if tok.len == name.len() =>
+            {
+                tok.kind == IDENT || (tok.kind.is_keyword() && self.allow_keywords)
+            }
+            _ => false,
+        }
+    }
+}
+
+rule_tests! {
+    NoUnneededComputedMember::default(),
+    err: {
+        "a[\"b\"];",
+        "a['b'] = 5;",
+        "a.b[\"c\"].d;",
+        "a?.[\"b\"];",
+        "a[\"$foo_bar\"];",
+        "a[\"class\"];",
+        "a[\"null\"];",
+        "this[\"b\"]();"
+    },
+    ok: {
+        "a[\"b-c\"];",
+        "a.b;",
+        "a[b];",
+        "a[\"5\"];",
+        "a[\"\"];",
+        "a[\"b c\"];",
+        "a[\"b.c\"];",
+        "a[5];",
+        "a[`b`];",
+        "a[\"\\u0062\"];",
+        "1[\"toString\"];"
+    }
+}
+
+rule_tests! {
+    disallow_keywords_valid,
+    disallow_keywords_invalid,
+    NoUnneededComputedMember {
+        allow_keywords: false,
+    },
+    err: {
+        "a[\"b\"];"
+    },
+    ok: {
+        "a[\"class\"];",
+        "a[\"true\"];"
+    }
+}
@@ -0,0 +1,208 @@
+use crate::rule_prelude::*;
+use ast::{Constructor, Expr, Stmt};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Disallow constructors which are unnecessary.
+
+    Classes have a default constructor if they do not declare one. For classes without a parent class,
+    the default constructor is empty, and for classes which extend another class, the default constructor
+    passes all of its arguments to the parent class' constructor. Therefore, an empty constructor in a
+    class without a parent, or a constructor which only calls `super` with its own arguments, can be removed.
+
+    ## Invalid Code Examples
+
+    ```js
+    class A {
+        constructor() {}
+    }
+
+    class B extends A {
+        constructor(foo, ...bar) {
+            super(foo, ...bar);
+        }
+    }
+
+    class C extends A {
+        constructor() {
+            super(...arguments);
+        }
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    class A {
+        constructor() {
+            this.foo = 5;
+        }
+    }
+
+    class B extends A {
+        constructor(foo) {
+            super(foo, 5);
+        }
+    }
+
+    class C extends A {
+        constructor(foo = 5) {
+            super(foo);
+        }
+    }
+    ```
+    */
+    #[derive(Default)]
+    NoUselessConstructor,
+    style,
+    "no-useless-constructor"
+}
+
+#[typetag::serde]
+impl CstRule for NoUselessConstructor {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let constructor = node.try_to::<Constructor>()?;
+        // `private constructor() {}` changes the visibility of the class in TypeScript
+        if constructor.accessibility().is_some() {
+            return None;
+        }
+        let class = node.parent()?.parent()?;
+        let extends = class
+            .children_with_tokens()
+            .any(|elem| elem.kind() == EXTENDS_KW);
+        let stmts = constructor.body()?.stmts().collect::<Vec<_>>();
+
+        let (useless, removable) = match stmts.as_slice() {
+            // parameters with defaults or destructuring can have side effects or throw even if the body is empty
+            [] if !extends => (true, simple_params(&constructor).is_some()),
+            [Stmt::ExprStmt(stmt)] if extends => match stmt.expr()? {
+                Expr::SuperCall(call) => {
+                    let args = call.arguments()?;
+                    (
+                        passes_arguments(&constructor, args.syntax()).unwrap_or(false),
+                        forwards_all_arguments(&constructor, args.syntax()).unwrap_or(false),
+                    )
+                }
+                _ => (false, false),
+            },
+            _ => (false, false),
+        };
+        if !useless {
+            return None;
+        }
+
+        let mut err = ctx
+            .err(self.name(), "this constructor is unnecessary")
+            .primary(
+                node.trimmed_range(),
+                if extends {
+                    "this constructor only passes its arguments to the parent class"
+                } else {
+                    "this constructor is empty"
+                },
+            )
+            .footer_help(
+                "classes have a default constructor, therefore this constructor can be removed",
+            );
+        if removable {
+            ctx.fixer()
+                .cancel_if_has_comments(node)
+                .delete(node.trimmed_range());
+        } else {
+            // the default constructor passes every argument to the parent class, not only the parameters
+            err = err.suggestion(
+                node.trimmed_range(),
+                "remove the constructor",
+                "",
+                Applicability::MaybeIncorrect,
+            );
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Get the names of the parameters of a constructor, and whether each is a rest parameter.
+/// Returns `None` if any parameter has a default or is destructured.
+fn simple_params(constructor: &Constructor) -> Option<Vec<(String, bool)>> {
+    constructor
+        .syntax()
+        .child_with_kind(PARAMETER_LIST)?
+        .children()
+        .map(|param| match param.kind() {
+            SINGLE_PATTERN => Some((param_name(&param)?, false)),
+            REST_PATTERN => Some((param_name(&param.children().next()?)?, true)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Get the arguments of a `super` call and whether each is spread, `None` if any argument is not a name.
+fn super_args(args: &SyntaxNode) -> Option<Vec<(String, bool)>> {
+    args.children()
+        .map(|arg| match arg.kind() {
+            NAME_REF => Some((arg.text().to_string(), false)),
+            SPREAD_ELEMENT => {
+                let element = arg
+                    .children()
+                    .next()
+                    .filter(|elem| elem.kind() == NAME_REF)?;
+                Some((element.text().to_string(), true))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether a `super` call passes every argument of the constructor, which is the case for
+/// `super(...arguments)` and `constructor(...args) { super(...args); }`.
+fn forwards_all_arguments(constructor: &Constructor, args: &SyntaxNode) -> Option<bool> {
+    let args = super_args(args)?;
+    if args == [("arguments".to_string(), true)] {
+        return Some(true);
+    }
+    Some(args.len() == 1 && args[0].1 && simple_params(constructor)? == args)
+}
+
+/// Whether the arguments of a `super` call are exactly the parameters of a constructor, or `...arguments`.
+fn passes_arguments(constructor: &Constructor, args: &SyntaxNode) -> Option<bool> {
+    let args = super_args(args)?;
+    if args == [("arguments".to_string(), true)] {
+        return Some(true);
+    }
+    Some(simple_params(constructor)? == args)
+}
+
+fn param_name(pattern: &SyntaxNode) -> Option<String> {
+    if pattern.kind() != SINGLE_PATTERN {
+        return None;
+    }
+    Some(pattern.child_with_kind(NAME)?.text().to_string())
+}
+
+rule_tests! {
+    NoUselessConstructor::default(),
+    err: {
+        "class A { constructor() {} }",
+        "class A extends B { constructor() { super(); } }",
+        "class A extends B { constructor(foo) { super(foo); } }",
+        "class A extends B { constructor(foo, ...bar) { super(foo, ...bar); } }",
+        "class A extends B { constructor() { super(...arguments); } }",
+        "const A = class extends B { constructor(a, b) { super(a, b) } }",
+        "class A { constructor(foo = bar()) {} }",
+        "class A extends B { constructor(...args) { super(...args); } }"
+    },
+    ok: {
+        "class A { }",
+        "class A { constructor() { this.foo = 5; } }",
+        "class A extends B { constructor() {} }",
+        "class A extends B { constructor(foo) { super(foo, 5); } }",
+        "class A extends B { constructor(foo, bar) { super(bar, foo); } }",
+        "class A extends B { constructor(foo = 5) { super(foo); } }",
+        "class A extends B { constructor({ foo }) { super({ foo }); } }",
+        "class A extends B { constructor(...foo) { super(foo); } }",
+        "class A extends B { constructor() { super(); this.foo = 5; } }",
+        "class A extends B { constructor() { foo(); } }"
+    }
+}
@@ -0,0 +1,287 @@
+use crate::rule_prelude::*;
+use ast::VarDecl;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce variables to be declared either together or separately.
+
+    With `"always"` (the default), every scope may only have one declaration of each kind, there
+    may only be one `var` declaration per function, and one `let` and `const` declaration per block.
+    With `"never"`, every declaration may only declare a single variable.
+
+    The mode can be overridden for each kind of declaration with the `var`, `let`, and `const` options.
+    Declarations in the head of `for` loops are ignored in `"always"` mode.
+
+    ## Invalid Code Examples
+
+    ```js
+    // with "always"
+    function foo() {
+        var a = 1;
+        var b = 2;
+    }
+
+    // with "never"
+    let a = 1, b = 2;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    // with "always"
+    function foo() {
+        var a = 1,
+            b = 2;
+    }
+
+    // with "never"
+    let a = 1;
+    let b = 2;
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    OneVar,
+    style,
+    "one-var",
+    /// Whether declarations should be combined (`"always"`, the default) or separate (`"never"`).
+    pub mode: Mode,
+    /// The mode for `var` declarations, overriding `mode`.
+    pub var: Option<Mode>,
+    /// The mode for `let` declarations, overriding `mode`.
+    #[serde(rename = "let")]
+    pub let_: Option<Mode>,
+    /// The mode for `const` declarations, overriding `mode`.
+    #[serde(rename = "const")]
+    pub const_: Option<Mode>
+}
+
+/// Whether declarations should be combined or separate.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    #[default]
+    Always,
+    Never,
+}
+
+const FUNCTIONS: [SyntaxKind; 7] = [
+    FN_DECL,
+    FN_EXPR,
+    ARROW_EXPR,
+    METHOD,
+    GETTER,
+    SETTER,
+    CONSTRUCTOR,
+];
+
+#[typetag::serde]
+impl CstRule for OneVar {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let decl = node.try_to::<VarDecl>()?;
+        let in_for = node.parent()?.kind() == FOR_STMT_INIT;
+
+        match self.mode_for(&decl) {
+            Mode::Never => self.check_separate(&decl, in_for, ctx),
+            Mode::Always if !in_for => self.check_combined(&decl, ctx),
+            Mode::Always => None,
+        }
+    }
+}
+
+impl OneVar {
+    fn mode_for(&self, decl: &VarDecl) -> Mode {
+        let mode = if decl.is_var() {
+            self.var
+        } else if decl.is_let() {
+            self.let_
+        } else {
+            self.const_
+        };
+        mode.unwrap_or(self.mode)
+    }
+
+    fn check_separate(&self, decl: &VarDecl, in_for: bool, ctx: &mut RuleCtx) -> Option<()> {
+        let declarators = decl.declared().collect::<Vec<_>>();
+        if declarators.len() < 2 {
+            return None;
+        }
+        let keyword = decl.syntax().first_token()?;
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "split this `{}` declaration into one declaration per variable",
+                    keyword.text()
+                ),
+            )
+            .primary(decl.syntax().trimmed_range(), "");
+
+        if !in_for {
+            let fixer = ctx.fixer();
+            fixer.cancel_if_has_comments(decl.syntax());
+            for pair in declarators.windows(2) {
+                fixer.replace(
+                    pair[0].syntax().text_range().end()..pair[1].syntax().text_range().start(),
+                    format!("; {} ", keyword.text()),
+                );
+            }
+        }
+        ctx.add_err(err);
+        None
+    }
+
+    fn check_combined(&self, decl: &VarDecl, ctx: &mut RuleCtx) -> Option<()> {
+        let kind = decl.syntax().first_token()?;
+        let first = self.scope_declarations(decl).find(|other| {
+            other
+                .syntax()
+                .first_token()
+                .map(|tok| tok.text().to_string())
+                == Some(kind.text().to_string())
+        })?;
+        if first.syntax() == decl.syntax() {
+            return None;
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "combine this `{}` declaration with the previous one",
+                    kind.text()
+                ),
+            )
+            .primary(decl.syntax().trimmed_range(), "")
+            .secondary(
+                first.syntax().trimmed_range(),
+                "variables are first declared here",
+            );
+
+        // declarations can only be merged into a directly preceding declaration
+        let prev = decl
+            .syntax()
+            .prev_sibling()
+            .and_then(|prev| prev.try_to::<VarDecl>())
+            .filter(|prev| {
+                prev.syntax()
+                    .first_token()
+                    .map(|tok| tok.text().to_string())
+                    == Some(kind.text().to_string())
+            });
+        let ends = prev.and_then(|prev| {
+            let prev_last = prev.declared().last()?;
+            let first_declarator = decl.declared().next()?;
+            Some((prev, prev_last, first_declarator))
+        });
+        if let Some((prev, prev_last, first_declarator)) = ends {
+            ctx.fixer()
+                .cancel_if_has_comments(prev.syntax())
+                .cancel_if_has_comments(decl.syntax())
+                .replace(
+                    prev_last.syntax().text_range().end()
+                        ..first_declarator.syntax().text_range().start(),
+                    ", ",
+                );
+        }
+        ctx.add_err(err);
+        None
+    }
+
+    /// The declarations which share a scope with a declaration, in source order. That is the enclosing
+    /// function for `var` declarations, and the enclosing block for `let` and `const` declarations.
+    fn scope_declarations(&self, decl: &VarDecl) -> Box<dyn Iterator<Item = VarDecl>> {
+        let parent = match decl.syntax().parent() {
+            Some(parent) => parent,
+            None => return Box::new(std::iter::empty()),
+        };
+        if !decl.is_var() {
+            return Box::new(parent.children().filter_map(|child| child.try_to()));
+        }
+
+        let scope =
+            decl.syntax().ancestors().skip(1).find(|ancestor| {
+                FUNCTIONS.contains(&ancestor.kind()) || ancestor.parent().is_none()
+            });
+        let scope = match scope {
+            Some(scope) => scope,
+            None => return Box::new(std::iter::empty()),
+        };
+        Box::new(
+            scope
+                .descendants()
+                .filter(move |child| {
+                    child.kind() == VAR_DECL
+                        && child.parent().map(|parent| parent.kind()) != Some(FOR_STMT_INIT)
+                        && child
+                            .ancestors()
+                            .skip(1)
+                            .find(|ancestor| FUNCTIONS.contains(&ancestor.kind()))
+                            .filter(|function| function != &scope)
+                            .is_none()
+                })
+                .filter_map(|child| child.try_to()),
+        )
+    }
+}
+
+rule_tests! {
+    OneVar::default(),
+    err: {
+        "var a = 1; var b = 2;",
+        "function foo() { var a; if (b) { var c; } }",
+        "let a; let b;",
+        "const a = 1; foo(); const b = 2;",
+        "{ let a; let b; }"
+    },
+    ok: {
+        "var a = 1, b = 2;",
+        "let a; const b = 1;",
+        "let a; { let b; }",
+        "var a; function foo() { var b; }",
+        "var a; for (var i = 0; i < 1; i++) {}",
+        "for (let i = 0;;) {} for (let i = 0;;) {}"
+    }
+}
+
+rule_tests! {
+    never_valid,
+    never_invalid,
+    OneVar {
+        mode: Mode::Never,
+        ..OneVar::default()
+    },
+    err: {
+        "var a, b;",
+        "let a = 1, b = 2, c = 3;",
+        "for (var i = 0, j = 1;;) {}"
+    },
+    ok: {
+        "var a; var b;",
+        "let a = 1; let b = 2;",
+        "for (var i = 0;;) {}"
+    }
+}
+
+rule_tests! {
+    per_kind_valid,
+    per_kind_invalid,
+    OneVar {
+        mode: Mode::Never,
+        var: Some(Mode::Always),
+        ..OneVar::default()
+    },
+    err: {
+        "var a; var b;",
+        "const a = 1, b = 2;"
+    },
+    ok: {
+        "var a, b;",
+        "let a; let b;"
+    }
+}
@@ -0,0 +1,279 @@
+use crate::rule_prelude::*;
+use crate::util::{pattern_names, unwrap_name_ref};
+use ast::{AssignExpr, AssignOp, Pattern, UnaryExpr, UnaryOp, VarDecl};
+use std::collections::HashMap;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require `const` declarations for variables which are never reassigned after being declared.
+
+    If a variable declared with `let` is never reassigned, declaring it with `const` makes
+    it clear to the reader that the value does not change, and prevents accidentally reassigning it later.
+
+    Only `let` declarations are checked, `var` declarations are function scoped and hoisted, which
+    means changing them to `const` may change the meaning of the program.
+
+    ## Invalid Code Examples
+
+    ```js
+    let a = 5;
+    console.log(a);
+
+    let b;
+    b = 0;
+    console.log(b);
+
+    for (let item of items) {
+        console.log(item);
+    }
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = 5;
+
+    let b = 0;
+    b += 1;
+
+    for (let i = 0; i < 10; i++) {
+        console.log(i);
+    }
+
+    // `a` is reassigned so `b` can't be const either with `destructuring: "all"`
+    let { a, b } = obj;
+    a = 6;
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    PreferConst,
+    style,
+    "prefer-const",
+    /// Which variables of a destructuring pattern are reported, `"any"` (the default) or `"all"`.
+    pub destructuring: Destructuring,
+    /// Ignore variables which are declared without an initializer, and are read before they are
+    /// assigned, such as `let a; foo(a); a = 5;`.
+    pub ignore_read_before_assign: bool
+}
+
+/// Which variables of a destructuring pattern are reported.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Destructuring {
+    /// Report any variable in a destructuring pattern which is never reassigned.
+    #[default]
+    Any,
+    /// Only report the variables of a destructuring pattern if every variable in it is never reassigned.
+    All,
+}
+
+#[typetag::serde]
+impl CstRule for PreferConst {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let decl = node.try_to::<VarDecl>()?;
+        if !decl.is_let() {
+            return None;
+        }
+
+        let parent = node.parent()?;
+        let loop_head = parent.kind() == FOR_STMT_INIT
+            && matches!(parent.parent()?.kind(), FOR_IN_STMT | FOR_OF_STMT);
+        let scope = node.ancestors().skip(1).find(|n| {
+            !matches!(
+                n.kind(),
+                FOR_STMT_INIT | CASE_CLAUSE | DEFAULT_CLAUSE | EXPORT_DECL
+            )
+        })?;
+        let writes = collect_writes(&scope);
+
+        let mut fixable = true;
+        let mut unassigned = vec![];
+        for declarator in decl.declared() {
+            let mut names = vec![];
+            pattern_names(declarator.pattern()?, &mut names);
+
+            let initialized = declarator.value().is_some() || loop_head;
+            let consts = names
+                .iter()
+                .filter(|name| {
+                    let writes = writes
+                        .get(name.trimmed_text().to_string().as_str())
+                        .map(|w| w.as_slice())
+                        .unwrap_or_default();
+                    if initialized {
+                        writes.is_empty()
+                    } else {
+                        self.assigned_once(name, writes, &parent, &scope)
+                    }
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if !initialized || consts.len() != names.len() {
+                fixable = false;
+                if self.destructuring == Destructuring::All && names.len() > 1 {
+                    continue;
+                }
+            }
+            unassigned.extend(consts);
+        }
+
+        if unassigned.is_empty() {
+            return None;
+        }
+
+        // the fix is attached to the first diagnostic of the declaration
+        if fixable {
+            if let Some(let_token) = decl.let_token() {
+                ctx.fixer().replace(let_token, "const");
+            }
+        }
+        for name in unassigned {
+            let err = ctx
+                .err(
+                    self.name(),
+                    format!(
+                        "`{}` is never reassigned, use `const` instead",
+                        name.trimmed_text()
+                    ),
+                )
+                .primary(name.trimmed_range(), "this variable is never reassigned");
+            ctx.add_err(err);
+        }
+        None
+    }
+}
+
+impl PreferConst {
+    /// Whether an uninitialized variable is assigned exactly once with a plain `a = b` statement
+    /// directly after its declaration, such as `let a; a = 5;`.
+    fn assigned_once(
+        &self,
+        name: &SyntaxNode,
+        writes: &[SyntaxNode],
+        decl_parent: &SyntaxNode,
+        scope: &SyntaxNode,
+    ) -> bool {
+        let write = match writes {
+            [write] => write,
+            _ => return false,
+        };
+
+        let stmt = match write.parent() {
+            Some(stmt) if stmt.kind() == EXPR_STMT => stmt,
+            _ => return false,
+        };
+        if write.try_to::<AssignExpr>().and_then(|expr| expr.op()) != Some(AssignOp::Assign)
+            || stmt.parent().as_ref() != Some(decl_parent)
+        {
+            return false;
+        }
+
+        !self.ignore_read_before_assign
+            || !scope.descendants().any(|node| {
+                node.kind() == NAME_REF
+                    && node.text() == name.trimmed_text()
+                    && node.text_range().start() < write.text_range().start()
+            })
+    }
+}
+
+/// Collect every write to a variable inside of a node, mapped by the name of the variable.
+/// This does not account for shadowing, so a write to any variable with the same name counts.
+fn collect_writes(scope: &SyntaxNode) -> HashMap<String, Vec<SyntaxNode>> {
+    let mut writes: HashMap<String, Vec<SyntaxNode>> = HashMap::new();
+    for node in scope.descendants() {
+        let mut names = vec![];
+        match node.kind() {
+            ASSIGN_EXPR => match node.first_child() {
+                Some(lhs) if lhs.is::<Pattern>() => pattern_names(lhs.to(), &mut names),
+                Some(lhs) => names.extend(lhs.try_to().and_then(unwrap_name_ref)),
+                None => {}
+            },
+            UNARY_EXPR => {
+                let unary = node.to::<UnaryExpr>();
+                if matches!(
+                    unary.op_details().map(|(_, op)| op),
+                    Some(UnaryOp::Increment) | Some(UnaryOp::Decrement)
+                ) {
+                    names.extend(unary.expr().and_then(unwrap_name_ref));
+                }
+            }
+            FOR_IN_STMT | FOR_OF_STMT => {
+                let head = node.children().find(|n| n.kind() == FOR_STMT_INIT);
+                match head.and_then(|head| head.first_child()) {
+                    Some(lhs) if lhs.is::<Pattern>() => pattern_names(lhs.to(), &mut names),
+                    Some(lhs) => names.extend(lhs.try_to().and_then(unwrap_name_ref)),
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+
+        for name in names {
+            writes
+                .entry(name.trimmed_text().to_string())
+                .or_default()
+                .push(node.clone());
+        }
+    }
+    writes
+}
+
+rule_tests! {
+    PreferConst::default(),
+    err: {
+        "let a = 5; console.log(a);",
+        "let a; a = 5; console.log(a);",
+        "for (let item of items) { console.log(item); }",
+        "let { a, b } = obj; a = 6;",
+        "let [a, ...rest] = arr;",
+        "let a = 1, b = 2; b++;",
+        "export let a = 5;"
+    },
+    ok: {
+        "let a = 5; a = 6;",
+        "let a = 0; a += 1;",
+        "let a; if (foo) { a = 5; }",
+        "let a; a = 5; a = 6;",
+        "for (let i = 0; i < 10; i++) {}",
+        "let a = 1; function f() { a++; }",
+        "let { a } = obj; ({ a } = other);",
+        "let [a] = arr; [a] = other;",
+        "let a; for (a of items) {}",
+        "var a = 5;",
+        "const a = 5;"
+    }
+}
+
+rule_tests! {
+    prefer_const_destructuring_all_valid,
+    prefer_const_destructuring_all_invalid,
+    PreferConst { destructuring: Destructuring::All, ignore_read_before_assign: false },
+    err: {
+        "let { a, b } = obj;",
+        "let a; foo(a); a = 5;"
+    },
+    ok: {
+        "let { a, b } = obj; a = 6;",
+        "let [a, b] = arr; b = 6;"
+    }
+}
+
+rule_tests! {
+    prefer_const_ignore_read_before_assign_valid,
+    prefer_const_ignore_read_before_assign_invalid,
+    PreferConst { destructuring: Destructuring::Any, ignore_read_before_assign: true },
+    err: {
+        "let a; a = 5; foo(a);"
+    },
+    ok: {
+        "let a; foo(a); a = 5;",
+        "let a; function f() { return a; } a = 5;"
+    }
+}
@@ -0,0 +1,167 @@
+use crate::rule_prelude::*;
+use ast::{BinExpr, BinOp, CallExpr, Expr};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Prefer the `**` operator over `Math.pow`.
+
+    The exponentiation operator `**` was introduced in ES2016 and is equivalent to calling `Math.pow`,
+    however it is shorter and easier to read.
+
+    ## Invalid Code Examples
+
+    ```js
+    const a = Math.pow(2, 8);
+
+    const b = Math.pow(a + 1, b);
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = 2 ** 8;
+
+    const b = (a + 1) ** b;
+
+    const c = Math.max(a, b);
+    ```
+    */
+    #[derive(Default)]
+    PreferExponentiationOperator,
+    style,
+    "prefer-exponentiation-operator"
+}
+
+/// Parent kinds which bind tighter than `**`, therefore the replacement must be wrapped in parentheses.
+const TIGHTER_PARENTS: [SyntaxKind; 8] = [
+    DOT_EXPR,
+    BRACKET_EXPR,
+    CALL_EXPR,
+    NEW_EXPR,
+    UNARY_EXPR,
+    AWAIT_EXPR,
+    TEMPLATE,
+    TS_NON_NULL,
+];
+
+#[typetag::serde]
+impl CstRule for PreferExponentiationOperator {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        if !is_math_pow(&call.callee()?) {
+            return None;
+        }
+
+        let err = ctx
+            .err(self.name(), "use the `**` operator instead of `Math.pow`")
+            .primary(node.trimmed_range(), "");
+
+        let args = call.arguments()?.syntax().children().collect::<Vec<_>>();
+        if let [base, exponent] = args.as_slice() {
+            if !node.contains_comments() {
+                let replacement = format!(
+                    "{} ** {}",
+                    parenthesize(base, needs_parens_as_base(base)),
+                    parenthesize(exponent, needs_parens_as_exponent(exponent))
+                );
+                let wrap = node
+                    .parent()
+                    .map(|parent| {
+                        TIGHTER_PARENTS.contains(&parent.kind())
+                            || parent
+                                .try_to::<BinExpr>()
+                                .and_then(|bin| bin.op())
+                                .filter(|op| *op == BinOp::Exponent)
+                                .is_some()
+                    })
+                    .unwrap_or_default();
+                ctx.fixer()
+                    .replace(node.trimmed_range(), parenthesize_str(replacement, wrap));
+            }
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+fn is_math_pow(callee: &Expr) -> bool {
+    match callee {
+        Expr::DotExpr(dot) => {
+            dot.prop().map(|prop| prop.syntax().text() == "pow") == Some(true)
+                && matches!(dot.object(), Some(Expr::NameRef(obj)) if obj.syntax().text() == "Math")
+        }
+        _ => false,
+    }
+}
+
+/// Whether an expression binds at least as tight as an operand of a call, such as `a`, `a.b`, or `(a + b)`.
+fn is_primary(expr: &SyntaxNode) -> bool {
+    matches!(
+        expr.kind(),
+        LITERAL
+            | TEMPLATE
+            | NAME_REF
+            | THIS_EXPR
+            | ARRAY_EXPR
+            | GROUPING_EXPR
+            | BRACKET_EXPR
+            | DOT_EXPR
+            | NEW_EXPR
+            | CALL_EXPR
+            | NEW_TARGET
+            | IMPORT_META
+            | SUPER_CALL
+            | IMPORT_CALL
+            | PRIVATE_PROP_ACCESS
+            | TS_NON_NULL
+    )
+}
+
+/// The base of `**` cannot be a unary expression such as `-a`, or any expression with a lower precedence.
+fn needs_parens_as_base(expr: &SyntaxNode) -> bool {
+    !is_primary(expr)
+}
+
+/// The exponent of `**` can be a unary expression or another `**` expression, because `**` is right associative.
+fn needs_parens_as_exponent(expr: &SyntaxNode) -> bool {
+    let is_exponent = expr
+        .try_to::<BinExpr>()
+        .and_then(|bin| bin.op())
+        .filter(|op| *op == BinOp::Exponent)
+        .is_some();
+    !(is_primary(expr) || is_exponent || matches!(expr.kind(), UNARY_EXPR | AWAIT_EXPR))
+}
+
+fn parenthesize(expr: &SyntaxNode, parens: bool) -> String {
+    parenthesize_str(expr.trimmed_text().to_string(), parens)
+}
+
+fn parenthesize_str(text: String, parens: bool) -> String {
+    if parens {
+        format!("({})", text)
+    } else {
+        text
+    }
+}
+
+rule_tests! {
+    PreferExponentiationOperator::default(),
+    err: {
+        "Math.pow(a, b);",
+        "const a = Math.pow(2, 8);",
+        "Math.pow(a + 1, b * 2);",
+        "Math.pow(-a, b);",
+        "Math.pow(a, b).toString();",
+        "-Math.pow(a, b);",
+        "Math.pow(Math.pow(a, b), c);",
+        "Math.pow(a);"
+    },
+    ok: {
+        "Math.max(a, b);",
+        "a ** b;",
+        "Math.pow;",
+        "foo.pow(a, b);",
+        "Math.PI;"
+    }
+}
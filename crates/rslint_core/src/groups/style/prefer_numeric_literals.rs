@@ -0,0 +1,113 @@
+use crate::rule_prelude::*;
+use crate::util::is_parse_int;
+use ast::CallExpr;
+
+declare_lint! {
+    /**
+    Disallow `parseInt()` and `Number.parseInt()` in favor of binary, octal, and hexadecimal literals.
+
+    Calls to `parseInt` with a constant string and a radix of 2, 8, or 16 can be written as a
+    numeric literal with the `0b`, `0o`, or `0x` prefix instead, which is shorter and avoids a
+    function call.
+
+    ## Invalid Code Examples
+
+    ```js
+    parseInt("111110111", 2) === 503;
+
+    parseInt("767", 8) === 503;
+
+    Number.parseInt("1F7", 16) === 503;
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    0b111110111 === 503;
+
+    0o767 === 503;
+
+    0x1F7 === 503;
+
+    parseInt(foo, 2);
+
+    parseInt("1F7", 10);
+    ```
+    */
+    #[derive(Default)]
+    PreferNumericLiterals,
+    style,
+    "prefer-numeric-literals"
+}
+
+#[typetag::serde]
+impl CstRule for PreferNumericLiterals {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        if !is_parse_int(&call.callee()?) {
+            return None;
+        }
+        let args = call.arguments()?.syntax().children().collect::<Vec<_>>();
+        let (value, radix) = match args.as_slice() {
+            [value, radix] => (
+                value.try_to::<ast::Literal>()?,
+                radix.try_to::<ast::Literal>()?,
+            ),
+            _ => return None,
+        };
+        let radix = radix.as_number()?;
+        let (prefix, name) = match radix as u32 {
+            _ if radix.fract() != 0.0 => return None,
+            2 => ("0b", "binary"),
+            8 => ("0o", "octal"),
+            16 => ("0x", "hexadecimal"),
+            _ => return None,
+        };
+        let digits = value.inner_string_text()?.to_string();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix as u32)) {
+            return None;
+        }
+
+        let literal = format!("{}{}", prefix, digits);
+        let err = ctx
+            .err(
+                self.name(),
+                format!("use a {} literal instead of `parseInt`", name),
+            )
+            .primary(node.trimmed_range(), "")
+            .suggestion(
+                node.trimmed_range(),
+                "replace the call with the literal",
+                literal.clone(),
+                Applicability::MaybeIncorrect,
+            );
+
+        ctx.fixer().replace(node.trimmed_range(), literal);
+        ctx.add_err(err);
+        None
+    }
+}
+
+rule_tests! {
+    PreferNumericLiterals::default(),
+    err: {
+        "parseInt(\"111\", 2);",
+        "parseInt(\"767\", 8);",
+        "parseInt(\"FF\", 16);",
+        "Number.parseInt('1f7', 16);",
+        "Number.parseInt(\"111110111\", 2) === 503;"
+    },
+    ok: {
+        "parseInt(foo, 2);",
+        "parseInt(\"FF\", radix);",
+        "parseInt(\"111\", 10);",
+        "parseInt(\"12\", 2);",
+        "parseInt(\"-FF\", 16);",
+        "parseInt(\"\", 16);",
+        "parseInt(\"FF\");",
+        "parseInt(1, 2);",
+        "parseInt(\"11\", 2.5);",
+        "foo.parseInt(\"111\", 2);",
+        "0b111;"
+    }
+}
@@ -0,0 +1,146 @@
+use crate::rule_prelude::*;
+use ast::CallExpr;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Prefer object spread over calling `Object.assign` with an object literal as the first argument.
+
+    `Object.assign` with an object literal as the first argument copies the properties of the other
+    arguments into a new object. Object spread (`{ ...foo }`) does the same thing, but it is shorter,
+    and it makes it clear that a new object is made.
+
+    ## Invalid Code Examples
+
+    ```js
+    const a = Object.assign({}, foo);
+
+    const b = Object.assign({}, foo, bar);
+
+    const c = Object.assign({ foo: 5 }, bar);
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    const a = { ...foo };
+
+    const b = { ...foo, ...bar };
+
+    // modifies `target` instead of making a new object
+    Object.assign(target, foo);
+
+    // spread arguments cannot be converted to object spread
+    Object.assign({}, ...foo);
+    ```
+    */
+    #[derive(Default)]
+    PreferObjectSpread,
+    style,
+    "prefer-object-spread"
+}
+
+#[typetag::serde]
+impl CstRule for PreferObjectSpread {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let call = node.try_to::<CallExpr>()?;
+        if call.opt_chain_token().is_some()
+            || !call
+                .callee()?
+                .syntax()
+                .structural_lossy_token_eq(&["Object", ".", "assign"])
+        {
+            return None;
+        }
+        let args = call.arguments()?.syntax().children().collect::<Vec<_>>();
+        let target = args.first().filter(|arg| arg.kind() == OBJECT_EXPR)?;
+        if args.iter().any(|arg| arg.kind() == SPREAD_ELEMENT) {
+            return None;
+        }
+
+        if !node.contains_comments() {
+            let props = target
+                .children()
+                .map(|prop| prop.trimmed_text().to_string())
+                .chain(args.iter().skip(1).flat_map(spread_props))
+                .collect::<Vec<_>>();
+            let object = if props.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", props.join(", "))
+            };
+            let object = if starts_statement_or_arrow_body(node) {
+                format!("({})", object)
+            } else {
+                object
+            };
+            ctx.fixer().replace(node.trimmed_range(), object);
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                "use an object spread instead of `Object.assign`",
+            )
+            .primary(
+                node.trimmed_range(),
+                "this makes a new object with the properties of its arguments",
+            );
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Whether an expression is at the start of a statement or an arrow function body, where `{` would be parsed as a block.
+fn starts_statement_or_arrow_body(node: &SyntaxNode) -> bool {
+    let start = node.trimmed_range().start();
+    let mut outer = node.clone();
+    while let Some(parent) = outer.parent() {
+        match parent.kind() {
+            EXPR_STMT => return true,
+            ARROW_EXPR => return parent.children().last().as_ref() == Some(&outer),
+            _ if parent.trimmed_range().start() == start => outer = parent,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// The properties of an object spread which copies the properties of an argument.
+///
+/// Object literals are inlined, unless they have getters or setters, which `Object.assign` invokes instead of copying.
+fn spread_props(arg: &SyntaxNode) -> Vec<String> {
+    let inline = arg.kind() == OBJECT_EXPR
+        && !arg
+            .children()
+            .any(|prop| matches!(prop.kind(), GETTER | SETTER));
+    if inline {
+        arg.children()
+            .map(|prop| prop.trimmed_text().to_string())
+            .collect()
+    } else {
+        vec![format!("...{}", arg.trimmed_text())]
+    }
+}
+
+rule_tests! {
+    PreferObjectSpread::default(),
+    err: {
+        "Object.assign({}, x);",
+        "const a = Object.assign({}, foo, bar);",
+        "const a = Object.assign({ foo: 5 }, bar);",
+        "const a = Object.assign({});",
+        "const a = () => Object.assign({}, foo);",
+        "Object.assign({}, foo).bar;",
+        "Object.assign({}, { get foo() {} });"
+    },
+    ok: {
+        "Object.assign(target, x);",
+        "Object.assign(foo.bar, x);",
+        "Object.assign({}, ...foo);",
+        "Object.assign?.({}, foo);",
+        "const a = { ...foo };",
+        "Object.keys({}, foo);",
+        "Object.assign();"
+    }
+}
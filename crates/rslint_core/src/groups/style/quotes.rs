@@ -0,0 +1,313 @@
+use crate::rule_prelude::*;
+use ast::Literal;
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Enforce a consistent quote style for strings.
+
+    Strings can be written with double quotes, single quotes, or backticks (template literals).
+    Using the same style everywhere makes code easier to read. This rule enforces one of `"double"`
+    (default), `"single"`, or `"backtick"` quotes with the `style` option.
+
+    With `avoidEscape` enabled, a string may use other quotes if it contains the preferred quote,
+    such as `'a "quoted" word'` when double quotes are preferred. With `allowTemplateLiterals`
+    enabled, template literals are allowed even if `style` is not `"backtick"`.
+
+    Template literals with substitutions, tagged template literals, and template literals with line breaks
+    are always allowed. When `style` is `"backtick"`, directives, module names, and property keys are allowed
+    to use other quotes, since template literals are not allowed there.
+
+    ## Invalid Code Examples
+
+    ```js
+    var a = 'single';
+    var b = `backtick`;
+    ```
+
+    ```js
+    // with `style` set to `"single"`
+    var a = "double";
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    var a = "double";
+    var b = `template ${literal}`;
+    var c = tag`tagged`;
+    ```
+
+    ```js
+    // with `avoidEscape` enabled
+    var a = 'a string with "double" quotes';
+    ```
+    */
+    #[serde(default)]
+    Quotes,
+    style,
+    "quotes",
+    /// The quotes to use for strings, `"double"` by default.
+    pub style: QuoteStyle,
+    /// Allow strings to use other quotes if they contain the preferred quote (false by default).
+    pub avoid_escape: bool,
+    /// Allow template literals when `style` is not `"backtick"` (false by default).
+    pub allow_template_literals: bool
+}
+
+impl Default for Quotes {
+    fn default() -> Self {
+        Self {
+            style: QuoteStyle::Double,
+            avoid_escape: false,
+            allow_template_literals: false,
+        }
+    }
+}
+
+/// The quotes used for a string.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyle {
+    Double,
+    Single,
+    Backtick,
+}
+
+impl QuoteStyle {
+    fn quote(self) -> char {
+        match self {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Single => '\'',
+            QuoteStyle::Backtick => '`',
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            QuoteStyle::Double => "double quotes",
+            QuoteStyle::Single => "single quotes",
+            QuoteStyle::Backtick => "backticks",
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for Quotes {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let text = node.trimmed_text().to_string();
+        match node.kind() {
+            LITERAL if node.to::<Literal>().is_string() => {
+                if self.style == QuoteStyle::Backtick && !can_be_template(node) {
+                    return None;
+                }
+            }
+            TEMPLATE => {
+                let convertible = node.first_token()?.kind() == BACKTICK
+                    && node.children().next().is_none()
+                    && !text.contains(&['\n', '\r'][..]);
+                if self.allow_template_literals || !convertible {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+
+        let quote = self.style.quote();
+        let old = text.chars().next()?;
+        let inner = text.get(1..text.len() - 1)?;
+        if old == quote || (self.avoid_escape && inner.contains(quote)) {
+            return None;
+        }
+
+        if quote != '`' || !has_octal_escape(inner) {
+            ctx.fixer().replace(
+                node.trimmed_range(),
+                format!("{}{}{}", quote, switch_quotes(inner, old, quote), quote),
+            );
+        }
+
+        let err = ctx
+            .err(
+                self.name(),
+                format!("strings must use {}", self.style.description()),
+            )
+            .primary(node.trimmed_range(), "");
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Change the quotes of the inner text of a string, unescaping the old quotes and escaping the new quotes.
+fn switch_quotes(inner: &str, old: char, new: char) -> String {
+    let mut res = String::with_capacity(inner.len());
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if escaped == old => res.push(escaped),
+                Some('$') if old == '`' && chars.peek() == Some(&'{') => res.push('$'),
+                Some(escaped) => {
+                    res.push('\\');
+                    res.push(escaped);
+                }
+                None => res.push('\\'),
+            },
+            _ if c == new => {
+                res.push('\\');
+                res.push(c);
+            }
+            '$' if new == '`' && chars.peek() == Some(&'{') => res.push_str("\\$"),
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+/// Whether a string has an octal escape such as `\1`, which is not allowed in template literals.
+fn has_octal_escape(inner: &str) -> bool {
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('1'..='9') => return true,
+                Some('0') if chars.peek().filter(|c| c.is_ascii_digit()).is_some() => return true,
+                _ => {}
+            }
+        }
+    }
+    false
+}
+
+/// Whether a string literal can be replaced with a template literal, which is not the case for
+/// directives, module names, and property keys.
+fn can_be_template(node: &SyntaxNode) -> bool {
+    let parent = match node.parent() {
+        Some(parent) => parent,
+        None => return true,
+    };
+    match parent.kind() {
+        IMPORT_DECL
+        | EXPORT_WILDCARD
+        | EXPORT_NAMED
+        | EXPORT_DECL
+        | SPECIFIER
+        | TS_MODULE_DECL
+        | TS_EXTERNAL_MODULE_REF
+        | TS_IMPORT => false,
+        LITERAL_PROP
+        | METHOD
+        | GETTER
+        | SETTER
+        | CLASS_PROP
+        | TS_ENUM_MEMBER
+        | TS_PROPERTY_SIGNATURE
+        | TS_METHOD_SIGNATURE => parent.children().next().as_ref() != Some(node),
+        EXPR_STMT => !is_directive(&parent),
+        _ => true,
+    }
+}
+
+/// Whether an expression statement is a directive, such as `"use strict"`.
+fn is_directive(stmt: &SyntaxNode) -> bool {
+    let in_prologue = stmt
+        .parent()
+        .filter(|parent| match parent.kind() {
+            SCRIPT | MODULE => true,
+            BLOCK_STMT => parent
+                .parent()
+                .filter(|func| {
+                    matches!(
+                        func.kind(),
+                        FN_DECL | FN_EXPR | ARROW_EXPR | METHOD | GETTER | SETTER | CONSTRUCTOR
+                    )
+                })
+                .is_some(),
+            _ => false,
+        })
+        .is_some();
+
+    in_prologue
+        && std::iter::successors(Some(stmt.clone()), |stmt| stmt.prev_sibling()).all(|stmt| {
+            stmt.kind() == EXPR_STMT
+                && stmt
+                    .first_child()
+                    .and_then(|expr| expr.try_to::<Literal>())
+                    .filter(|literal| literal.is_string())
+                    .is_some()
+        })
+}
+
+rule_tests! {
+    Quotes::default(),
+    err: {
+        "var a = 'foo';",
+        "var a = `foo`;",
+        "var a = { b: 'c' };",
+        "foo('bar', \"baz\");"
+    },
+    ok: {
+        "var a = \"foo\";",
+        "var a = `foo ${bar}`;",
+        "var a = tag`foo`;",
+        "var a = `foo\nbar`;",
+        "var a = \"it's\";"
+    }
+}
+
+rule_tests! {
+    single_valid,
+    single_invalid,
+    Quotes {
+        style: QuoteStyle::Single,
+        ..Quotes::default()
+    },
+    err: {
+        "var a = \"foo\";",
+        "var a = \"a 'quoted' word\";",
+        "var a = `foo`;"
+    },
+    ok: {
+        "var a = 'foo';",
+        "var a = 'a \"quoted\" word';"
+    }
+}
+
+rule_tests! {
+    avoid_escape_valid,
+    avoid_escape_invalid,
+    Quotes {
+        style: QuoteStyle::Single,
+        avoid_escape: true,
+        allow_template_literals: true
+    },
+    err: {
+        "var a = \"foo\";"
+    },
+    ok: {
+        "var a = \"a 'quoted' word\";",
+        "var a = `foo`;"
+    }
+}
+
+rule_tests! {
+    backtick_valid,
+    backtick_invalid,
+    Quotes {
+        style: QuoteStyle::Backtick,
+        ..Quotes::default()
+    },
+    err: {
+        "var a = 'foo';",
+        "var a = \"foo\";",
+        "var a = { b: 'c' };"
+    },
+    ok: {
+        "'use strict'; var a = `foo`;",
+        "import a from 'foo';",
+        "export * from 'foo';",
+        "var a = { 'b': c };",
+        "class A { 'b'() {} }"
+    }
+}
@@ -0,0 +1,325 @@
+use crate::rule_prelude::*;
+use ast::{ImportClause, ImportDecl};
+use SyntaxKind::*;
+
+declare_lint! {
+    /**
+    Require import declarations and the names imported by them to be sorted.
+
+    Sorting imports makes it easier to find a specific import, and avoids merge conflicts when imports
+    are added. Import declarations are sorted by their member syntax (`memberSyntaxSortOrder`),
+    then by the first name they import. The names inside of `{}` are sorted alphabetically.
+
+    The member syntax of an import declaration is one of:
+
+    - `none`: imports nothing, such as `import "foo"`
+    - `all`: imports the whole module, such as `import * as foo from "foo"`
+    - `multiple`: imports multiple names, such as `import { a, b } from "foo"`
+    - `single`: imports a single name, such as `import foo from "foo"` or `import { foo } from "foo"`
+
+    Only the order of names inside of `{}` is fixed automatically.
+
+    ## Invalid Code Examples
+
+    ```js
+    import b from "foo";
+    import a from "bar";
+    ```
+
+    ```js
+    import a from "foo";
+    import { b, c } from "bar";
+    ```
+
+    ```js
+    import { c, a, b } from "foo";
+    ```
+
+    ## Correct Code Examples
+
+    ```js
+    import "polyfill";
+    import * as utils from "utils";
+    import { a, b } from "foo";
+    import c from "bar";
+    import d from "baz";
+    ```
+
+    ```js
+    // uppercase letters are sorted before lowercase letters unless `ignoreCase` is enabled
+    import { B, a, c } from "foo";
+    ```
+    */
+    #[serde(default)]
+    SortImports,
+    style,
+    "sort-imports",
+    /// Ignore the case of names when sorting them (false by default).
+    pub ignore_case: bool,
+    /// Do not check the order of import declarations (false by default).
+    pub ignore_declaration_sort: bool,
+    /// Do not check the order of names inside of `{}` (false by default).
+    pub ignore_member_sort: bool,
+    /// The order of member syntax kinds, `["none", "all", "multiple", "single"]` by default.
+    pub member_syntax_sort_order: Vec<MemberSyntax>
+}
+
+impl Default for SortImports {
+    fn default() -> Self {
+        Self {
+            ignore_case: false,
+            ignore_declaration_sort: false,
+            ignore_member_sort: false,
+            member_syntax_sort_order: vec![
+                MemberSyntax::None,
+                MemberSyntax::All,
+                MemberSyntax::Multiple,
+                MemberSyntax::Single,
+            ],
+        }
+    }
+}
+
+/// The kind of names imported by an import declaration.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MemberSyntax {
+    None,
+    All,
+    Multiple,
+    Single,
+}
+
+impl MemberSyntax {
+    fn of(import: &ImportDecl) -> Self {
+        let names = local_names(import);
+        if import
+            .imports()
+            .any(|clause| matches!(clause, ImportClause::WildcardImport(_)))
+        {
+            MemberSyntax::All
+        } else if names.is_empty() {
+            MemberSyntax::None
+        } else if names.len() > 1 {
+            MemberSyntax::Multiple
+        } else {
+            MemberSyntax::Single
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            MemberSyntax::None => "none",
+            MemberSyntax::All => "all",
+            MemberSyntax::Multiple => "multiple",
+            MemberSyntax::Single => "single",
+        }
+    }
+}
+
+#[typetag::serde]
+impl CstRule for SortImports {
+    fn check_root(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let imports = node
+            .children()
+            .filter_map(|child| child.try_to::<ImportDecl>())
+            .collect::<Vec<_>>();
+
+        if !self.ignore_declaration_sort {
+            for pair in imports.windows(2) {
+                self.check_declarations(&pair[0], &pair[1], ctx);
+            }
+        }
+        if !self.ignore_member_sort {
+            for import in &imports {
+                self.check_members(import, ctx);
+            }
+        }
+        None
+    }
+}
+
+impl SortImports {
+    fn check_declarations(&self, prev: &ImportDecl, cur: &ImportDecl, ctx: &mut RuleCtx) {
+        let (prev_syntax, cur_syntax) = (MemberSyntax::of(prev), MemberSyntax::of(cur));
+        let err = match self.syntax_index(cur_syntax).cmp(&self.syntax_index(prev_syntax)) {
+            std::cmp::Ordering::Less => ctx
+                .err(
+                    self.name(),
+                    format!(
+                        "imports with `{}` member syntax should come before imports with `{}` member syntax",
+                        cur_syntax.name(),
+                        prev_syntax.name()
+                    ),
+                )
+                .primary(cur.syntax().trimmed_range(), "")
+                .secondary(
+                    prev.syntax().trimmed_range(),
+                    format!("this import has `{}` member syntax", prev_syntax.name()),
+                ),
+            std::cmp::Ordering::Equal => {
+                let first_name = |import: &ImportDecl| {
+                    local_names(import)
+                        .first()
+                        .map(|name| self.sort_key(&name.text().to_string()))
+                };
+                match (first_name(prev), first_name(cur)) {
+                    (Some(prev_name), Some(cur_name)) if cur_name < prev_name => ctx
+                        .err(self.name(), "imports should be sorted alphabetically")
+                        .primary(cur.syntax().trimmed_range(), "")
+                        .secondary(
+                            prev.syntax().trimmed_range(),
+                            "this import should come after the next one",
+                        ),
+                    _ => return,
+                }
+            }
+            std::cmp::Ordering::Greater => return,
+        };
+        ctx.add_err(err);
+    }
+
+    fn check_members(&self, import: &ImportDecl, ctx: &mut RuleCtx) -> Option<()> {
+        let named = import.imports().find_map(|clause| match clause {
+            ImportClause::NamedImports(named) => Some(named),
+            _ => None,
+        })?;
+        let specifiers = named.specifiers().collect::<Vec<_>>();
+        let keys = specifiers
+            .iter()
+            .map(|specifier| Some(self.sort_key(&local_name(specifier)?.text().to_string())))
+            .collect::<Option<Vec<_>>>()?;
+        let unsorted = (1..keys.len()).find(|idx| keys[*idx] < keys[idx - 1])?;
+
+        if !named.syntax().contains_comments() {
+            let mut sorted = specifiers.iter().zip(&keys).collect::<Vec<_>>();
+            sorted.sort_by_key(|(_, key)| *key);
+            let fixer = ctx.fixer();
+            for (specifier, (replacement, _)) in specifiers.iter().zip(sorted) {
+                fixer.replace(
+                    specifier.syntax().trimmed_range(),
+                    replacement.syntax().trimmed_text().to_string(),
+                );
+            }
+        }
+
+        let name = local_name(&specifiers[unsorted])?;
+        let err = ctx
+            .err(
+                self.name(),
+                format!(
+                    "member `{}` of the import should be sorted alphabetically",
+                    name.text()
+                ),
+            )
+            .primary(specifiers[unsorted].syntax().trimmed_range(), "");
+        ctx.add_err(err);
+        None
+    }
+
+    fn syntax_index(&self, syntax: MemberSyntax) -> usize {
+        self.member_syntax_sort_order
+            .iter()
+            .position(|other| *other == syntax)
+            .unwrap_or(self.member_syntax_sort_order.len())
+    }
+
+    fn sort_key(&self, name: &str) -> String {
+        if self.ignore_case {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+/// The local name of an import specifier, `b` in `{ a as b }` and `a` in `{ a }`.
+fn local_name(specifier: &ast::Specifier) -> Option<SyntaxNode> {
+    specifier
+        .alias()
+        .map(|alias| alias.syntax().clone())
+        .or_else(|| specifier.name())
+}
+
+/// The local names of the bindings made by an import declaration, in order.
+fn local_names(import: &ImportDecl) -> Vec<SyntaxNode> {
+    let mut names = vec![];
+    for clause in import.imports() {
+        match clause {
+            ImportClause::Name(name) => names.push(name.syntax().clone()),
+            ImportClause::WildcardImport(wildcard) => {
+                names.extend(wildcard.syntax().child_with_kind(NAME))
+            }
+            ImportClause::NamedImports(named) => {
+                names.extend(named.specifiers().filter_map(|spec| local_name(&spec)))
+            }
+            ImportClause::ImportStringSpecifier(_) => {}
+        }
+    }
+    names
+}
+
+rule_tests! {
+    SortImports::default(),
+    err: {
+        "import b from 'foo';\nimport a from 'bar';",
+        "import a from 'foo';\nimport { b, c } from 'bar';",
+        "import * as a from 'foo';\nimport 'bar';",
+        "import a from 'foo';\nimport B from 'bar';",
+        "import { b, a } from 'foo';",
+        "import { c as b, a } from 'foo';",
+        "import d, { c, b } from 'foo';"
+    },
+    ok: {
+        "import a from 'foo';\nimport b from 'bar';",
+        "import 'foo';\nimport * as b from 'b';\nimport { a, c } from 'c';\nimport d from 'd';",
+        "import { a, b as c, d } from 'foo';",
+        "import B from 'foo';\nimport a from 'bar';",
+        "import a, { b, c } from 'foo';",
+        "import { B, a, c } from 'foo';",
+        "import 'foo';\nimport 'bar';",
+        "import a from 'foo';\nfoo();\nimport b from 'bar';"
+    }
+}
+
+rule_tests! {
+    options_valid,
+    options_invalid,
+    SortImports {
+        ignore_case: true,
+        ignore_declaration_sort: false,
+        ignore_member_sort: false,
+        member_syntax_sort_order: vec![
+            MemberSyntax::Single,
+            MemberSyntax::Multiple,
+            MemberSyntax::All,
+            MemberSyntax::None
+        ]
+    },
+    err: {
+        "import * as a from 'foo';\nimport b from 'bar';",
+        "import { b, A } from 'foo';",
+        "import B from 'foo';\nimport a from 'bar';"
+    },
+    ok: {
+        "import b from 'foo';\nimport * as a from 'bar';",
+        "import { A, b } from 'foo';",
+        "import a from 'foo';\nimport B from 'bar';"
+    }
+}
+
+rule_tests! {
+    ignore_valid,
+    ignore_invalid,
+    SortImports {
+        ignore_declaration_sort: true,
+        ignore_member_sort: true,
+        ..SortImports::default()
+    },
+    err: {},
+    ok: {
+        "import b from 'foo';\nimport a from 'bar';",
+        "import { b, a } from 'foo';"
+    }
+}
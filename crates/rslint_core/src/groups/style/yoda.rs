@@ -0,0 +1,257 @@
+use crate::rule_prelude::*;
+use ast::{BinExpr, BinOp, Expr, UnaryOp};
+
+declare_lint! {
+    /**
+    Require or disallow "Yoda" conditions.
+
+    Yoda conditions are comparisons where the literal value comes first and the variable
+    comes second, such as `if (42 === x)`. They read backwards compared to how the condition
+    would be spoken out loud ("if x is 42"), which makes them harder to understand.
+
+    ## Never
+
+    ### Incorrect Code Examples
+
+    ```js
+    if ("red" === color) {
+        /* */
+    }
+
+    if (5 < value) {
+        /* */
+    }
+    ```
+
+    ### Correct Code Examples
+
+    ```js
+    if (color === "red") {
+        /* */
+    }
+
+    if (0 <= x && x < 10) {
+        /* range tests are allowed with `exceptRange` */
+    }
+    ```
+
+    ## Always
+
+    ### Incorrect Code Examples
+
+    ```js
+    if (color === "red") {
+        /* */
+    }
+    ```
+
+    ### Correct Code Examples
+
+    ```js
+    if ("red" === color) {
+        /* */
+    }
+    ```
+    */
+    #[derive(Default)]
+    #[serde(default)]
+    Yoda,
+    style,
+    "yoda",
+    /// Either `"never"` (the default) to disallow literals on the left side of comparisons, or
+    /// `"always"` to require literals to be on the left side of comparisons.
+    pub style: Style,
+    /// Allow yoda conditions in range tests such as `0 <= x && x < 10`.
+    pub except_range: bool
+}
+
+/// Which side of comparisons literals should be on.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Style {
+    #[default]
+    Never,
+    Always,
+}
+
+#[typetag::serde]
+impl CstRule for Yoda {
+    fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+        let expr = node.try_to::<BinExpr>()?;
+        if !expr.comparison() {
+            return None;
+        }
+        let (lhs, rhs) = (expr.lhs()?, expr.rhs()?);
+        let never = self.style == Style::Never;
+
+        let violation = if never {
+            is_literalish(&lhs) && !is_literalish(&rhs)
+        } else {
+            is_literalish(&rhs) && !is_literalish(&lhs)
+        };
+        if !violation || (self.except_range && is_range_test(&expr)) {
+            return None;
+        }
+
+        let op_token = expr.op_token()?;
+        let err = ctx
+            .err(
+                self.name(),
+                if never {
+                    "expected the literal to be on the right side of the comparison"
+                } else {
+                    "expected the literal to be on the left side of the comparison"
+                },
+            )
+            .primary(
+                node.trimmed_range(),
+                format!(
+                    "`{}` is on the {} side of `{}`",
+                    if never {
+                        lhs.syntax().trimmed_text()
+                    } else {
+                        rhs.syntax().trimmed_text()
+                    },
+                    if never { "left" } else { "right" },
+                    op_token.text()
+                ),
+            );
+
+        if let Some(op) = expr.op() {
+            ctx.fixer()
+                .replace(lhs.syntax().trimmed_range(), rhs.syntax().trimmed_text())
+                .replace(op_token, flipped_op(op))
+                .replace(rhs.syntax().trimmed_range(), lhs.syntax().trimmed_text());
+        }
+        ctx.add_err(err);
+        None
+    }
+}
+
+/// Whether an expression is used like a literal, this includes things like `-5` and `` `foo` ``.
+fn is_literalish(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(_) => true,
+        Expr::Template(tpl) => tpl.elements().next().is_none(),
+        Expr::UnaryExpr(unary) => {
+            matches!(unary.op(), Some(UnaryOp::Minus) | Some(UnaryOp::Plus))
+                && matches!(unary.expr(), Some(Expr::Literal(lit)) if lit.is_number())
+        }
+        _ => false,
+    }
+}
+
+/// The operator to use when swapping the sides of a comparison.
+fn flipped_op(op: BinOp) -> &'static str {
+    match op {
+        BinOp::LessThan => ">",
+        BinOp::GreaterThan => "<",
+        BinOp::LessThanOrEqual => ">=",
+        BinOp::GreaterThanOrEqual => "<=",
+        BinOp::Equality => "==",
+        BinOp::StrictEquality => "===",
+        BinOp::Inequality => "!=",
+        BinOp::StrictInequality => "!==",
+        _ => unreachable!("flipped_op called with a non comparison operator"),
+    }
+}
+
+/// Whether the comparison is one half of a range test such as `0 <= x && x < 10` or `x < 0 || 10 <= x`.
+fn is_range_test(expr: &BinExpr) -> bool {
+    let parent = match expr
+        .syntax()
+        .expr_parent()
+        .and_then(|p| p.try_to::<BinExpr>())
+    {
+        Some(parent) => parent,
+        None => return false,
+    };
+
+    let side = |expr: Option<Expr>| -> Option<(Expr, Expr)> {
+        let bin = match expr? {
+            Expr::BinExpr(bin) => bin,
+            Expr::GroupingExpr(group) => match group.inner()? {
+                Expr::BinExpr(bin) => bin,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if matches!(bin.op()?, BinOp::LessThan | BinOp::LessThanOrEqual) {
+            Some((bin.lhs()?, bin.rhs()?))
+        } else {
+            None
+        }
+    };
+
+    let ((left_lhs, left_rhs), (right_lhs, right_rhs)) =
+        match (side(parent.lhs()), side(parent.rhs())) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return false,
+        };
+
+    match parent.op() {
+        // `a <= x && x < b`
+        Some(BinOp::LogicalAnd) => {
+            left_rhs.syntax().lexical_eq(right_lhs.syntax())
+                && is_literalish(&left_lhs)
+                && is_literalish(&right_rhs)
+        }
+        // `x < a || b < x`
+        Some(BinOp::LogicalOr) => {
+            left_lhs.syntax().lexical_eq(right_rhs.syntax())
+                && is_literalish(&left_rhs)
+                && is_literalish(&right_lhs)
+        }
+        _ => false,
+    }
+}
+
+rule_tests! {
+    Yoda::default(),
+    err: {
+        "if (1 === x) {}",
+        "if ('red' == color) {}",
+        "if (-1 < x) {}",
+        "if (`foo` !== bar) {}",
+        "if (0 <= x && x < 10) {}"
+    },
+    ok: {
+        "if (x === 1) {}",
+        "if (color == 'red') {}",
+        "if (1 === 2) {}",
+        "if (x < y) {}",
+        "if (`${foo}` === bar) {}"
+    }
+}
+
+rule_tests! {
+    yoda_always_valid,
+    yoda_always_invalid,
+    Yoda { style: Style::Always, except_range: false },
+    err: {
+        "if (x === 1) {}",
+        "if (color == 'red') {}"
+    },
+    ok: {
+        "if (1 === x) {}",
+        "if (x === y) {}"
+    }
+}
+
+rule_tests! {
+    yoda_except_range_valid,
+    yoda_except_range_invalid,
+    Yoda { style: Style::Never, except_range: true },
+    err: {
+        "if (0 <= x && y < 10) {}",
+        "if (5 === x && x < 10) {}"
+    },
+    ok: {
+        "if (0 <= x && x < 10) {}",
+        "if (x < 0 || 10 <= x) {}",
+        "if ((0 < x) && (x <= 10)) {}"
+    }
+}
@@ -0,0 +1,181 @@
+//! Matching of file paths against gitignore-style patterns.
+
+use std::path::{Component, Path};
+
+/// A set of gitignore-style patterns used for skipping files such as `node_modules` or `dist`.
+///
+/// The patterns support:
+/// - `*` to match anything except `/`, `**` to match anything including `/`, and `?` to match a single character.
+/// - A leading `!` to negate a pattern, re-including paths ignored by a previous pattern.
+/// - A trailing `/` to only match directories.
+/// - Patterns containing a `/` (other than at the end) match relative to the root, other patterns
+///   match a file or directory name at any depth.
+/// - Empty lines and lines starting with `#` are ignored.
+///
+/// The last pattern which matches a path decides if it is ignored. A pattern matching a directory
+/// ignores everything inside the directory.
+///
+/// ```
+/// use rslint_core::IgnoreSet;
+///
+/// let set = IgnoreSet::from_patterns(&["node_modules/", "dist/*.js", "!dist/keep.js"]);
+/// assert!(set.is_ignored("node_modules/foo/index.js"));
+/// assert!(set.is_ignored("dist/bundle.js"));
+/// assert!(!set.is_ignored("dist/keep.js"));
+/// assert!(!set.is_ignored("src/index.js"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreSet {
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnorePattern {
+    glob: String,
+    negated: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+impl IgnoreSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make a new set from a list of patterns, such as the lines of an ignore file.
+    pub fn from_patterns<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let mut set = Self::new();
+        for pattern in patterns {
+            set.add(pattern.as_ref());
+        }
+        set
+    }
+
+    /// Add a single pattern to the set, empty patterns and comments are ignored.
+    pub fn add(&mut self, pattern: &str) {
+        let mut pattern = pattern.trim();
+        if pattern.is_empty() || pattern.starts_with('#') {
+            return;
+        }
+
+        let negated = pattern.starts_with('!');
+        if negated {
+            pattern = &pattern[1..];
+        }
+        let dir_only = pattern.ends_with('/');
+        let pattern = pattern.trim_end_matches('/');
+        let anchored = pattern.contains('/');
+
+        self.patterns.push(IgnorePattern {
+            glob: pattern.trim_start_matches('/').to_string(),
+            negated,
+            dir_only,
+            anchored,
+        });
+    }
+
+    /// Whether there are no patterns in this set.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Check if a path is ignored by the patterns in this set.
+    ///
+    /// Paths are matched as given, therefore they should be relative to the directory the patterns were written for.
+    pub fn is_ignored(&self, path: impl AsRef<Path>) -> bool {
+        let components = path
+            .as_ref()
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut ignored = false;
+        for pattern in &self.patterns {
+            if pattern.negated == ignored && pattern.matches(&components) {
+                ignored = !pattern.negated;
+            }
+        }
+        ignored
+    }
+}
+
+impl IgnorePattern {
+    fn matches(&self, components: &[String]) -> bool {
+        // check every directory leading up to the path and the path itself
+        (1..=components.len()).any(|len| {
+            let is_dir = len != components.len();
+            if self.dir_only && !is_dir {
+                return false;
+            }
+
+            if self.anchored {
+                glob_match(self.glob.as_bytes(), components[..len].join("/").as_bytes())
+            } else {
+                glob_match(self.glob.as_bytes(), components[len - 1].as_bytes())
+            }
+        })
+    }
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            let rest = match rest {
+                [b'/', rest @ ..] => rest,
+                rest => rest,
+            };
+            (0..=text.len()).any(|idx| glob_match(rest, &text[idx..]))
+        }
+        [b'*', rest @ ..] => {
+            let segment_end = text.iter().position(|c| *c == b'/').unwrap_or(text.len());
+            (0..=segment_end).any(|idx| glob_match(rest, &text[idx..]))
+        }
+        [b'?', rest @ ..] => match text {
+            [c, text @ ..] if *c != b'/' => glob_match(rest, text),
+            _ => false,
+        },
+        [c, rest @ ..] => match text {
+            [t, text @ ..] if t == c => glob_match(rest, text),
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_set_matching() {
+        let set = IgnoreSet::from_patterns(&[
+            "# comment",
+            "",
+            "node_modules",
+            "build/",
+            "/dist/**/*.min.js",
+            "*.log",
+            "!important.log",
+            "src/?.js",
+        ]);
+
+        assert!(set.is_ignored("node_modules"));
+        assert!(set.is_ignored("node_modules/a/b.js"));
+        assert!(set.is_ignored("packages/foo/node_modules/a.js"));
+        assert!(set.is_ignored("./build/index.js"));
+        assert!(!set.is_ignored("build"));
+        assert!(set.is_ignored("dist/a.min.js"));
+        assert!(set.is_ignored("dist/a/b/c.min.js"));
+        assert!(!set.is_ignored("dist/a.js"));
+        assert!(!set.is_ignored("lib/dist/a.min.js"));
+        assert!(set.is_ignored("logs/debug.log"));
+        assert!(!set.is_ignored("logs/important.log"));
+        assert!(set.is_ignored("src/a.js"));
+        assert!(!set.is_ignored("src/ab.js"));
+        assert!(!set.is_ignored("src/index.js"));
+        assert!(!set.is_ignored("# comment"));
+    }
+}
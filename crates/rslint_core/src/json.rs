@@ -0,0 +1,210 @@
+//! Machine readable JSON output of lint results.
+//!
+//! Diagnostics come from `rslint_errors` and are not serializable, therefore they are converted to
+//! small mirror structs which only contain the parts useful for other tools.
+
+use crate::{Diagnostic, LintResult, Outcome, Severity};
+use rslint_errors::SubDiagnostic;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResult {
+    file_id: usize,
+    outcome: &'static str,
+    counts: SeverityCounts,
+    diagnostics: Vec<JsonDiagnostic>,
+}
+
+/// The names used for each severity in exported diagnostics.
+///
+/// Different tools expect different severity vocabularies, the default names follow the SARIF
+/// conventions of `error`, `warning`, and `note`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeverityMap {
+    pub bug: String,
+    pub error: String,
+    pub warning: String,
+    pub note: String,
+    pub help: String,
+}
+
+impl Default for SeverityMap {
+    fn default() -> Self {
+        Self {
+            bug: "error".to_string(),
+            error: "error".to_string(),
+            warning: "warning".to_string(),
+            note: "note".to_string(),
+            help: "note".to_string(),
+        }
+    }
+}
+
+impl SeverityMap {
+    /// Get the name of a severity.
+    pub fn get(&self, severity: Severity) -> &str {
+        match severity {
+            Severity::Bug => &self.bug,
+            Severity::Error => &self.error,
+            Severity::Warning => &self.warning,
+            Severity::Note => &self.note,
+            Severity::Help => &self.help,
+        }
+    }
+}
+
+/// The amount of diagnostics of each severity.
+#[derive(Serialize, Default)]
+struct SeverityCounts {
+    bug: usize,
+    error: usize,
+    warning: usize,
+    note: usize,
+    help: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDiagnostic {
+    severity: String,
+    /// The name of the rule which emitted the diagnostic, `None` for parser diagnostics.
+    rule: Option<String>,
+    message: String,
+    file_id: usize,
+    primary: Option<JsonLabel>,
+    secondary: Vec<JsonLabel>,
+}
+
+/// A label with the byte range it points to.
+#[derive(Serialize)]
+struct JsonLabel {
+    message: String,
+    start: usize,
+    end: usize,
+}
+
+impl From<&SubDiagnostic> for JsonLabel {
+    fn from(sub: &SubDiagnostic) -> Self {
+        Self {
+            message: sub.msg.clone(),
+            start: sub.span.range.start,
+            end: sub.span.range.end,
+        }
+    }
+}
+
+impl JsonDiagnostic {
+    fn new(diagnostic: &Diagnostic, severities: &SeverityMap) -> Self {
+        Self {
+            severity: severities.get(diagnostic.severity).to_string(),
+            rule: diagnostic.code.clone(),
+            message: diagnostic.title.clone(),
+            file_id: diagnostic.file_id,
+            primary: diagnostic.primary.as_ref().map(Into::into),
+            secondary: diagnostic.children.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+fn outcome_name(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::Failure => "failure",
+        Outcome::Warning => "warning",
+        Outcome::Success => "success",
+    }
+}
+
+impl LintResult<'_> {
+    /// Serialize this result to JSON for consumption by other tools.
+    ///
+    /// The object contains the `fileId`, the overall `outcome` (`"failure"`, `"warning"`, or `"success"`),
+    /// the `counts` of diagnostics per severity, and every diagnostic in the order of [`LintResult::diagnostics`].
+    /// Each diagnostic has a `severity`, the `rule` which emitted it (`null` for parser diagnostics), a `message`,
+    /// a `fileId`, and its `primary` and `secondary` labels with their `message` and `start` and `end` byte offsets.
+    ///
+    /// Severities are named using the default [`SeverityMap`].
+    pub fn to_json(&self) -> Value {
+        self.to_json_with_severities(&SeverityMap::default())
+    }
+
+    /// Serialize this result to JSON like [`LintResult::to_json`], naming severities using `severities`.
+    pub fn to_json_with_severities(&self, severities: &SeverityMap) -> Value {
+        let mut counts = SeverityCounts::default();
+        for diagnostic in self.diagnostics() {
+            *match diagnostic.severity {
+                Severity::Bug => &mut counts.bug,
+                Severity::Error => &mut counts.error,
+                Severity::Warning => &mut counts.warning,
+                Severity::Note => &mut counts.note,
+                Severity::Help => &mut counts.help,
+            } += 1;
+        }
+
+        let result = JsonResult {
+            file_id: self.file_id,
+            outcome: outcome_name(self.outcome()),
+            counts,
+            diagnostics: self
+                .diagnostics()
+                .map(|diagnostic| JsonDiagnostic::new(diagnostic, severities))
+                .collect(),
+        };
+        serde_json::to_value(result).expect("lint results are always serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::{NoDebugger, NoEmpty};
+    use crate::{lint_file, CstRule, CstRuleStore, File};
+    use rslint_parser::FileKind;
+    use serde_json::json;
+
+    #[test]
+    fn to_json_includes_diagnostics_and_counts() {
+        let file = File::from_string("{}\ndebugger;", FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoEmpty::default()) as Box<dyn CstRule>,
+            Box::new(NoDebugger::default()),
+        ]);
+        let json = lint_file(&file, &store, false).to_json();
+
+        assert_eq!(json["fileId"], json!(file.id));
+        assert_eq!(json["outcome"], "failure");
+        assert_eq!(
+            json["counts"],
+            json!({ "bug": 0, "error": 2, "warning": 0, "note": 0, "help": 0 })
+        );
+
+        let diagnostics = json["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0]["rule"], "no-empty");
+        assert_eq!(diagnostics[0]["severity"], "error");
+        assert_eq!(diagnostics[0]["primary"]["start"], 0);
+        assert_eq!(diagnostics[0]["primary"]["end"], 2);
+        assert_eq!(diagnostics[1]["rule"], "no-debugger");
+        assert_eq!(diagnostics[1]["primary"]["start"], 3);
+        assert!(diagnostics[1]["secondary"].is_array());
+    }
+
+    #[test]
+    fn to_json_with_severities_uses_custom_names() {
+        let file = File::from_string("{}", FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoEmpty::default()) as Box<dyn CstRule>]);
+        let severities = SeverityMap {
+            error: "high".to_string(),
+            warning: "medium".to_string(),
+            ..SeverityMap::default()
+        };
+        let json = lint_file(&file, &store, false).to_json_with_severities(&severities);
+
+        assert_eq!(json["diagnostics"][0]["severity"], "high");
+        assert_eq!(json["counts"]["error"], 1);
+        assert_eq!(SeverityMap::default().get(Severity::Help), "note");
+    }
+}
@@ -15,7 +15,8 @@
 //! ## Using the runner
 //!
 //! To run the runner you must first create a [`CstRuleStore`], which is the structure used for storing what rules
-//! to run. Then you can use [`lint_file`].
+//! to run. Then you can use [`lint_file`]. Rules are run in parallel on the global rayon thread pool
+//! if the opt-in `parallel` feature is enabled, the results are identical either way.
 //!
 //! ## Running a single rule
 //!
@@ -29,9 +30,14 @@
 // FIXME: Workaround for https://github.com/GREsau/schemars/pull/65
 #![allow(clippy::field_reassign_with_default)]
 
+mod code_actions;
 mod file;
+mod ignore_set;
+mod json;
 mod rule;
+mod stats;
 mod store;
+mod summary;
 mod testing;
 
 pub mod autofix;
@@ -41,20 +47,38 @@ pub mod rule_prelude;
 pub mod util;
 
 pub use self::{
+    code_actions::{CodeAction, CodeActionKind},
     file::File,
-    rule::{CstRule, Inferable, Outcome, Rule, RuleCtx, RuleLevel, RuleResult, Tag},
-    store::CstRuleStore,
+    ignore_set::IgnoreSet,
+    json::SeverityMap,
+    rule::{
+        CstRule, Inferable, Matching, Outcome, Rule, RuleCtx, RuleLevel, RuleResult, Tag,
+        TraceEntry,
+    },
+    stats::{parse_stats, ParseStats},
+    store::{ConfigureError, CstRuleStore, NameIssue, DEPRECATED_RULE_NAMES},
+    summary::{batch_summary, BatchSummary, Summary},
+    testing::{assert_rule_has_metadata, assert_rule_under},
 };
+pub use crate::groups::{all_groups, register_group};
 pub use rslint_errors::{Diagnostic, Severity, Span};
 
+use crate::directives::default_directive_prefixes;
 pub use crate::directives::{
-    apply_top_level_directives, skip_node, Directive, DirectiveError, DirectiveErrorKind,
-    DirectiveParser,
+    apply_top_level_directives, collect_directive_rules, is_directive_comment, skip_node,
+    Directive, DirectiveError, DirectiveErrorKind, DirectiveParser, LineSuppressions,
 };
 
 use dyn_clone::clone_box;
-use rslint_parser::{util::SyntaxNodeExt, SyntaxKind, SyntaxNode};
-use std::collections::HashMap;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use rslint_parser::{
+    util::{SyntaxNodeExt, SyntaxTokenExt},
+    FileKind, GreenNode, SyntaxElement, SyntaxKind, SyntaxNode, TextRange,
+};
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// The result of linting a file.
@@ -76,34 +100,144 @@ pub struct LintResult<'s> {
 
 impl LintResult<'_> {
     /// Get all of the diagnostics thrown during linting, in the order of parser diagnostics, then
-    /// the diagnostics of each rule sequentially.
+    /// the diagnostics of each rule sequentially in the order of the rules in the store.
     pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
         self.parser_diagnostics
             .iter()
             .chain(
-                self.rule_results
-                    .values()
-                    .map(|x| x.diagnostics.iter())
-                    .flatten(),
+                self.ordered_rule_results()
+                    .flat_map(|result| result.diagnostics.iter()),
             )
             .chain(self.directive_diagnostics.iter().map(|x| &x.diagnostic))
     }
 
+    /// The results of each rule in the order of the rules in the store, results of rules which are
+    /// not in the store come last and are sorted by name.
+    fn ordered_rule_results(&self) -> impl Iterator<Item = &RuleResult> {
+        self.ordered_rule_names()
+            .into_iter()
+            .map(move |name| &self.rule_results[name])
+    }
+
+    /// The names of the rules which were run, in the same order as [`LintResult::ordered_rule_results`].
+    fn ordered_rule_names(&self) -> Vec<&'static str> {
+        let mut names = self.rule_results.keys().copied().collect::<Vec<_>>();
+        names.sort_by_key(|name| {
+            let idx = self
+                .store
+                .rules
+                .iter()
+                .position(|rule| rule.name() == *name);
+            (idx.unwrap_or(usize::MAX), *name)
+        });
+        names
+    }
+
+    /// Get the diagnostics emitted by a single rule, this is empty if the rule was not run
+    /// or if it did not emit any diagnostics.
+    pub fn diagnostics_for(&self, name: &str) -> &[Diagnostic] {
+        self.rule_results
+            .get(name)
+            .map(|result| result.diagnostics.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The names of the rules which emitted at least one diagnostic, in the same order as
+    /// the rules in [`LintResult::diagnostics`].
+    pub fn fired_rules(&self) -> impl Iterator<Item = &str> {
+        self.ordered_rule_names()
+            .into_iter()
+            .filter(move |name| !self.rule_results[name].diagnostics.is_empty())
+    }
+
+    /// The trace of every diagnostic emitted by rules, in the same order as the rules in
+    /// [`LintResult::diagnostics`]. This is empty unless the file was linted with [`lint_file_with_trace`].
+    pub fn trace(&self) -> impl Iterator<Item = &TraceEntry> {
+        self.ordered_rule_results()
+            .flat_map(|result| result.trace.iter())
+    }
+
+    /// Replace the diagnostics of each rule past the store's collapse threshold with one diagnostic
+    /// which summarizes them. The summary has the highest severity of the diagnostics it replaces so
+    /// the outcome of the file does not change.
+    ///
+    /// The replaced diagnostics are kept in [`RuleResult::collapsed`], results which are already
+    /// collapsed are left untouched.
+    pub(crate) fn collapse_noisy_rules(&mut self) {
+        let threshold = match self.store.collapse_threshold() {
+            Some(threshold) => threshold,
+            None => return,
+        };
+        for (rule, result) in self.rule_results.iter_mut() {
+            if result.diagnostics.len() <= threshold || !result.collapsed.is_empty() {
+                continue;
+            }
+            let hidden = result.diagnostics.split_off(threshold);
+            let severity = [
+                Severity::Bug,
+                Severity::Error,
+                Severity::Warning,
+                Severity::Note,
+                Severity::Help,
+            ]
+            .iter()
+            .copied()
+            .find(|severity| hidden.iter().any(|d| d.severity == *severity))
+            .unwrap_or(Severity::Note);
+
+            let mut summary = Diagnostic::new_with_code(
+                self.file_id,
+                severity,
+                format!(
+                    "`{}` found {} problems in this file",
+                    rule,
+                    threshold + hidden.len()
+                ),
+                Some(rule.to_string()),
+            )
+            .footer_note(format!(
+                "only the first {} problems of this rule are shown",
+                threshold
+            ));
+            if let Some(primary) = &hidden[0].primary {
+                summary = summary.primary(
+                    primary.span.range.clone(),
+                    format!("{} more problems, starting here", hidden.len()),
+                );
+            }
+            result.diagnostics.push(summary);
+            result.collapsed = hidden;
+        }
+    }
+
+    /// Undo [`LintResult::collapse_noisy_rules`], replacing the summaries with the diagnostics they replaced.
+    pub(crate) fn expand_collapsed_rules(&mut self) {
+        for result in self.rule_results.values_mut() {
+            if !result.collapsed.is_empty() {
+                result.diagnostics.pop();
+                result.diagnostics.append(&mut result.collapsed);
+            }
+        }
+    }
+
     /// The overall outcome of linting this file (failure, warning, success, etc)
     pub fn outcome(&self) -> Outcome {
         self.diagnostics().into()
     }
 
+    /// Whether the parser emitted any errors which cannot be fixed automatically.
+    pub fn has_unfixable_syntax_errors(&self) -> bool {
+        self.parser_diagnostics
+            .iter()
+            .any(|x| x.severity == Severity::Error && autofix::parser_fix(x).is_none())
+    }
+
     /// Attempt to automatically fix any fixable issues and return the fixed code.
     ///
-    /// This will not run if there are syntax errors unless `dirty` is set to true.
+    /// Recoverable syntax errors such as missing semicolons are fixed too. This will not
+    /// run if there are any other syntax errors unless `dirty` is set to true.
     pub fn fix(&mut self, dirty: bool, file: &File) -> Option<String> {
-        if self
-            .parser_diagnostics
-            .iter()
-            .any(|x| x.severity == Severity::Error)
-            && !dirty
-        {
+        if self.has_unfixable_syntax_errors() && !dirty {
             None
         } else {
             Some(autofix::recursively_apply_fixes(self, file))
@@ -114,7 +248,190 @@ impl LintResult<'_> {
 /// Lint a file with a specific rule store.
 pub fn lint_file<'s>(file: &File, store: &'s CstRuleStore, verbose: bool) -> LintResult<'s> {
     let (diagnostics, node) = file.parse_with_errors();
-    lint_file_inner(node, diagnostics, file, store, verbose)
+    lint_parsed(file.id, node.green().clone(), diagnostics, store, verbose)
+}
+
+/// Lint an already parsed file with a specific rule store.
+///
+/// This is the same as [`lint_file`], but it does not parse the file again, which allows caching
+/// the parsed tree and linting it with different stores, for example when the config changes.
+/// `green` is the root of the tree, including its trivia, and `parser_diagnostics` are the errors
+/// produced while parsing it.
+///
+/// The returned [`LintResult`] only borrows `store`, the tree is shared with it (cloning a
+/// [`GreenNode`] is cheap), therefore the same tree can be linted again while the result is alive.
+pub fn lint_parsed<'s>(
+    file_id: usize,
+    green: GreenNode,
+    parser_diagnostics: Vec<Diagnostic>,
+    store: &'s CstRuleStore,
+    verbose: bool,
+) -> LintResult<'s> {
+    let node = SyntaxNode::new_root(green);
+    let kind = if node.kind() == SyntaxKind::MODULE {
+        FileKind::Module
+    } else {
+        FileKind::Script
+    };
+    let file = File {
+        id: file_id,
+        ..File::from_string(node.to_string(), kind, "")
+    };
+    let mut result = lint_file_inner(node, parser_diagnostics, &file, store, verbose, true, false);
+    result.collapse_noisy_rules();
+    result
+}
+
+/// Lint a file with a specific rule store on the current thread.
+///
+/// The rules are run one after another in the order they are in the store, even if the `parallel`
+/// feature is enabled. This produces the same result as [`lint_file`], but is easier to debug and
+/// guarantees that rules with interior state are run in a reproducible order.
+pub fn lint_file_sequential<'s>(
+    file: &File,
+    store: &'s CstRuleStore,
+    verbose: bool,
+) -> LintResult<'s> {
+    let (diagnostics, node) = file.parse_with_errors();
+    let mut result = lint_file_inner(node, diagnostics, file, store, verbose, false, false);
+    result.collapse_noisy_rules();
+    result
+}
+
+/// Lint a file with a specific rule store like [`lint_file`], recording which node or token caused
+/// each diagnostic, which can be retrieved using [`LintResult::trace`].
+pub fn lint_file_with_trace<'s>(
+    file: &File,
+    store: &'s CstRuleStore,
+    verbose: bool,
+) -> LintResult<'s> {
+    let (diagnostics, node) = file.parse_with_errors();
+    let mut result = lint_file_inner(node, diagnostics, file, store, verbose, true, true);
+    result.collapse_noisy_rules();
+    result
+}
+
+/// Lint only the part of a file covered by a byte range, such as the selection in an editor.
+///
+/// The file is parsed entirely, but rules are only run on nodes and tokens which overlap `range`,
+/// and only diagnostics whose primary label overlaps `range` are kept. Diagnostics without a primary
+/// label cannot be attributed to a range and are therefore removed.
+pub fn lint_range<'s>(
+    file_id: usize,
+    source: impl ToString,
+    module: bool,
+    range: Range<usize>,
+    store: &'s CstRuleStore,
+    verbose: bool,
+) -> LintResult<'s> {
+    let kind = if module {
+        FileKind::Module
+    } else {
+        FileKind::Script
+    };
+    let file = File {
+        id: file_id,
+        ..File::from_string(source, kind, "")
+    };
+    let (parser_diagnostics, node) = file.parse_with_errors();
+    let in_range = |node_range: TextRange| {
+        usize::from(node_range.start()) < range.end && range.start < usize::from(node_range.end())
+    };
+    let mut result = lint_file_filtered(
+        node,
+        parser_diagnostics,
+        &file,
+        store,
+        verbose,
+        false,
+        false,
+        &|elem| in_range(elem.text_range()),
+    );
+
+    let keep = |diagnostic: &Diagnostic| {
+        diagnostic
+            .primary
+            .as_ref()
+            .filter(|primary| {
+                primary.span.range.start < range.end && range.start < primary.span.range.end
+            })
+            .is_some()
+    };
+    result.parser_diagnostics.retain(&keep);
+    for rule_result in result.rule_results.values_mut() {
+        rule_result.diagnostics.retain(&keep);
+    }
+    result.directive_diagnostics.retain(|d| keep(&d.diagnostic));
+    result.collapse_noisy_rules();
+    result
+}
+
+/// Lint multiple files with a specific rule store.
+pub fn lint_files<'s>(
+    files: &[File],
+    store: &'s CstRuleStore,
+    verbose: bool,
+) -> Vec<LintResult<'s>> {
+    files
+        .iter()
+        .map(|file| lint_file(file, store, verbose))
+        .collect()
+}
+
+/// Lint multiple files in parallel on a specific thread pool.
+///
+/// Both the files and the rules of each file are run on `pool` instead of the global rayon pool,
+/// which allows controlling the amount of threads used for linting without affecting other users of rayon.
+/// The results are in the same order as `files`.
+#[cfg(feature = "parallel")]
+pub fn lint_files_in_pool<'s>(
+    files: &[File],
+    store: &'s CstRuleStore,
+    verbose: bool,
+    pool: &rayon::ThreadPool,
+) -> Vec<LintResult<'s>> {
+    pool.install(|| {
+        files
+            .par_iter()
+            .map(|file| lint_file(file, store, verbose))
+            .collect()
+    })
+}
+
+/// The result of linting multiple files with [`lint_paths`].
+#[derive(Debug, Clone)]
+pub struct LintPathsResult<'s> {
+    /// The results of the files which were linted
+    pub results: Vec<LintResult<'s>>,
+    /// The paths of the files which were skipped because they are ignored
+    pub ignored: Vec<PathBuf>,
+}
+
+/// Lint multiple files with a specific rule store, skipping any files ignored by an [`IgnoreSet`]
+/// before parsing them.
+///
+/// Files are matched using their path, or their name if they do not have a path.
+pub fn lint_paths<'s>(
+    files: &[File],
+    store: &'s CstRuleStore,
+    verbose: bool,
+    ignore: &IgnoreSet,
+) -> LintPathsResult<'s> {
+    let mut results = vec![];
+    let mut ignored = vec![];
+
+    for file in files {
+        let path = file
+            .path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(&file.name));
+        if ignore.is_ignored(&path) {
+            ignored.push(path);
+        } else {
+            results.push(lint_file(file, store, verbose));
+        }
+    }
+    LintPathsResult { results, ignored }
 }
 
 /// used by lint_file and incrementally_relint to not duplicate code
@@ -124,6 +441,33 @@ pub(crate) fn lint_file_inner<'s>(
     file: &File,
     store: &'s CstRuleStore,
     verbose: bool,
+    parallel: bool,
+    trace: bool,
+) -> LintResult<'s> {
+    lint_file_filtered(
+        node,
+        parser_diagnostics,
+        file,
+        store,
+        verbose,
+        parallel,
+        trace,
+        &|_| true,
+    )
+}
+
+/// Like [`lint_file_inner`], but rules are only run on the nodes and tokens for which `filter`
+/// returns `true`, see [`run_rule_filtered`].
+#[allow(clippy::too_many_arguments)]
+fn lint_file_filtered<'s>(
+    node: SyntaxNode,
+    mut parser_diagnostics: Vec<Diagnostic>,
+    file: &File,
+    store: &'s CstRuleStore,
+    verbose: bool,
+    parallel: bool,
+    trace: bool,
+    filter: &(dyn Fn(&SyntaxElement) -> bool + Sync),
 ) -> LintResult<'s> {
     let mut new_store = store.clone();
     let directives::DirectiveResult {
@@ -137,27 +481,60 @@ pub(crate) fn lint_file_inner<'s>(
         &mut directive_diagnostics,
         file.id,
     );
+    new_store.remove_rules_turned_off();
 
     let src: Arc<str> = Arc::from(node.to_string());
+    autofix::attach_parser_fixes(&mut parser_diagnostics, &src);
 
-    // FIXME: Replace with thread pool
-    let results = new_store
-        .rules
-        .into_iter()
-        .map(|rule| {
-            (
-                rule.name(),
-                run_rule(
-                    &*rule,
-                    file.id,
-                    node.clone(),
-                    verbose,
-                    &directives,
-                    src.clone(),
-                ),
-            )
-        })
-        .collect();
+    let suppressions = extra_line_suppressions(&node, store);
+    let directive_prefixes = store_directive_prefixes(store);
+    let run = |rules: &[Box<dyn CstRule>]| {
+        let results = run_rules_inner(
+            rules,
+            file.id,
+            node.clone(),
+            verbose,
+            &directives,
+            src.clone(),
+            trace,
+            filter,
+            directive_prefixes.clone(),
+        );
+        rules
+            .iter()
+            .map(|rule| rule.name())
+            .zip(results)
+            .map(|(name, mut result)| {
+                if let Some(suppressions) = &suppressions {
+                    suppressions.apply(name, &mut result);
+                }
+                if let Some(level) = store.level(name) {
+                    level.remap(&mut result.diagnostics);
+                }
+                (name, result)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // every rule is run in a single traversal of the tree, with the rules split in a chunk for each thread
+    #[cfg(feature = "parallel")]
+    let results = if parallel {
+        let threads = rayon::current_num_threads();
+        let chunk_size = new_store.rules.len() / threads + 1;
+        new_store
+            .rules
+            .par_chunks(chunk_size)
+            .map(run)
+            .flatten()
+            .collect()
+    } else {
+        run(&new_store.rules).into_iter().collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let results = {
+        let _ = parallel;
+        run(&new_store.rules).into_iter().collect()
+    };
 
     LintResult {
         parser_diagnostics,
@@ -171,6 +548,57 @@ pub(crate) fn lint_file_inner<'s>(
     }
 }
 
+/// Line suppressions using the directive prefixes of a store, if it has any besides `rslint`.
+///
+/// Rules always apply the `rslint` line suppressions themselves.
+fn extra_line_suppressions(root: &SyntaxNode, store: &CstRuleStore) -> Option<LineSuppressions> {
+    let prefixes = store.directive_prefixes();
+    if prefixes.len() > 1 {
+        Some(LineSuppressions::with_prefixes(root, &prefixes))
+    } else {
+        None
+    }
+}
+
+/// The directive prefixes of a store, given to the contexts of the rules run with it.
+fn store_directive_prefixes(store: &CstRuleStore) -> Arc<[String]> {
+    store
+        .directive_prefixes()
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Remove any diagnostics from a lint result whose primary label is not on one of `lines`.
+///
+/// This allows only reporting problems introduced by a diff. `lines` are zero based line indices.
+/// A diagnostic spanning multiple lines is kept if any of its lines is in `lines`, diagnostics
+/// without a primary label cannot be attributed to a line and are therefore always kept.
+pub fn filter_to_lines(result: &mut LintResult, lines: &BTreeSet<usize>) {
+    let src = result.parsed.to_string();
+    let line_starts = File::line_starts(&src).collect::<Vec<_>>();
+    let line_index = |byte_index: usize| match line_starts.binary_search(&byte_index) {
+        Ok(line) => line,
+        Err(next_line) => next_line - 1,
+    };
+
+    let keep = |diagnostic: &Diagnostic| {
+        let range = match &diagnostic.primary {
+            Some(primary) => primary.span.range.clone(),
+            None => return true,
+        };
+        let start = line_index(range.start);
+        let end = line_index(range.end.saturating_sub(1).max(range.start));
+        lines.range(start..=end).next().is_some()
+    };
+
+    result.parser_diagnostics.retain(&keep);
+    for rule_result in result.rule_results.values_mut() {
+        rule_result.diagnostics.retain(&keep);
+    }
+    result.directive_diagnostics.retain(|d| keep(&d.diagnostic));
+}
+
 /// Run a single run on an entire parsed file.
 ///
 /// # Panics
@@ -182,6 +610,24 @@ pub fn run_rule(
     verbose: bool,
     directives: &[Directive],
     src: Arc<str>,
+) -> RuleResult {
+    run_rule_filtered(rule, file_id, root, verbose, directives, src, &|_| true)
+}
+
+/// Run a single rule on the nodes and tokens of a parsed file for which `filter` returns `true`.
+///
+/// The children of a node which is filtered out are not visited. [`CstRule::check_root`] is always run.
+///
+/// # Panics
+/// Panics if `root`'s kind is not `SCRIPT` or `MODULE`
+pub fn run_rule_filtered(
+    rule: &dyn CstRule,
+    file_id: usize,
+    root: SyntaxNode,
+    verbose: bool,
+    directives: &[Directive],
+    src: Arc<str>,
+    filter: &dyn Fn(&SyntaxElement) -> bool,
 ) -> RuleResult {
     assert!(root.kind() == SyntaxKind::SCRIPT || root.kind() == SyntaxKind::MODULE);
     let mut ctx = RuleCtx {
@@ -189,30 +635,201 @@ pub fn run_rule(
         verbose,
         diagnostics: vec![],
         fixer: None,
+        pending_fix: None,
         src,
+        trace: None,
+        directive_prefixes: default_directive_prefixes(),
     };
 
     rule.check_root(&root, &mut ctx);
+    ctx.discard_pending_fix();
+    let token_only = rule.token_only();
 
     root.descendants_with_tokens_with(&mut |elem| {
+        if !filter(elem) {
+            return false;
+        }
         match elem {
             rslint_parser::NodeOrToken::Node(node) => {
                 if skip_node(directives, node, rule) || node.kind() == SyntaxKind::ERROR {
                     return false;
                 }
-                rule.check_node(node, &mut ctx);
+                if !token_only {
+                    rule.check_node(node, &mut ctx);
+                    ctx.discard_pending_fix();
+                }
             }
             rslint_parser::NodeOrToken::Token(tok) => {
                 let _ = rule.check_token(tok, &mut ctx);
+                ctx.discard_pending_fix();
+                if let Some(comment) = tok.comment() {
+                    let _ = rule.check_comment(&comment, &mut ctx);
+                    ctx.discard_pending_fix();
+                }
             }
         };
         true
     });
-    RuleResult::new(ctx.diagnostics, ctx.fixer)
+    let mut result = RuleResult::new(ctx.diagnostics, ctx.fixer);
+    LineSuppressions::new(&root).apply(rule.name(), &mut result);
+    result
+}
+
+/// Run multiple rules on an entire parsed file in a single traversal of the tree.
+///
+/// This produces the same results as calling [`run_rule`] for each rule, but only walks the tree once
+/// instead of once per rule, walking the tree is the bulk of the time it takes to run most rules.
+/// The results are in the same order as `rules`.
+///
+/// # Panics
+/// Panics if `root`'s kind is not `SCRIPT` or `MODULE`
+pub fn run_rules(
+    rules: &[Box<dyn CstRule>],
+    file_id: usize,
+    root: SyntaxNode,
+    verbose: bool,
+    directives: &[Directive],
+    src: Arc<str>,
+) -> Vec<RuleResult> {
+    run_rules_inner(
+        rules,
+        file_id,
+        root,
+        verbose,
+        directives,
+        src,
+        false,
+        &|_| true,
+        default_directive_prefixes(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_rules_inner(
+    rules: &[Box<dyn CstRule>],
+    file_id: usize,
+    root: SyntaxNode,
+    verbose: bool,
+    directives: &[Directive],
+    src: Arc<str>,
+    trace: bool,
+    filter: &dyn Fn(&SyntaxElement) -> bool,
+    directive_prefixes: Arc<[String]>,
+) -> Vec<RuleResult> {
+    assert!(root.kind() == SyntaxKind::SCRIPT || root.kind() == SyntaxKind::MODULE);
+    let mut ctxs = rules
+        .iter()
+        .map(|_| RuleCtx {
+            file_id,
+            verbose,
+            diagnostics: vec![],
+            fixer: None,
+            pending_fix: None,
+            src: src.clone(),
+            trace: if trace { Some(vec![]) } else { None },
+            directive_prefixes: directive_prefixes.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    for (rule, ctx) in rules.iter().zip(ctxs.iter_mut()) {
+        rule.check_root(&root, ctx);
+        ctx.discard_pending_fix();
+        ctx.record_trace(&**rule, root.kind(), root.text_range(), 0);
+    }
+    let token_only = rules
+        .iter()
+        .map(|rule| rule.token_only())
+        .collect::<Vec<_>>();
+    let active = (0..rules.len()).collect::<Vec<_>>();
+    run_rules_on_children(
+        &root,
+        rules,
+        &token_only,
+        &mut ctxs,
+        &active,
+        directives,
+        filter,
+    );
+
+    let suppressions = LineSuppressions::new(&root);
+    rules
+        .iter()
+        .zip(ctxs)
+        .map(|(rule, ctx)| {
+            let mut result = RuleResult::new(ctx.diagnostics, ctx.fixer);
+            result.trace = ctx.trace.unwrap_or_default();
+            suppressions.apply(rule.name(), &mut result);
+            result
+        })
+        .collect()
+}
+
+/// Run the rules at the `active` indices on every descendant of `node`, skipping nodes the same way
+/// [`run_rule`] does.
+fn run_rules_on_children(
+    node: &SyntaxNode,
+    rules: &[Box<dyn CstRule>],
+    token_only: &[bool],
+    ctxs: &mut [RuleCtx],
+    active: &[usize],
+    directives: &[Directive],
+    filter: &dyn Fn(&SyntaxElement) -> bool,
+) {
+    for elem in node.children_with_tokens() {
+        if !filter(&elem) {
+            continue;
+        }
+        match elem {
+            rslint_parser::NodeOrToken::Node(child) => {
+                if child.kind() == SyntaxKind::ERROR {
+                    continue;
+                }
+                let filtered;
+                let active = match directives::node_directive(directives, &child) {
+                    Some(directive) => {
+                        filtered = active
+                            .iter()
+                            .copied()
+                            .filter(|idx| {
+                                !directives::directive_skips_rule(directive, &*rules[*idx])
+                            })
+                            .collect::<Vec<_>>();
+                        filtered.as_slice()
+                    }
+                    None => active,
+                };
+                if active.is_empty() {
+                    continue;
+                }
+                for idx in active.iter().copied().filter(|idx| !token_only[*idx]) {
+                    let before = ctxs[idx].diagnostics.len();
+                    rules[idx].check_node(&child, &mut ctxs[idx]);
+                    ctxs[idx].discard_pending_fix();
+                    ctxs[idx].record_trace(&*rules[idx], child.kind(), child.text_range(), before);
+                }
+                run_rules_on_children(&child, rules, token_only, ctxs, active, directives, filter);
+            }
+            rslint_parser::NodeOrToken::Token(tok) => {
+                let comment = tok.comment();
+                for idx in active.iter().copied() {
+                    let before = ctxs[idx].diagnostics.len();
+                    let _ = rules[idx].check_token(&tok, &mut ctxs[idx]);
+                    ctxs[idx].discard_pending_fix();
+                    if let Some(comment) = &comment {
+                        let _ = rules[idx].check_comment(comment, &mut ctxs[idx]);
+                        ctxs[idx].discard_pending_fix();
+                    }
+                    ctxs[idx].record_trace(&*rules[idx], tok.kind(), tok.text_range(), before);
+                }
+            }
+        }
+    }
 }
 
-/// Get a rule by its kebab-case name.
+/// Get a rule by its kebab-case name, deprecated names in [`DEPRECATED_RULE_NAMES`] get the rule
+/// which replaces them.
 pub fn get_rule_by_name(name: &str) -> Option<Box<dyn CstRule>> {
+    let name = store::current_rule_name(name);
     CstRuleStore::new()
         .builtins()
         .rules
@@ -221,17 +838,9 @@ pub fn get_rule_by_name(name: &str) -> Option<Box<dyn CstRule>> {
         .map(|rule| clone_box(&**rule))
 }
 
-/// Get a group's rules by the group name.
-// TODO: there should be a good way to not have to hardcode all of this
+/// Get a group's rules by the group name, this includes groups added with [`register_group`].
 pub fn get_group_rules_by_name(group_name: &str) -> Option<Vec<Box<dyn CstRule>>> {
-    use groups::*;
-
-    Some(match group_name {
-        "errors" => errors(),
-        "style" => style(),
-        "regex" => regex(),
-        _ => return None,
-    })
+    groups::group_rules(group_name)
 }
 
 /// Get a suggestion for an incorrect rule name for things such as "did you mean ...?"
@@ -269,3 +878,699 @@ pub fn get_inferable_rules() -> Vec<Box<dyn Inferable>> {
 
     trait_obj_helper![BlockSpacing]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::{NoDebugger, NoEmpty};
+    use crate::groups::style::NoUselessConstructor;
+    use rslint_errors::Applicability;
+    use rslint_parser::FileKind;
+
+    fn lint_no_empty(source: &str) -> (File, CstRuleStore) {
+        let file = File::from_string(source, FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoEmpty::default()) as Box<dyn CstRule>]);
+        (file, store)
+    }
+
+    #[test]
+    fn filter_to_lines_keeps_changed_lines() {
+        let (file, store) = lint_no_empty("{}\nfoo;\n{}\n{\n\n}\n");
+        let mut result = lint_file(&file, &store, false);
+        assert_eq!(result.diagnostics().count(), 3);
+
+        let lines = vec![2].into_iter().collect();
+        filter_to_lines(&mut result, &lines);
+        let ranges = result
+            .diagnostics()
+            .map(|d| d.primary.as_ref().unwrap().span.range.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec![8..10]);
+    }
+
+    #[test]
+    fn filter_to_lines_keeps_multiline_diagnostics() {
+        let (file, store) = lint_no_empty("foo;\n{\n\n}\n");
+        let mut result = lint_file(&file, &store, false);
+
+        let lines = vec![2].into_iter().collect();
+        filter_to_lines(&mut result, &lines);
+        assert_eq!(result.diagnostics().count(), 1);
+
+        let lines = vec![0, 4].into_iter().collect();
+        filter_to_lines(&mut result, &lines);
+        assert_eq!(result.diagnostics().count(), 0);
+    }
+
+    #[test]
+    fn lint_file_matches_sequential_rules() {
+        let file = File::from_string(
+            "debugger;\nif (x = 5) {}\nlet a = { b: 1, b: 2 };\nswitch (a) { case 1: case 1: }",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let store = CstRuleStore::new().builtins();
+        let result = lint_file(&file, &store, false);
+
+        let src: Arc<str> = Arc::from(file.source.as_str());
+        for rule in &store.rules {
+            let expected = run_rule(
+                &**rule,
+                file.id,
+                result.parsed.clone(),
+                false,
+                &[],
+                src.clone(),
+            );
+            assert_eq!(
+                result.rule_results[rule.name()].diagnostics,
+                expected.diagnostics,
+                "`{}` produced different diagnostics",
+                rule.name()
+            );
+        }
+    }
+
+    #[test]
+    fn custom_directive_prefix_suppresses_diagnostics() {
+        let source = "foo;\n// eslint-ignore no-debugger\ndebugger;\ndebugger; // eslint-ignore\nfoo;\ndebugger;\n";
+        let file = File::from_string(source, FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoDebugger::default()) as Box<dyn CstRule>]);
+        assert_eq!(lint_file(&file, &store, false).diagnostics().count(), 3);
+
+        let store = store.with_directive_prefix("eslint");
+        let result = lint_file(&file, &store, false);
+        let diagnostics = result.diagnostics().collect::<Vec<_>>();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].primary.as_ref().unwrap().span.range,
+            source.rfind("debugger;").unwrap()..source.len() - 1
+        );
+    }
+
+    #[test]
+    fn no_debugger_fix_does_not_join_statements() {
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoDebugger::default()) as Box<dyn CstRule>]);
+        let fixed = |source: &str| {
+            let file = File::from_string(source, FileKind::Module, "test.mjs");
+            lint_file(&file, &store, false).fix(false, &file)
+        };
+
+        assert_eq!(
+            fixed("foo()\ndebugger\n;bar()").as_deref(),
+            Some("foo()\nbar()")
+        );
+        for source in &[
+            "foo()\ndebugger\n;[1]",
+            "foo()\ndebugger\n(a)",
+            "foo\ndebugger\n`a`",
+        ] {
+            assert_eq!(fixed(source).as_deref(), Some(*source));
+        }
+    }
+
+    #[test]
+    fn no_useless_constructor_only_fixes_constructors_which_forward_every_argument() {
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoUselessConstructor::default()) as Box<dyn CstRule>
+        ]);
+        let lint = |source: &str| {
+            let file = File::from_string(source, FileKind::Module, "test.mjs");
+            let mut result = lint_file(&file, &store, false);
+            let suggestions = result
+                .diagnostics()
+                .flat_map(|d| d.suggestions.iter().map(|s| s.applicability))
+                .collect::<Vec<_>>();
+            (result.fix(false, &file).unwrap(), suggestions)
+        };
+
+        assert_eq!(lint("class A { constructor() {} }").0, "class A {  }");
+        assert_eq!(
+            lint("class A extends B { constructor(...args) { super(...args); } }").0,
+            "class A extends B {  }"
+        );
+        let source = "class A extends B { constructor(a) { super(a); } }";
+        assert_eq!(
+            lint(source),
+            (source.to_string(), vec![Applicability::MaybeIncorrect])
+        );
+    }
+
+    #[test]
+    fn rule_fixes_are_attached_to_their_diagnostics() {
+        let source = "var a = 'b'; if (5 === a) {} function f() {return a}";
+        let file = File::from_string(source, FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(crate::groups::style::Quotes::default()) as Box<dyn CstRule>,
+            Box::new(crate::groups::style::Yoda::default()),
+            Box::new(crate::groups::style::BlockSpacing::default()),
+        ]);
+        let mut result = lint_file(&file, &store, false);
+
+        let diagnostics = result.diagnostics().collect::<Vec<_>>();
+        assert_eq!(diagnostics.len(), 4);
+        for diagnostic in diagnostics {
+            assert!(diagnostic
+                .suggestions
+                .iter()
+                .any(|suggestion| suggestion.msg == rule::FIX_MSG));
+        }
+        assert_eq!(
+            result.fix(false, &file).as_deref(),
+            Some("var a = \"b\"; if (a === 5) {} function f() { return a }")
+        );
+    }
+
+    #[test]
+    fn diagnostics_for_returns_the_diagnostics_of_one_rule() {
+        let (file, mut store) = lint_no_empty("{}\nfoo;\nif (a) {}\n");
+        store.load_rules(vec![
+            Box::new(crate::groups::errors::NoDebugger::default()) as Box<dyn CstRule>
+        ]);
+        let result = lint_file(&file, &store, false);
+
+        assert_eq!(result.diagnostics_for("no-empty").len(), 2);
+        assert!(result.diagnostics_for("no-debugger").is_empty());
+        assert!(result.diagnostics_for("no-unknown-rule").is_empty());
+        assert_eq!(result.fired_rules().collect::<Vec<_>>(), vec!["no-empty"]);
+
+        let file = File::from_string("debugger;\n{}\n", FileKind::Module, "test.mjs");
+        let result = lint_file(&file, &store, false);
+        assert_eq!(
+            result.fired_rules().collect::<Vec<_>>(),
+            vec!["no-empty", "no-debugger"]
+        );
+    }
+
+    #[test]
+    fn lint_parsed_reuses_parsed_tree() {
+        let (file, store) = lint_no_empty("{}\nfoo(;\nif (a) {}\n");
+        let (diagnostics, node) = file.parse_with_errors();
+        let expected = lint_file(&file, &store, false);
+
+        let result = lint_parsed(
+            file.id,
+            node.green().clone(),
+            diagnostics.clone(),
+            &store,
+            false,
+        );
+        assert_eq!(
+            result.diagnostics().collect::<Vec<_>>(),
+            expected.diagnostics().collect::<Vec<_>>()
+        );
+
+        let other_store = CstRuleStore::new();
+        let result = lint_parsed(
+            file.id,
+            node.green().clone(),
+            diagnostics.clone(),
+            &other_store,
+            false,
+        );
+        assert_eq!(result.diagnostics().count(), diagnostics.len());
+        assert_eq!(result.parsed.green(), node.green());
+    }
+
+    mod comment_rule {
+        use crate::rule_prelude::*;
+
+        declare_lint! {
+            /**
+            Report every comment, used to test `check_comment`.
+            */
+            #[derive(Default)]
+            ReportComments,
+            tests,
+            "report-comments"
+        }
+
+        #[typetag::serde]
+        impl CstRule for ReportComments {
+            fn check_comment(&self, comment: &Comment, ctx: &mut RuleCtx) -> Option<()> {
+                let err = ctx
+                    .err(
+                        self.name(),
+                        format!(
+                            "{} block: {} directive: {}",
+                            comment.content.trim(),
+                            comment.is_block(),
+                            ctx.is_directive(comment)
+                        ),
+                    )
+                    .primary(comment.token.text_range(), "");
+                ctx.add_err(err);
+                None
+            }
+        }
+    }
+
+    mod abandoned_fix_rule {
+        use crate::rule_prelude::*;
+
+        declare_lint! {
+            /**
+            Start a fix for every name without reporting it, and report every literal without a fix.
+            */
+            #[derive(Default)]
+            AbandonFixes,
+            tests,
+            "abandon-fixes"
+        }
+
+        #[typetag::serde]
+        impl CstRule for AbandonFixes {
+            fn check_node(&self, node: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
+                match node.kind() {
+                    SyntaxKind::NAME_REF => {
+                        ctx.fixer().replace(node.trimmed_range(), "fixed");
+                    }
+                    SyntaxKind::LITERAL => {
+                        let err = ctx
+                            .err(self.name(), "literal")
+                            .primary(node.trimmed_range(), "");
+                        ctx.add_err(err);
+                    }
+                    _ => {}
+                }
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn unreported_fixes_are_not_attached_to_later_diagnostics() {
+        let src = "foo;\n5;\n";
+        let root = rslint_parser::parse_module(src, 0).syntax();
+        let rule = abandoned_fix_rule::AbandonFixes::new();
+
+        let result = run_rule(&rule, 0, root.clone(), false, &[], Arc::from(src));
+        assert_eq!(result.diagnostics.len(), 1);
+        assert!(result.diagnostics[0].suggestions.is_empty());
+        assert!(result.fixer.is_none());
+
+        let rules = vec![Box::new(rule) as Box<dyn CstRule>];
+        let results = run_rules(&rules, 0, root, false, &[], Arc::from(src));
+        assert_eq!(results[0].diagnostics.len(), 1);
+        assert!(results[0].diagnostics[0].suggestions.is_empty());
+        assert!(results[0].fixer.is_none());
+    }
+
+    #[test]
+    fn check_comment_is_called_for_each_comment() {
+        let file = File::from_string(
+            "foo; // a\n/* b */ bar;\n/** c */\nfunction f() {\n    // rslint-ignore no-empty\n    {}\n}\n",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(comment_rule::ReportComments::new()) as Box<dyn CstRule>
+        ]);
+        let expected = vec![
+            "a block: false directive: false",
+            "b block: true directive: false",
+            "c block: true directive: false",
+            "rslint-ignore no-empty block: false directive: true",
+        ];
+
+        let result = lint_file(&file, &store, false);
+        let titles = result.rule_results["report-comments"]
+            .diagnostics
+            .iter()
+            .map(|d| d.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, expected);
+
+        let result = run_rule(
+            &comment_rule::ReportComments::new(),
+            file.id,
+            file.parse(),
+            false,
+            &[],
+            Arc::from(file.source.as_str()),
+        );
+        let titles = result
+            .diagnostics
+            .iter()
+            .map(|d| d.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, expected);
+    }
+
+    #[test]
+    fn is_directive_uses_the_prefixes_of_the_store() {
+        let file = File::from_string(
+            "// eslint-ignore no-empty
+{}
+",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(comment_rule::ReportComments::new()) as Box<dyn CstRule>
+        ]);
+        let title = |store: &CstRuleStore| {
+            lint_file(&file, store, false).rule_results["report-comments"].diagnostics[0]
+                .title
+                .clone()
+        };
+
+        assert_eq!(
+            title(&store),
+            "eslint-ignore no-empty block: false directive: false"
+        );
+        let store = store.with_directive_prefix("eslint");
+        assert_eq!(
+            title(&store),
+            "eslint-ignore no-empty block: false directive: true"
+        );
+    }
+
+    #[test]
+    fn collapse_threshold_summarizes_noisy_rules() {
+        let (file, store) = lint_no_empty("{}\n{}\n{}\n{}\n{}\n");
+        assert_eq!(lint_file(&file, &store, false).diagnostics().count(), 5);
+
+        let store = store.with_collapse_threshold(2);
+        let result = lint_file(&file, &store, false);
+        let diagnostics = result.diagnostics().collect::<Vec<_>>();
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(
+            diagnostics[2].title,
+            "`no-empty` found 5 problems in this file"
+        );
+        assert_eq!(diagnostics[2].severity, Severity::Error);
+        assert_eq!(diagnostics[2].primary.as_ref().unwrap().span.range, 6..8);
+        assert_eq!(result.outcome(), Outcome::Failure);
+    }
+
+    #[test]
+    fn run_rules_matches_run_rule_with_directives() {
+        let file = File::from_string(
+            "debugger;\n// rslint-ignore no-empty\nif (x = 5) {}\n// rslint-ignore\n{ debugger; }\nfoo() {}",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let store = CstRuleStore::new().builtins();
+        let root = file.parse();
+        let directives = DirectiveParser::new_with_store(root.clone(), &file, &store)
+            .get_file_directives()
+            .directives;
+        assert!(!directives.is_empty());
+
+        let src: Arc<str> = Arc::from(file.source.as_str());
+        let results = run_rules(
+            &store.rules,
+            file.id,
+            root.clone(),
+            false,
+            &directives,
+            src.clone(),
+        );
+        for (rule, result) in store.rules.iter().zip(results) {
+            let expected = run_rule(
+                &**rule,
+                file.id,
+                root.clone(),
+                false,
+                &directives,
+                src.clone(),
+            );
+            assert_eq!(
+                result.diagnostics,
+                expected.diagnostics,
+                "`{}` produced different diagnostics",
+                rule.name()
+            );
+        }
+    }
+
+    #[test]
+    fn registered_groups_can_be_queried() {
+        fn custom() -> Vec<Box<dyn CstRule>> {
+            vec![Box::new(NoEmpty::default())]
+        }
+
+        // a local registry, since other tests query the global groups in parallel
+        let mut registry = groups::GroupRegistry::builtin();
+        assert!(registry.get("custom").is_none());
+        registry.register("custom", custom);
+
+        let rules = registry.get("custom").unwrap()();
+        assert_eq!(
+            rules.iter().map(|rule| rule.name()).collect::<Vec<_>>(),
+            vec!["no-empty"]
+        );
+        assert!(!registry.get("errors").unwrap()().is_empty());
+        assert_eq!(
+            registry
+                .all()
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>(),
+            vec!["custom", "errors", "regex", "style"]
+        );
+    }
+
+    #[test]
+    fn every_group_has_rules() {
+        let groups = all_groups().collect::<Vec<_>>();
+        for (name, rules) in groups::BUILTIN_GROUPS {
+            assert!(groups.iter().any(|(group, _)| group == name));
+            assert!(rules().iter().all(|rule| rule.group() == *name));
+        }
+        for (name, rules) in groups {
+            assert!(!rules.is_empty(), "group `{}` has no rules", name);
+            assert!(get_group_rules_by_name(name).is_some());
+        }
+    }
+
+    #[test]
+    fn lint_paths_skips_ignored_files() {
+        let (_, store) = lint_no_empty("");
+        let files = vec![
+            File::new_concrete("{}".to_string(), PathBuf::from("node_modules/foo/index.js")),
+            File::new_concrete("{}".to_string(), PathBuf::from("src/index.js")),
+        ];
+        let ignore = IgnoreSet::from_patterns(&["node_modules/"]);
+
+        let result = lint_paths(&files, &store, false, &ignore);
+        assert_eq!(
+            result.ignored,
+            vec![PathBuf::from("node_modules/foo/index.js")]
+        );
+        assert_eq!(result.results.len(), 1);
+        assert_eq!(result.results[0].file_id, files[1].id);
+        assert_eq!(result.results[0].diagnostics().count(), 1);
+
+        assert_eq!(lint_files(&files, &store, false).len(), 2);
+    }
+
+    #[test]
+    fn missing_semicolon_gets_parser_fix() {
+        let (file, store) = lint_no_empty("let foo = bar throw foo\nfoo(); {}");
+        let mut result = lint_file(&file, &store, false);
+        assert_eq!(result.parser_diagnostics.len(), 1);
+
+        let fix = autofix::parser_fix(&result.parser_diagnostics[0]).expect("a parser fix");
+        assert_eq!(fix.insert, ";");
+        assert_eq!(usize::from(fix.delete.start()), 13);
+        assert!(fix.delete.is_empty());
+        assert!(!result.has_unfixable_syntax_errors());
+
+        assert_eq!(
+            result.fix(false, &file).as_deref(),
+            Some("let foo = bar; throw foo\nfoo(); {}")
+        );
+        assert!(result.parser_diagnostics.is_empty());
+    }
+
+    #[test]
+    fn missing_semicolons_inside_of_delimiters_are_not_fixed() {
+        for source in &["foo(a b)", "if (a b) {}", "foo(a b); let a = b c"] {
+            let (file, store) = lint_no_empty(source);
+            let result = lint_file(&file, &store, false);
+            assert!(
+                result
+                    .parser_diagnostics
+                    .iter()
+                    .any(|d| d.code.as_deref()
+                        == Some(rslint_parser::syntax::stmt::MISSING_SEMICOLON))
+            );
+            let fixes = result
+                .parser_diagnostics
+                .iter()
+                .filter_map(autofix::parser_fix)
+                .map(|fix| usize::from(fix.delete.start()))
+                .collect::<Vec<_>>();
+            let expected = if source.contains("let") {
+                vec![19]
+            } else {
+                vec![]
+            };
+            assert_eq!(fixes, expected, "fixes for `{}`", source);
+        }
+
+        let (file, store) = lint_no_empty("function f() { let a = b c }");
+        let result = lint_file(&file, &store, false);
+        assert!(autofix::parser_fix(&result.parser_diagnostics[0]).is_some());
+    }
+
+    #[test]
+    fn unrecoverable_syntax_errors_are_not_fixed() {
+        let (file, store) = lint_no_empty("let = ; {}");
+        let mut result = lint_file(&file, &store, false);
+        assert!(result.has_unfixable_syntax_errors());
+        assert_eq!(result.fix(false, &file), None);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn lint_files_in_pool_matches_lint_files() {
+        let files = vec![
+            File::from_string("{}\nif (a) {}", FileKind::Module, "a.mjs"),
+            File::from_string("debugger; {}", FileKind::Module, "b.mjs"),
+            File::from_string("foo;", FileKind::Module, "c.mjs"),
+        ];
+        let store = CstRuleStore::new().builtins();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+
+        let diagnostics = |results: Vec<LintResult>| {
+            results
+                .iter()
+                .map(|result| {
+                    let mut rules = result
+                        .rule_results
+                        .iter()
+                        .map(|(name, result)| (*name, result.diagnostics.clone()))
+                        .collect::<Vec<_>>();
+                    rules.sort_by_key(|(name, _)| *name);
+                    (result.file_id, rules)
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected = diagnostics(lint_files(&files, &store, false));
+        assert_eq!(
+            diagnostics(lint_files_in_pool(&files, &store, false, &pool)),
+            expected
+        );
+        assert!(expected
+            .iter()
+            .any(|(_, rules)| rules.iter().any(|(_, diagnostics)| !diagnostics.is_empty())));
+    }
+
+    #[test]
+    fn builtin_rule_types_match_names() {
+        for rule in CstRuleStore::new().builtins().rules {
+            let value = serde_json::to_value(&rule).unwrap();
+            let type_name = value.as_object().unwrap().keys().next().unwrap().to_owned();
+            assert_eq!(
+                util::kebab_to_pascal(rule.name()),
+                type_name,
+                "rule `{}` is declared with a mismatched type name",
+                rule.name()
+            );
+        }
+    }
+
+    #[test]
+    fn lint_file_sequential_matches_lint_file() {
+        let file = File::from_string(
+            "if (a) {} debugger; switch (b) { case 1: case 1: } foo;; new Symbol();",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let store = CstRuleStore::new().builtins();
+        let codes = |result: &LintResult| {
+            result
+                .diagnostics()
+                .map(|d| {
+                    (
+                        d.code.clone(),
+                        d.primary.as_ref().map(|p| p.span.range.clone()),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = codes(&lint_file_sequential(&file, &store, false));
+        assert!(sequential.len() > 3);
+        assert_eq!(sequential, codes(&lint_file(&file, &store, false)));
+        assert_eq!(
+            sequential,
+            codes(&lint_file_sequential(&file, &store, false))
+        );
+
+        let names = store
+            .rules
+            .iter()
+            .map(|rule| rule.name())
+            .collect::<Vec<_>>();
+        let positions = sequential
+            .iter()
+            .map(|(code, _)| {
+                names
+                    .iter()
+                    .position(|name| Some(name.to_string()) == *code)
+            })
+            .collect::<Vec<_>>();
+        assert!(positions.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn lint_file_with_trace_records_triggering_nodes() {
+        use crate::groups::errors::NoDuplicateCases;
+
+        let file = File::from_string(
+            "switch (a) { case 1: break; case 1: break; }",
+            FileKind::Module,
+            "test.mjs",
+        );
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoDuplicateCases::default()) as Box<dyn CstRule>,
+            Box::new(NoEmpty::default()),
+        ]);
+
+        let result = lint_file_with_trace(&file, &store, false);
+        assert_eq!(
+            result.trace().copied().collect::<Vec<_>>(),
+            vec![TraceEntry {
+                rule: "no-duplicate-cases",
+                kind: SyntaxKind::SWITCH_STMT,
+                range: TextRange::new(0.into(), 44.into()),
+            }]
+        );
+        assert_eq!(lint_file(&file, &store, false).trace().count(), 0);
+    }
+
+    #[test]
+    fn lint_range_only_reports_diagnostics_in_range() {
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![Box::new(NoEmpty::default()) as Box<dyn CstRule>]);
+        let source = "if (a) {}\nif (b) {}\nif (c) {}";
+
+        let result = lint_range(0, source, true, 10..19, &store, false);
+        let ranges = result
+            .diagnostics()
+            .map(|d| d.primary.as_ref().unwrap().span.range.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec![17..19]);
+        assert_eq!(
+            lint_range(0, source, true, 0..source.len(), &store, false)
+                .diagnostics()
+                .count(),
+            3
+        );
+    }
+}
@@ -4,19 +4,25 @@
 #![allow(unused_variables, unused_imports)]
 
 use crate::autofix::Fixer;
+use crate::directives::{default_directive_prefixes, is_directive_comment};
 use crate::Diagnostic;
 use dyn_clone::DynClone;
-use rslint_errors::Severity;
-use rslint_parser::{SyntaxNode, SyntaxNodeExt, SyntaxToken};
+use rslint_errors::{Applicability, Severity};
+use rslint_parser::{
+    util::Comment, AstNode, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken, TextRange,
+};
 use rslint_text_edit::apply_indels;
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::fmt::Debug;
-use std::marker::{Send, Sync};
+use std::marker::{PhantomData, Send, Sync};
 use std::ops::{Deref, DerefMut, Drop};
 use std::rc::Rc;
 use std::sync::Arc;
 
+/// The message of the suggestions made from fixes recorded with [`RuleCtx::fixer`].
+pub const FIX_MSG: &str = "apply the automatic fix";
+
 /// A tag describing properties present on a rule, such as if the rule is recommended or if it runs on only some languages.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Tag {
@@ -73,6 +79,14 @@ pub trait CstRule: Rule {
         None
     }
 
+    /// Check an individual comment in the syntax tree, this is called for every `COMMENT` token
+    /// after [`check_token`](CstRule::check_token).
+    /// Defaults to doing nothing.
+    #[inline]
+    fn check_comment(&self, comment: &Comment, ctx: &mut RuleCtx) -> Option<()> {
+        None
+    }
+
     /// Check the root of the tree one time.
     /// This method is guaranteed to only be called once.
     /// The root's kind will be either `SCRIPT` or `MODULE`.
@@ -81,6 +95,14 @@ pub trait CstRule: Rule {
     fn check_root(&self, root: &SyntaxNode, ctx: &mut RuleCtx) -> Option<()> {
         None
     }
+
+    /// Whether this rule only checks tokens, which allows the linter to skip calling
+    /// [`check_node`](CstRule::check_node) for every node in the tree.
+    /// Defaults to `false`.
+    #[inline]
+    fn token_only(&self) -> bool {
+        false
+    }
 }
 
 /// A generic trait which describes things common to a rule regardless on what they run on.
@@ -110,6 +132,16 @@ pub trait Rule: Debug + DynClone + Send + Sync {
     fn schema(&self) -> Option<schemars::schema::RootSchema> {
         None
     }
+
+    /// The JSON schema of the rule's options, which can be used for things like autocompletion of configs in editors.
+    ///
+    /// This is generated from [`Self::schema`], rules without a schema have a schema for an empty object.
+    #[cfg(feature = "schema")]
+    fn config_schema(&self) -> serde_json::Value {
+        self.schema()
+            .and_then(|schema| serde_json::to_value(schema).ok())
+            .unwrap_or_else(|| serde_json::json!({ "type": "object" }))
+    }
 }
 
 dyn_clone::clone_trait_object!(Rule);
@@ -126,10 +158,31 @@ pub trait Inferable: CstRule {
 /// The level configured for a rule.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RuleLevel {
+    /// The rule is not run at all.
+    Off,
+    /// Errors emitted by the rule are reported as warnings.
     Warning,
+    /// Diagnostics emitted by the rule keep their severity.
     Error,
 }
 
+impl RuleLevel {
+    /// Remap each error diagnostic to a warning diagnostic if this level is [`RuleLevel::Warning`].
+    ///
+    /// This leaves warnings untouched for [`RuleLevel::Error`] because rules should be able to emit
+    /// errors and warnings for context without the warnings being remapped to errors.
+    pub fn remap(self, diagnostics: &mut [Diagnostic]) {
+        if self != RuleLevel::Warning {
+            return;
+        }
+        for diagnostic in diagnostics {
+            if diagnostic.severity == Severity::Error {
+                diagnostic.severity = Severity::Warning;
+            }
+        }
+    }
+}
+
 /// Context given to a rule when running it.
 // This is passed by reference and not by Arc, which is very important,
 // Arcs are very expensive to copy, and for 50 rules running on 50 files we will have a total of
@@ -144,7 +197,13 @@ pub struct RuleCtx {
     /// An empty vector of diagnostics which the rule adds to.
     pub diagnostics: Vec<Diagnostic>,
     pub fixer: Option<Fixer>,
+    /// The edits made through [`RuleCtx::fixer`] which have not been attached to a diagnostic yet.
+    pub pending_fix: Option<Fixer>,
     pub src: Arc<str>,
+    /// The trace of the diagnostics emitted by the rule, `None` if tracing is disabled.
+    pub trace: Option<Vec<TraceEntry>>,
+    /// The prefixes directive comments are recognized by, such as `rslint` for `// rslint-ignore`.
+    pub directive_prefixes: Arc<[String]>,
 }
 
 impl RuleCtx {
@@ -153,17 +212,130 @@ impl RuleCtx {
         Diagnostic::error(self.file_id, code.into(), message.into())
     }
 
-    pub fn add_err(&mut self, diagnostic: Diagnostic) {
+    pub fn add_err(&mut self, mut diagnostic: Diagnostic) {
+        // dummy contexts do not have a source to validate against
+        if !self.src.is_empty() {
+            self.validate_ranges(&mut diagnostic);
+        }
+        if let Some(pending) = self.pending_fix.take() {
+            diagnostic = self.attach_fix(diagnostic, pending);
+        }
         self.diagnostics.push(diagnostic)
     }
 
+    /// Attach the edits of a fixer to a diagnostic as a suggestion and add them to the context's fixer.
+    /// The fix is dropped if any of its edits overlap with another edit the rule made, applying only
+    /// some of the edits of a fix could produce broken code.
+    fn attach_fix(&mut self, diagnostic: Diagnostic, pending: Fixer) -> Diagnostic {
+        let src = self.src.clone();
+        let fixer = self.fixer.get_or_insert_with(|| Fixer::new(src.clone()));
+        let mut checked = Fixer::new(src);
+        for indel in &pending.indels {
+            if fixer.overlaps(indel) || checked.overlaps(indel) {
+                return diagnostic.footer_note(
+                    "this problem is not fixed automatically because its fix overlaps with another fix",
+                );
+            }
+            checked.indels.push(indel.clone());
+        }
+
+        let start = pending
+            .indels
+            .iter()
+            .map(|indel| indel.delete.start())
+            .min();
+        let end = pending.indels.iter().map(|indel| indel.delete.end()).max();
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return diagnostic,
+        };
+        fixer.indels.extend(pending.indels.iter().cloned());
+        diagnostic.indel_suggestion(
+            pending.indels,
+            TextRange::new(start, end),
+            FIX_MSG,
+            Applicability::Always,
+        )
+    }
+
+    /// Make sure every label of a diagnostic is inside of the source code and is not inverted.
+    /// This panics in debug builds to catch buggy rules, and clamps the ranges in release builds.
+    fn validate_ranges(&self, diagnostic: &mut Diagnostic) {
+        let len = self.src.len();
+        let rule = diagnostic.code.clone().unwrap_or_default();
+
+        for label in diagnostic
+            .primary
+            .iter_mut()
+            .chain(diagnostic.children.iter_mut())
+        {
+            let range = &mut label.span.range;
+            debug_assert!(
+                range.start <= range.end && range.end <= len,
+                "rule `{}` emitted a label with an invalid range {:?} for a source of length {}",
+                rule,
+                range,
+                len
+            );
+            range.end = range.end.min(len);
+            range.start = range.start.min(range.end);
+        }
+    }
+
+    /// Get a fixer for recording the fix of the next diagnostic added with [`RuleCtx::add_err`].
+    ///
+    /// Unlike [`RuleCtx::fix`], the edits are not thrown away by later fixes, they are attached to the
+    /// diagnostic as a suggestion and accumulate in [`RuleCtx::fixer`](RuleCtx#structfield.fixer)
+    /// over the entire rule run. A fix is dropped if it overlaps with another fix of the rule.
+    pub fn fixer(&mut self) -> &mut Fixer {
+        let src = self.src.clone();
+        self.pending_fix.get_or_insert_with(|| Fixer::new(src))
+    }
+
+    /// Throw away the edits made through [`RuleCtx::fixer`] which were not attached to a diagnostic.
+    ///
+    /// This is called after each `check_*` call of a rule, so a fix a rule started but did not report
+    /// is not attached to the next, unrelated, diagnostic of the rule.
+    pub(crate) fn discard_pending_fix(&mut self) {
+        self.pending_fix = None;
+    }
+
     /// Make a new fixer for this context and return a mutable reference to it
+    #[deprecated(
+        note = "this replaces the fixes of the rule and does not attach them to a diagnostic, use `RuleCtx::fixer` instead"
+    )]
     pub fn fix(&mut self) -> &mut Fixer {
         let fixer = Fixer::new(self.src.clone());
         self.fixer = Some(fixer);
         self.fixer.as_mut().unwrap()
     }
 
+    /// Whether a comment is a directive using any of the directive prefixes of the linted store,
+    /// such as `// rslint-ignore`.
+    pub fn is_directive(&self, comment: &Comment) -> bool {
+        is_directive_comment(comment, &self.directive_prefixes)
+    }
+
+    /// Get every node of a specific AST type in a tree, including `root` itself, in preorder.
+    ///
+    /// This is useful for rules which only care about a single kind of node, and would otherwise
+    /// have to use `try_to` in [`CstRule::check_node`], the rule can instead implement [`CstRule::check_root`]:
+    ///
+    /// ```
+    /// use rslint_core::rule_prelude::*;
+    /// use ast::SwitchStmt;
+    ///
+    /// let root = rslint_parser::parse_module("switch (a) {} if (b) { switch (c) {} }", 0).syntax();
+    /// let switches = RuleCtx::matching::<SwitchStmt>(&root).collect::<Vec<_>>();
+    /// assert_eq!(switches.len(), 2);
+    /// ```
+    pub fn matching<N: AstNode>(root: &SyntaxNode) -> Matching<N> {
+        Matching {
+            descendants: Box::new(root.descendants()),
+            _marker: PhantomData,
+        }
+    }
+
     /// Create a context which is used to simply run a rule without needing to know about
     /// the resulting fixer, therefore the ctx's source is not a valid source
     pub(crate) fn dummy_ctx() -> Self {
@@ -172,9 +344,57 @@ impl RuleCtx {
             verbose: false,
             diagnostics: vec![],
             fixer: None,
+            pending_fix: None,
             src: Arc::from(String::new()),
+            trace: None,
+            directive_prefixes: default_directive_prefixes(),
         }
     }
+
+    /// Record a trace entry for every diagnostic added since there were `before` diagnostics,
+    /// this does nothing if tracing is disabled.
+    pub(crate) fn record_trace(
+        &mut self,
+        rule: &dyn CstRule,
+        kind: SyntaxKind,
+        range: TextRange,
+        before: usize,
+    ) {
+        if let Some(trace) = self.trace.as_mut() {
+            let entry = TraceEntry {
+                rule: rule.name(),
+                kind,
+                range,
+            };
+            trace.extend((before..self.diagnostics.len()).map(|_| entry));
+        }
+    }
+}
+
+/// The node or token which caused a rule to emit a diagnostic, recorded when tracing is enabled
+/// to debug why a rule emitted a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceEntry {
+    /// The name of the rule which emitted the diagnostic.
+    pub rule: &'static str,
+    /// The kind of the node or token the rule was checking.
+    pub kind: SyntaxKind,
+    /// The range of the node or token the rule was checking.
+    pub range: TextRange,
+}
+
+/// An iterator over the nodes of a specific AST type in a tree, created by [`RuleCtx::matching`].
+pub struct Matching<N> {
+    descendants: Box<dyn Iterator<Item = SyntaxNode>>,
+    _marker: PhantomData<N>,
+}
+
+impl<N: AstNode> Iterator for Matching<N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<N> {
+        self.descendants.find_map(N::cast)
+    }
 }
 
 /// The result of running a single rule on a syntax tree.
@@ -182,6 +402,12 @@ impl RuleCtx {
 pub struct RuleResult {
     pub diagnostics: Vec<Diagnostic>,
     pub fixer: Option<Fixer>,
+    /// The trace of the diagnostics, empty if tracing is disabled. This includes diagnostics
+    /// which were removed because of suppression comments.
+    pub trace: Vec<TraceEntry>,
+    /// The diagnostics which were replaced by a summary because the rule emitted more diagnostics
+    /// than the store's collapse threshold.
+    pub collapsed: Vec<Diagnostic>,
 }
 
 impl RuleResult {
@@ -190,6 +416,8 @@ impl RuleResult {
         Self {
             diagnostics,
             fixer: fixer.into(),
+            trace: vec![],
+            collapsed: vec![],
         }
     }
 
@@ -204,6 +432,8 @@ impl RuleResult {
         RuleResult {
             diagnostics: [self.diagnostics, other.diagnostics].concat(),
             fixer: self.fixer.or(other.fixer),
+            trace: [self.trace, other.trace].concat(),
+            collapsed: [self.collapsed, other.collapsed].concat(),
         }
     }
 
@@ -350,7 +580,7 @@ macro_rules! __declare_lint_inner {
         #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
         #[derive(Debug, Clone, Deserialize, Serialize)]
         $(#[$outer])*
-        #[serde(rename_all = "camelCase")]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
         pub struct $name {
             $(
                 $(
@@ -442,3 +672,110 @@ macro_rules! declare_lint {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "rule `foo` emitted a label with an invalid range 2..11")]
+    fn add_err_out_of_bounds_label() {
+        let mut ctx = RuleCtx {
+            src: Arc::from("let a = 5;"),
+            ..RuleCtx::dummy_ctx()
+        };
+        let err = ctx.err("foo", "bar").primary(2usize..10, "");
+        ctx.add_err(err);
+        let err = ctx.err("foo", "bar").primary(2usize..11, "");
+        ctx.add_err(err);
+    }
+
+    #[test]
+    fn fixer_attaches_edits_to_diagnostics() {
+        use rslint_errors::SuggestionChange;
+
+        let mut ctx = RuleCtx {
+            src: Arc::from("let a = 5;"),
+            ..RuleCtx::dummy_ctx()
+        };
+        ctx.fixer().replace(0usize..3, "const");
+        let err = ctx.err("foo", "bar").primary(0usize..3, "");
+        ctx.add_err(err);
+
+        // overlaps with the first fix
+        ctx.fixer().delete(2usize..5).insert_after(8usize..9, "0");
+        let err = ctx.err("foo", "baz").primary(2usize..5, "");
+        ctx.add_err(err);
+
+        ctx.fixer().insert_after(8usize..9, "0");
+        let err = ctx.err("foo", "qux").primary(8usize..9, "");
+        ctx.add_err(err);
+
+        let suggestions = ctx
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.suggestions.len())
+            .collect::<Vec<_>>();
+        assert_eq!(suggestions, vec![1, 0, 1]);
+        assert_eq!(ctx.diagnostics[1].footers.len(), 1);
+        assert!(ctx.diagnostics[1].footers[0]
+            .msg
+            .contains("overlaps with another fix"));
+        match &ctx.diagnostics[0].suggestions[0].substitution {
+            SuggestionChange::Indels(indels) => assert_eq!(indels.len(), 1),
+            SuggestionChange::String(_) => panic!("expected the fix to be attached as edits"),
+        }
+
+        let fixer = ctx.fixer.unwrap();
+        assert_eq!(fixer.indels.len(), 2);
+        assert_eq!(fixer.apply(), "const a = 50;");
+    }
+
+    #[test]
+    #[cfg(feature = "schema")]
+    fn config_schema_exposes_options() {
+        use crate::groups::errors::NoDuplicateCases;
+        use crate::groups::style::NoDuplicateString;
+
+        let schema = NoDuplicateString::default().config_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(
+            properties.keys().collect::<Vec<_>>(),
+            vec!["minLength", "threshold"]
+        );
+        assert_eq!(properties["threshold"]["type"], "integer");
+
+        let schema = NoDuplicateCases::default().config_schema();
+        assert_eq!(schema["type"], "object");
+        assert!(schema["properties"]
+            .as_object()
+            .into_iter()
+            .all(|properties| properties.is_empty()));
+    }
+
+    #[test]
+    fn rule_metadata_comes_from_declare_lint() {
+        use crate::groups::errors::NoDuplicateCases;
+
+        let rule = NoDuplicateCases::default();
+        assert_eq!(rule.name(), "no-duplicate-cases");
+        assert_eq!(rule.group(), "errors");
+        assert!(rule.docs().contains("Disallow duplicate test cases"));
+    }
+
+    #[test]
+    fn matching_collects_typed_nodes() {
+        use rslint_parser::ast::SwitchStmt;
+
+        let root = rslint_parser::parse_module(
+            "switch (a) { case 1: switch (b) {} } function f() { switch (c) {} } if (d) {}",
+            0,
+        )
+        .syntax();
+        let discriminants = RuleCtx::matching::<SwitchStmt>(&root)
+            .map(|switch| switch.test().unwrap().syntax().trimmed_text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(discriminants, vec!["(a)", "(b)", "(c)"]);
+    }
+}
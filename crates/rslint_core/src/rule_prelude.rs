@@ -3,14 +3,17 @@
 #[doc(no_inline)]
 pub use crate::{
     autofix::{Fixer, Unwrappable, Wrapping},
-    declare_lint, rule_tests, ts_rule_tests, util, CstRule, Diagnostic, Outcome, RuleCtx,
-    RuleResult, Span,
+    declare_lint, rule_tests, ts_rule_tests, util,
+    util::whitespace::{self, WhitespaceKind, WhitespaceRun},
+    util::{static_eval, StaticValue},
+    CstRule, Diagnostic, Matching, Outcome, RuleCtx, RuleResult, Span,
 };
 
 #[doc(no_inline)]
 pub use rslint_parser::{
-    ast, op, token_set, util as parseutil, util::color, AstNode, AstToken, BigInt, JsNum,
-    SyntaxElement, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken, SyntaxTokenExt, TokenSet, T,
+    ast, op, token_set, util as parseutil, util::color, util::Comment, AstNode, AstToken, BigInt,
+    JsNum, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken, SyntaxTokenExt,
+    TokenSet, T,
 };
 
 #[doc(no_inline)]
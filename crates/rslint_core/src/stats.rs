@@ -0,0 +1,78 @@
+//! Statistics about the shape of a parse tree.
+
+use rslint_parser::{parse_module, parse_text, SyntaxKind, SyntaxNode, WalkEvent};
+
+/// Simple statistics about a parsed file, useful for understanding the complexity of a file
+/// and correlating it with the time it takes to lint it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseStats {
+    /// The amount of nodes in the tree, including the root.
+    pub node_count: usize,
+    /// The deepest level of node nesting, the root is at a depth of `1`.
+    pub max_depth: usize,
+    /// The amount of tokens in the tree, not including whitespace and comments.
+    pub token_count: usize,
+    /// The amount of comment tokens in the tree.
+    pub comment_count: usize,
+}
+
+impl ParseStats {
+    /// Compute the statistics of an already parsed tree in a single traversal.
+    pub fn from_node(root: &SyntaxNode) -> Self {
+        let mut stats = ParseStats::default();
+        let mut depth = 0;
+
+        for event in root.preorder_with_tokens() {
+            match event {
+                WalkEvent::Enter(elem) => match elem.into_token() {
+                    Some(token) if token.kind() == SyntaxKind::COMMENT => stats.comment_count += 1,
+                    Some(token) if token.kind() == SyntaxKind::WHITESPACE => {}
+                    Some(_) => stats.token_count += 1,
+                    None => {
+                        depth += 1;
+                        stats.node_count += 1;
+                        stats.max_depth = stats.max_depth.max(depth);
+                    }
+                },
+                WalkEvent::Leave(elem) => {
+                    if elem.as_node().is_some() {
+                        depth -= 1;
+                    }
+                }
+            }
+        }
+        stats
+    }
+}
+
+/// Parse a source as a module or a script and compute the statistics of its parse tree.
+pub fn parse_stats(source: &str, module: bool) -> ParseStats {
+    let root = if module {
+        parse_module(source, 0).syntax()
+    } else {
+        parse_text(source, 0).syntax()
+    };
+    ParseStats::from_node(&root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stats_counts() {
+        // MODULE > VAR_DECL > DECLARATOR > SINGLE_PATTERN > NAME, and a LITERAL in the declarator
+        let stats = parse_stats("// a\nlet a = 5; /* b */", true);
+        assert_eq!(
+            stats,
+            ParseStats {
+                node_count: 6,
+                max_depth: 5,
+                token_count: 5,
+                comment_count: 2,
+            }
+        );
+
+        assert_eq!(parse_stats("", false).node_count, 1);
+    }
+}
@@ -1,12 +1,92 @@
 //! A rule store, which houses rule groups as well as individual rules.
 
+use crate::directives::DEFAULT_PREFIX;
 use crate::groups::*;
-use crate::CstRule;
+use crate::{util, CstRule, RuleLevel};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Rule names which were renamed or replaced, and the name of the rule which replaces them.
+pub const DEPRECATED_RULE_NAMES: &[(&str, &str)] = &[
+    ("no-negated-in-lhs", "no-unsafe-negation"),
+    ("require_yield", "require-yield"),
+];
+
+/// The name of the rule which replaces a deprecated rule name, or the name itself if it is not deprecated.
+pub(crate) fn current_rule_name(name: &str) -> &str {
+    DEPRECATED_RULE_NAMES
+        .iter()
+        .find(|(old, _)| *old == name)
+        .map_or(name, |(_, replacement)| replacement)
+}
+
+/// An issue with a rule name requested by something like a config.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NameIssue {
+    /// The rule does not exist, with an optional suggestion for a similarly named rule.
+    Unknown {
+        name: String,
+        suggestion: Option<&'static str>,
+    },
+    /// The rule was renamed or replaced by another rule.
+    Deprecated {
+        name: String,
+        replacement: &'static str,
+    },
+}
+
+/// An error which occurred while configuring the options of a rule in a store.
+#[derive(Debug)]
+pub enum ConfigureError {
+    /// The rule is not in the store.
+    UnknownRule(NameIssue),
+    /// The options do not match the options the rule expects.
+    InvalidOptions {
+        rule: String,
+        error: serde_json::Error,
+    },
+}
+
+impl fmt::Display for ConfigureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigureError::UnknownRule(NameIssue::Unknown {
+                name,
+                suggestion: Some(suggestion),
+            }) => write!(f, "unknown rule `{}`, did you mean `{}`?", name, suggestion),
+            ConfigureError::UnknownRule(NameIssue::Unknown { name, .. }) => {
+                write!(f, "unknown rule `{}`", name)
+            }
+            ConfigureError::UnknownRule(NameIssue::Deprecated { name, replacement }) => {
+                write!(f, "the rule `{}` was replaced by `{}`", name, replacement)
+            }
+            ConfigureError::InvalidOptions { rule, error } => {
+                write!(f, "invalid options for `{}`: {}", rule, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigureError::InvalidOptions { error, .. } => Some(error),
+            ConfigureError::UnknownRule(_) => None,
+        }
+    }
+}
 
 /// A utility structure for housing CST rules for a linting run.
 #[derive(Debug, Default, Clone)]
 pub struct CstRuleStore {
     pub rules: Vec<Box<dyn CstRule>>,
+    /// Prefixes of comment directives which are honored in addition to `rslint`.
+    extra_directive_prefixes: Vec<String>,
+    /// The levels of rules set with [`CstRuleStore::set_level`].
+    levels: HashMap<String, RuleLevel>,
+    /// The amount of diagnostics a rule may emit in a file before the rest are collapsed.
+    collapse_threshold: Option<usize>,
 }
 
 impl CstRuleStore {
@@ -16,23 +96,79 @@ impl CstRuleStore {
 
     /// All built in rules from every group.
     pub fn builtins(mut self) -> Self {
-        self.rules.extend(errors());
-        self.rules.extend(style());
-        self.rules.extend(regex());
+        for (_, rules) in BUILTIN_GROUPS {
+            self.rules.extend(rules());
+        }
         self
     }
 
     /// All recommended rules from every group.
     pub fn recommended(mut self) -> Self {
-        self.rules
-            .extend(errors().into_iter().filter(|x| x.recommended()));
-        self.rules
-            .extend(style().into_iter().filter(|x| x.recommended()));
-        self.rules
-            .extend(regex().into_iter().filter(|x| x.recommended()));
+        for (_, rules) in BUILTIN_GROUPS {
+            self.rules
+                .extend(rules().into_iter().filter(|x| x.recommended()));
+        }
+        self
+    }
+
+    /// Also honor comment directives which start with `prefix`, for example `eslint` makes
+    /// `// eslint-ignore no-empty` behave like `// rslint-ignore no-empty`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslint_core::CstRuleStore;
+    ///
+    /// let store = CstRuleStore::new().with_directive_prefix("eslint");
+    /// assert_eq!(store.directive_prefixes(), vec!["rslint", "eslint"]);
+    /// ```
+    pub fn with_directive_prefix(mut self, prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        if prefix != DEFAULT_PREFIX && !self.extra_directive_prefixes.contains(&prefix) {
+            self.extra_directive_prefixes.push(prefix);
+        }
         self
     }
 
+    /// The prefixes of comment directives honored by this store, `rslint` is always the first one.
+    pub fn directive_prefixes(&self) -> Vec<&str> {
+        std::iter::once(DEFAULT_PREFIX)
+            .chain(self.extra_directive_prefixes.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Collapse the diagnostics of a rule which emits more than `threshold` diagnostics in a file,
+    /// the diagnostics past the threshold are replaced with a single diagnostic which summarizes them.
+    ///
+    /// This reduces the noise from a single rule dominating the output, fixes are still applied for
+    /// every diagnostic.
+    pub fn with_collapse_threshold(mut self, threshold: usize) -> Self {
+        self.collapse_threshold = Some(threshold);
+        self
+    }
+
+    /// The threshold set with [`CstRuleStore::with_collapse_threshold`], if any.
+    pub fn collapse_threshold(&self) -> Option<usize> {
+        self.collapse_threshold
+    }
+
+    /// Override the level of a rule, rules set to [`RuleLevel::Off`] are not run, and errors of rules
+    /// set to [`RuleLevel::Warning`] are reported as warnings.
+    pub fn set_level(&mut self, rule_name: impl Into<String>, level: RuleLevel) {
+        self.levels.insert(rule_name.into(), level);
+    }
+
+    /// The level of a rule set with [`CstRuleStore::set_level`], if any.
+    pub fn level(&self, rule_name: &str) -> Option<RuleLevel> {
+        self.levels.get(rule_name).copied()
+    }
+
+    /// Remove every rule set to [`RuleLevel::Off`] from this store, so they are never run.
+    pub(crate) fn remove_rules_turned_off(&mut self) {
+        let levels = &self.levels;
+        self.rules
+            .retain(|rule| levels.get(rule.name()) != Some(&RuleLevel::Off));
+    }
+
     /// Load a list of rules into this store.
     pub fn load_rules(&mut self, rules: impl IntoIterator<Item = Box<dyn CstRule>>) {
         self.rules.extend(rules);
@@ -52,4 +188,236 @@ impl CstRuleStore {
             .find(|rule| rule.name() == rule_name.as_ref())
             .cloned()
     }
+
+    /// Replace the options of a rule in this store, `options` is an object of the rule's options,
+    /// such as `{ "checkLoops": false }` for `no-constant-condition`.
+    ///
+    /// Options which are left out use their default value.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslint_core::CstRuleStore;
+    /// use serde_json::json;
+    ///
+    /// let mut store = CstRuleStore::new().builtins();
+    /// assert!(store.configure("no-constant-condition", json!({ "checkLoops": false })).is_ok());
+    /// assert!(store.configure("no-constant-condition", json!({ "checkLoops": 5 })).is_err());
+    /// assert!(store.configure("no-constant-condition", json!({ "chekLoops": false })).is_err());
+    /// ```
+    pub fn configure(&mut self, rule_name: &str, options: Value) -> Result<(), ConfigureError> {
+        let idx = self
+            .rules
+            .iter()
+            .position(|rule| rule.name() == rule_name)
+            .ok_or_else(|| {
+                ConfigureError::UnknownRule(self.validate_names(&[rule_name]).remove(0))
+            })?;
+
+        let invalid = |error| ConfigureError::InvalidOptions {
+            rule: rule_name.to_string(),
+            error,
+        };
+        // rules are externally tagged with the name of their struct, which may not match the rule name
+        let tag = match serde_json::to_value(&self.rules[idx]).map_err(invalid)? {
+            Value::Object(object) => object.into_iter().next().map(|(tag, _)| tag),
+            _ => None,
+        }
+        .expect("rules are serialized as an object with their struct name as the only key");
+        self.rules[idx] = serde_json::from_value(json!({ tag: options })).map_err(invalid)?;
+        Ok(())
+    }
+
+    /// Check a list of rule names against the rules in this store and report any
+    /// names which are unknown or deprecated.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslint_core::{CstRuleStore, NameIssue};
+    ///
+    /// let issues = CstRuleStore::new().builtins().validate_names(&["no-empty", "require_yield"]);
+    /// assert_eq!(
+    ///     issues,
+    ///     vec![NameIssue::Deprecated {
+    ///         name: "require_yield".to_string(),
+    ///         replacement: "require-yield"
+    ///     }]
+    /// );
+    /// ```
+    pub fn validate_names(&self, requested: &[&str]) -> Vec<NameIssue> {
+        requested
+            .iter()
+            .filter(|name| self.get(name).is_none())
+            .map(|name| {
+                if let Some((_, replacement)) =
+                    DEPRECATED_RULE_NAMES.iter().find(|(old, _)| old == name)
+                {
+                    NameIssue::Deprecated {
+                        name: name.to_string(),
+                        replacement,
+                    }
+                } else {
+                    NameIssue::Unknown {
+                        name: name.to_string(),
+                        suggestion: util::find_best_match_for_name(
+                            self.rules.iter().map(|rule| rule.name()),
+                            name,
+                            None,
+                        ),
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod custom_rule {
+        use crate::rule_prelude::*;
+
+        declare_lint! {
+            /**
+            A rule whose struct name is not the pascal case rule name.
+            */
+            #[derive(Default)]
+            CustomName,
+            tests,
+            "custom-rule",
+            pub strict: bool
+        }
+
+        #[typetag::serde]
+        impl CstRule for CustomName {}
+    }
+
+    #[test]
+    fn validate_names_reports_unknown_and_deprecated() {
+        let store = CstRuleStore::new().builtins();
+        let issues = store.validate_names(&["no-empty", "no-emty", "no-negated-in-lhs"]);
+
+        assert_eq!(
+            issues,
+            vec![
+                NameIssue::Unknown {
+                    name: "no-emty".to_string(),
+                    suggestion: Some("no-empty"),
+                },
+                NameIssue::Deprecated {
+                    name: "no-negated-in-lhs".to_string(),
+                    replacement: "no-unsafe-negation",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn deprecated_names_are_replaced_by_builtin_rules() {
+        let store = CstRuleStore::new().builtins();
+        for (old, replacement) in DEPRECATED_RULE_NAMES {
+            assert!(store.get(old).is_none(), "`{}` is still a rule", old);
+            assert!(
+                store.get(replacement).is_some(),
+                "`{}` is not a rule",
+                replacement
+            );
+            assert_eq!(
+                crate::get_rule_by_name(old).map(|rule| rule.name()),
+                Some(*replacement)
+            );
+        }
+
+        let deprecated = |name: &str, replacement| NameIssue::Deprecated {
+            name: name.to_string(),
+            replacement,
+        };
+        assert_eq!(
+            store.validate_names(&["no-negated-in-lhs"]),
+            vec![deprecated("no-negated-in-lhs", "no-unsafe-negation")]
+        );
+        assert_eq!(
+            store.validate_names(&["require_yield"]),
+            vec![deprecated("require_yield", "require-yield")]
+        );
+    }
+
+    #[test]
+    fn configure_changes_rule_options() {
+        use crate::groups::errors::NoConstantCondition;
+        use crate::{lint_file, File};
+        use rslint_parser::FileKind;
+
+        let file = File::from_string("while (true) {}", FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoConstantCondition::default()) as Box<dyn CstRule>
+        ]);
+        assert_eq!(lint_file(&file, &store, false).diagnostics().count(), 1);
+
+        store
+            .configure("no-constant-condition", json!({ "checkLoops": false }))
+            .unwrap();
+        assert_eq!(lint_file(&file, &store, false).diagnostics().count(), 0);
+
+        let err = store
+            .configure("no-constant-condition", json!({ "checkLoops": "no" }))
+            .unwrap_err();
+        assert!(matches!(err, ConfigureError::InvalidOptions { .. }));
+        let err = store
+            .configure("no-constant-condition", json!({ "chekLoops": false }))
+            .unwrap_err();
+        assert!(matches!(err, ConfigureError::InvalidOptions { .. }));
+        assert!(matches!(
+            store.configure("no-empty", json!({})),
+            Err(ConfigureError::UnknownRule(NameIssue::Unknown { .. }))
+        ));
+    }
+
+    #[test]
+    fn configure_custom_rules() {
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(custom_rule::CustomName::new()) as Box<dyn CstRule>
+        ]);
+        store
+            .configure("custom-rule", json!({ "strict": true }))
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&store.get("custom-rule").unwrap()).unwrap(),
+            json!({ "CustomName": { "strict": true } })
+        );
+    }
+
+    #[test]
+    fn set_level_turns_off_and_downgrades_rules() {
+        use crate::groups::errors::{NoDebugger, NoEmpty};
+        use crate::{lint_file, File};
+        use rslint_errors::Severity;
+        use rslint_parser::FileKind;
+
+        let file = File::from_string("{}\ndebugger;", FileKind::Module, "test.mjs");
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoEmpty::default()) as Box<dyn CstRule>,
+            Box::new(NoDebugger::default()),
+        ]);
+        store.set_level("no-empty", RuleLevel::Off);
+        store.set_level("no-debugger", RuleLevel::Warning);
+
+        let result = lint_file(&file, &store, false);
+        assert!(!result.rule_results.contains_key("no-empty"));
+        let diagnostics = result.diagnostics().collect::<Vec<_>>();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code.as_deref(), Some("no-debugger"));
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+
+        store.set_level("no-empty", RuleLevel::Error);
+        store.set_level("no-debugger", RuleLevel::Error);
+        let result = lint_file(&file, &store, false);
+        assert_eq!(result.diagnostics().count(), 2);
+        assert!(result
+            .diagnostics()
+            .all(|diagnostic| diagnostic.severity == Severity::Error));
+    }
 }
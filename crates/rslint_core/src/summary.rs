@@ -0,0 +1,147 @@
+//! Aggregated counts of the diagnostics emitted while linting one or more files.
+
+use crate::{LintResult, Severity};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The amount of diagnostics emitted while linting a single file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// The amount of errors, including parser and directive errors.
+    pub errors: usize,
+    /// The amount of warnings, including parser and directive warnings.
+    pub warnings: usize,
+    /// The amount of diagnostics of any severity emitted by each rule, rules without diagnostics are not included.
+    pub rule_counts: BTreeMap<&'static str, usize>,
+}
+
+impl Summary {
+    /// The total amount of errors and warnings.
+    pub fn problems(&self) -> usize {
+        self.errors + self.warnings
+    }
+}
+
+impl LintResult<'_> {
+    /// Count the diagnostics emitted while linting this file.
+    pub fn summary(&self) -> Summary {
+        let mut summary = Summary::default();
+        for diagnostic in self.diagnostics() {
+            match diagnostic.severity {
+                Severity::Bug | Severity::Error => summary.errors += 1,
+                Severity::Warning => summary.warnings += 1,
+                _ => {}
+            }
+        }
+        for (name, result) in &self.rule_results {
+            if !result.diagnostics.is_empty() {
+                summary.rule_counts.insert(name, result.diagnostics.len());
+            }
+        }
+        summary
+    }
+}
+
+/// The amount of diagnostics emitted while linting a batch of files.
+///
+/// The [`Display`](fmt::Display) implementation renders a short overview for command line output,
+/// such as `42 problems across 10 files (most frequent: no-empty ×15)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// The total amount of errors across all files.
+    pub errors: usize,
+    /// The total amount of warnings across all files.
+    pub warnings: usize,
+    /// The amount of diagnostics emitted by each rule across all files.
+    pub rule_counts: BTreeMap<&'static str, usize>,
+    /// The amount of problems in each file by file id, files without problems are not included.
+    pub file_counts: BTreeMap<usize, usize>,
+}
+
+impl BatchSummary {
+    /// The total amount of errors and warnings across all files.
+    pub fn problems(&self) -> usize {
+        self.errors + self.warnings
+    }
+
+    /// The rule which emitted the most diagnostics and the amount it emitted, ties are broken by the rule name.
+    pub fn most_frequent_rule(&self) -> Option<(&'static str, usize)> {
+        self.rule_counts
+            .iter()
+            .max_by_key(|(name, count)| (**count, Reverse(**name)))
+            .map(|(name, count)| (*name, *count))
+    }
+}
+
+impl fmt::Display for BatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let problems = self.problems();
+        let files = self.file_counts.len();
+        write!(
+            f,
+            "{} problem{} across {} file{}",
+            problems,
+            if problems == 1 { "" } else { "s" },
+            files,
+            if files == 1 { "" } else { "s" }
+        )?;
+        if let Some((name, count)) = self.most_frequent_rule() {
+            write!(f, " (most frequent: {} ×{})", name, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Aggregate the diagnostics of many lint results into a single summary.
+pub fn batch_summary(results: &[LintResult]) -> BatchSummary {
+    let mut batch = BatchSummary::default();
+    for result in results {
+        let summary = result.summary();
+        if summary.problems() > 0 {
+            *batch.file_counts.entry(result.file_id).or_insert(0) += summary.problems();
+        }
+        batch.errors += summary.errors;
+        batch.warnings += summary.warnings;
+        for (name, count) in summary.rule_counts {
+            *batch.rule_counts.entry(name).or_insert(0) += count;
+        }
+    }
+    batch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::{NoDebugger, NoEmpty};
+    use crate::{lint_files, CstRule, CstRuleStore, File};
+    use rslint_parser::FileKind;
+
+    #[test]
+    fn batch_summary_aggregates_results() {
+        let files = vec![
+            File::from_string("{}\nif (a) {}\ndebugger;", FileKind::Module, "a.mjs"),
+            File::from_string("{}\nfoo;", FileKind::Module, "b.mjs"),
+            File::from_string("foo;", FileKind::Module, "c.mjs"),
+        ];
+        let mut store = CstRuleStore::new();
+        store.load_rules(vec![
+            Box::new(NoEmpty::default()) as Box<dyn CstRule>,
+            Box::new(NoDebugger::default()),
+        ]);
+        let results = lint_files(&files, &store, false);
+        let summary = batch_summary(&results);
+
+        assert_eq!(summary.problems(), 4);
+        assert_eq!(summary.rule_counts.get("no-empty"), Some(&3));
+        assert_eq!(summary.rule_counts.get("no-debugger"), Some(&1));
+        assert_eq!(summary.file_counts.len(), 2);
+        assert_eq!(summary.file_counts.get(&files[0].id), Some(&3));
+        assert_eq!(summary.most_frequent_rule(), Some(("no-empty", 3)));
+        assert_eq!(
+            summary.to_string(),
+            "4 problems across 2 files (most frequent: no-empty ×3)"
+        );
+        assert_eq!(batch_summary(&[]).to_string(), "0 problems across 0 files");
+    }
+}
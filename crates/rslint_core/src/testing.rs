@@ -1,4 +1,9 @@
-//! Macros for easily making rule tests which also generate documentation examples.
+//! Macros and helpers for easily making rule tests, the macros also generate documentation examples.
+
+use crate::groups::group_rules;
+use crate::{run_rule, CstRule};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// A macro for generating linter rule tests.
 ///
@@ -139,3 +144,191 @@ macro_rules! ts_rule_tests {
         }
     };
 }
+
+/// Assert that the metadata of a rule is well formed, this is useful for checking custom rules in tests.
+///
+/// This checks that:
+/// - The name of the rule is not empty and is kebab-case, such as `no-empty`.
+/// - The group of the rule is a lowercase name such as `errors`, and if the group is registered
+///   (see [`register_group`](crate::register_group)), that it contains a rule with the same name.
+/// - The documentation of the rule is well formed markdown, meaning code fences are closed
+///   and headings have text.
+///
+/// # Panics
+///
+/// Panics with a message describing the issue if any of the checks fail.
+pub fn assert_rule_has_metadata(rule: &dyn CstRule) {
+    let name = rule.name();
+    assert!(
+        is_kebab_case(name),
+        "rule name `{}` is not kebab-case and non-empty",
+        name
+    );
+
+    let group = rule.group();
+    assert!(
+        !group.is_empty()
+            && group
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+        "group `{}` of rule `{}` is not a lowercase name",
+        group,
+        name
+    );
+    if let Some(rules) = group_rules(group) {
+        assert!(
+            rules.iter().any(|other| other.name() == name),
+            "rule `{}` is not in its registered group `{}`",
+            name,
+            group
+        );
+    }
+
+    if let Err(msg) = check_markdown(rule.docs()) {
+        panic!("documentation of rule `{}` is malformed: {}", name, msg);
+    }
+}
+
+/// The amount of times [`assert_rule_under`] runs a rule.
+const TIMING_ITERATIONS: usize = 5;
+
+/// Assert that running a rule on `source` takes less than `max`, this is useful for guarding
+/// rules against accidental performance regressions, such as quadratic loops over large inputs.
+///
+/// The source is parsed once as a module, then the rule is run several times on it, and the
+/// fastest run is compared to `max` so other work on the machine does not cause spurious failures.
+///
+/// # Panics
+///
+/// Panics if the fastest run of the rule took `max` or longer.
+pub fn assert_rule_under(rule: &dyn CstRule, source: &str, max: Duration) {
+    let root = rslint_parser::parse_module(source, 0).syntax();
+    let src: Arc<str> = Arc::from(source);
+    let fastest = (0..TIMING_ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            run_rule(rule, 0, root.clone(), false, &[], src.clone());
+            start.elapsed()
+        })
+        .min()
+        .unwrap();
+
+    assert!(
+        fastest < max,
+        "rule `{}` took {:?} on a source of {} bytes, which is over the limit of {:?}",
+        rule.name(),
+        fastest,
+        source.len(),
+        max
+    );
+}
+
+fn is_kebab_case(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+fn check_markdown(docs: &str) -> Result<(), String> {
+    let mut open_fence: Option<(usize, &str)> = None;
+    for (idx, line) in docs.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some((_, fence)) = open_fence {
+            if trimmed.starts_with(fence) && trimmed.trim_start_matches(fence).is_empty() {
+                open_fence = None;
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            open_fence = Some((idx + 1, &trimmed[..3]));
+        } else if trimmed.starts_with('#') {
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            let text = &trimmed[level..];
+            if level > 6 || !text.starts_with(' ') || text.trim().is_empty() {
+                return Err(format!("invalid heading on line {}", idx + 1));
+            }
+        }
+    }
+
+    match open_fence {
+        Some((line, _)) => Err(format!("unclosed code block starting on line {}", line)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::errors::NoDuplicateCases;
+    use crate::CstRuleStore;
+
+    #[test]
+    fn builtin_rules_have_metadata() {
+        for rule in CstRuleStore::new().builtins().rules {
+            assert_rule_has_metadata(&*rule);
+        }
+    }
+
+    fn large_switch() -> String {
+        let cases = (0..5000)
+            .map(|idx| format!("case foo.bar({}): break;", idx))
+            .collect::<String>();
+        format!("switch (a) {{ {} case foo . bar ( 42 ): break; }}", cases)
+    }
+
+    #[test]
+    #[ignore = "wall clock timings depend on the machine running the tests"]
+    fn no_duplicate_cases_is_fast_on_large_switches() {
+        assert_rule_under(
+            &NoDuplicateCases::default(),
+            &large_switch(),
+            Duration::from_secs(2),
+        );
+    }
+
+    #[test]
+    fn no_duplicate_cases_on_large_switches() {
+        let source = large_switch();
+        let root = rslint_parser::parse_module(&source, 0).syntax();
+        let result = run_rule(
+            &NoDuplicateCases::default(),
+            0,
+            root,
+            false,
+            &[],
+            Arc::from(source.as_str()),
+        );
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            result.diagnostics[0].title,
+            "duplicate switch statement test `foo.bar(42)`"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "over the limit")]
+    fn assert_rule_under_fails_on_slow_rules() {
+        assert_rule_under(
+            &NoDuplicateCases::default(),
+            "switch (a) {}",
+            Duration::ZERO,
+        );
+    }
+
+    #[test]
+    fn malformed_markdown() {
+        assert!(check_markdown("foo\n\n## Bar\n\n```js\nfoo;\n```").is_ok());
+        assert!(check_markdown("```js\n# not a heading\n```").is_ok());
+        assert!(check_markdown("```js\nfoo;").is_err());
+        assert!(check_markdown("##Foo").is_err());
+        assert!(check_markdown("## ").is_err());
+        assert!(!is_kebab_case("no-Empty"));
+        assert!(!is_kebab_case("no--empty"));
+        assert!(is_kebab_case("no-empty2"));
+    }
+}
@@ -185,6 +185,209 @@ fn short_circuits(expr: Expr, op: BinOp) -> bool {
     }
 }
 
+/// The value of an expression which can be determined statically, see [`static_eval`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticValue {
+    Bool(bool),
+    Number(f64),
+    String(std::string::String),
+    Null,
+    Undefined,
+    /// An object, array, function, class, or regex, whose exact value is unknown but which is always truthy.
+    Object,
+}
+
+impl StaticValue {
+    /// Whether the value is truthy when coerced to a boolean.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            StaticValue::Bool(val) => *val,
+            StaticValue::Number(num) => *num != 0.0 && !num.is_nan(),
+            StaticValue::String(string) => !string.is_empty(),
+            StaticValue::Null | StaticValue::Undefined => false,
+            StaticValue::Object => true,
+        }
+    }
+
+    /// Whether the value is `null` or `undefined`.
+    pub fn is_nullish(&self) -> bool {
+        matches!(self, StaticValue::Null | StaticValue::Undefined)
+    }
+
+    /// The result of `typeof` for this value.
+    pub fn type_of(&self) -> Option<&'static str> {
+        Some(match self {
+            StaticValue::Bool(_) => "boolean",
+            StaticValue::Number(_) => "number",
+            StaticValue::String(_) => "string",
+            StaticValue::Null => "object",
+            StaticValue::Undefined => "undefined",
+            // could be a function
+            StaticValue::Object => return None,
+        })
+    }
+
+    /// Coerce the value to a number, `None` for strings and objects whose conversion is not implemented.
+    fn to_number(&self) -> Option<f64> {
+        match self {
+            StaticValue::Bool(val) => Some(if *val { 1.0 } else { 0.0 }),
+            StaticValue::Number(num) => Some(*num),
+            StaticValue::Null => Some(0.0),
+            StaticValue::Undefined => Some(f64::NAN),
+            StaticValue::String(_) | StaticValue::Object => None,
+        }
+    }
+}
+
+/// Attempt to statically evaluate an expression.
+///
+/// This handles literals, templates without substitutions, `undefined`, `NaN`, `Infinity`, unary operators,
+/// short circuiting logical operators, arithmetic on numbers, strict equality, and conditional expressions.
+/// Returns `None` if the node is not an expression or if its value is unknown.
+pub fn static_eval(expr: &SyntaxNode) -> Option<StaticValue> {
+    eval_expr(expr.try_to::<Expr>()?)
+}
+
+fn eval_expr(expr: Expr) -> Option<StaticValue> {
+    Some(match expr {
+        Expr::Literal(lit) => match lit.kind() {
+            LiteralKind::Bool(val) => StaticValue::Bool(val),
+            LiteralKind::Null => StaticValue::Null,
+            LiteralKind::Number(num) => StaticValue::Number(num),
+            LiteralKind::String => {
+                let text = lit.inner_string_text()?.to_string();
+                // escapes are not resolved
+                if text.contains('\\') {
+                    return None;
+                }
+                StaticValue::String(text)
+            }
+            LiteralKind::Regex => StaticValue::Object,
+            LiteralKind::BigInt(_) => return None,
+        },
+        Expr::Template(tpl) => {
+            if tpl.tag().is_some() || tpl.elements().next().is_some() {
+                return None;
+            }
+            let text = tpl
+                .quasis()
+                .map(|quasi| quasi.text().to_string())
+                .collect::<std::string::String>();
+            if text.contains('\\') {
+                return None;
+            }
+            StaticValue::String(text)
+        }
+        Expr::NameRef(name) => match name.ident_token()?.text().as_str() {
+            "undefined" => StaticValue::Undefined,
+            "NaN" => StaticValue::Number(f64::NAN),
+            "Infinity" => StaticValue::Number(f64::INFINITY),
+            _ => return None,
+        },
+        Expr::ObjectExpr(_)
+        | Expr::ArrayExpr(_)
+        | Expr::FnExpr(_)
+        | Expr::ArrowExpr(_)
+        | Expr::ClassExpr(_) => StaticValue::Object,
+        Expr::GroupingExpr(group) => eval_expr(group.inner()?)?,
+        Expr::SequenceExpr(seq) => eval_expr(seq.exprs().last()?)?,
+        Expr::AssignExpr(assign) if assign.op() == Some(AssignOp::Assign) => {
+            eval_expr(assign.rhs()?)?
+        }
+        Expr::CondExpr(cond) => {
+            if eval_expr(cond.test()?)?.is_truthy() {
+                eval_expr(cond.cons()?)?
+            } else {
+                eval_expr(cond.alt()?)?
+            }
+        }
+        Expr::UnaryExpr(unary) => {
+            let op = unary.op()?;
+            // the operand of `void` may have side effects, but the result is always undefined
+            if op == UnaryOp::Void {
+                return Some(StaticValue::Undefined);
+            }
+            let value = eval_expr(unary.expr()?)?;
+            match op {
+                UnaryOp::LogicalNot => StaticValue::Bool(!value.is_truthy()),
+                UnaryOp::Minus => StaticValue::Number(-value.to_number()?),
+                UnaryOp::Plus => StaticValue::Number(value.to_number()?),
+                UnaryOp::Typeof => StaticValue::String(value.type_of()?.to_string()),
+                _ => return None,
+            }
+        }
+        Expr::BinExpr(bin) => {
+            let op = bin.op()?;
+            let lhs = eval_expr(bin.lhs()?);
+            // a logical expression is known if its left side short circuits, even if the right side is unknown
+            match op {
+                BinOp::LogicalAnd => {
+                    let lhs = lhs?;
+                    return if lhs.is_truthy() {
+                        eval_expr(bin.rhs()?)
+                    } else {
+                        Some(lhs)
+                    };
+                }
+                BinOp::LogicalOr => {
+                    let lhs = lhs?;
+                    return if lhs.is_truthy() {
+                        Some(lhs)
+                    } else {
+                        eval_expr(bin.rhs()?)
+                    };
+                }
+                BinOp::NullishCoalescing => {
+                    let lhs = lhs?;
+                    return if lhs.is_nullish() {
+                        eval_expr(bin.rhs()?)
+                    } else {
+                        Some(lhs)
+                    };
+                }
+                _ => {}
+            }
+            eval_binary(op, lhs?, eval_expr(bin.rhs()?)?)?
+        }
+        _ => return None,
+    })
+}
+
+fn eval_binary(op: BinOp, lhs: StaticValue, rhs: StaticValue) -> Option<StaticValue> {
+    if let (StaticValue::String(lhs), StaticValue::String(rhs)) = (&lhs, &rhs) {
+        return match op {
+            BinOp::Plus => Some(StaticValue::String(format!("{}{}", lhs, rhs))),
+            BinOp::StrictEquality => Some(StaticValue::Bool(lhs == rhs)),
+            BinOp::StrictInequality => Some(StaticValue::Bool(lhs != rhs)),
+            _ => None,
+        };
+    }
+    if lhs == StaticValue::Object || rhs == StaticValue::Object {
+        return None;
+    }
+    match op {
+        // nan is not equal to itself, which `PartialEq` for `f64` already handles
+        BinOp::StrictEquality => return Some(StaticValue::Bool(lhs == rhs)),
+        BinOp::StrictInequality => return Some(StaticValue::Bool(lhs != rhs)),
+        _ => {}
+    }
+
+    let (lhs, rhs) = (lhs.to_number()?, rhs.to_number()?);
+    Some(match op {
+        BinOp::Plus => StaticValue::Number(lhs + rhs),
+        BinOp::Minus => StaticValue::Number(lhs - rhs),
+        BinOp::Times => StaticValue::Number(lhs * rhs),
+        BinOp::Divide => StaticValue::Number(lhs / rhs),
+        BinOp::Remainder => StaticValue::Number(lhs % rhs),
+        BinOp::Exponent => StaticValue::Number(lhs.powf(rhs)),
+        BinOp::LessThan => StaticValue::Bool(lhs < rhs),
+        BinOp::GreaterThan => StaticValue::Bool(lhs > rhs),
+        BinOp::LessThanOrEqual => StaticValue::Bool(lhs <= rhs),
+        BinOp::GreaterThanOrEqual => StaticValue::Bool(lhs >= rhs),
+        _ => return None,
+    })
+}
+
 /// Issue more context around the effects of a constant condition on a node.
 ///
 /// For example, if the statement is an if statement and the condition value is false,
@@ -331,3 +534,57 @@ pub fn simple_const_condition_context(
     }
     diagnostic
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::parse_expr;
+
+    fn eval(code: &str) -> Option<StaticValue> {
+        static_eval(&parse_expr(code, 0).syntax())
+    }
+
+    #[test]
+    fn static_eval_literals() {
+        assert_eq!(eval("true"), Some(StaticValue::Bool(true)));
+        assert_eq!(eval("5"), Some(StaticValue::Number(5.0)));
+        assert_eq!(eval("'foo'"), Some(StaticValue::String("foo".into())));
+        assert_eq!(eval("`foo`"), Some(StaticValue::String("foo".into())));
+        assert_eq!(eval("null"), Some(StaticValue::Null));
+        assert_eq!(eval("undefined"), Some(StaticValue::Undefined));
+        assert_eq!(eval("/a/"), Some(StaticValue::Object));
+        assert_eq!(eval("[]"), Some(StaticValue::Object));
+        assert_eq!(eval("`${a}`"), None);
+        assert_eq!(eval("foo"), None);
+    }
+
+    #[test]
+    fn static_eval_operators() {
+        assert_eq!(eval("!0"), Some(StaticValue::Bool(true)));
+        assert_eq!(eval("!!''"), Some(StaticValue::Bool(false)));
+        assert_eq!(eval("!{}"), Some(StaticValue::Bool(false)));
+        assert_eq!(eval("-(1 + 2)"), Some(StaticValue::Number(-3.0)));
+        assert_eq!(eval("void foo()"), Some(StaticValue::Undefined));
+        assert_eq!(
+            eval("typeof 'a'"),
+            Some(StaticValue::String("string".into()))
+        );
+        assert_eq!(eval("'a' + 'b'"), Some(StaticValue::String("ab".into())));
+        assert_eq!(eval("1 === 1"), Some(StaticValue::Bool(true)));
+        assert_eq!(eval("NaN === NaN"), Some(StaticValue::Bool(false)));
+        assert_eq!(eval("a ? 1 : 2"), None);
+        assert_eq!(eval("true ? 1 : a"), Some(StaticValue::Number(1.0)));
+        assert_eq!(eval("!a"), None);
+    }
+
+    #[test]
+    fn static_eval_short_circuits() {
+        assert_eq!(eval("false && a"), Some(StaticValue::Bool(false)));
+        assert_eq!(eval("1 || a"), Some(StaticValue::Number(1.0)));
+        assert_eq!(eval("null ?? 'a'"), Some(StaticValue::String("a".into())));
+        assert_eq!(eval("0 ?? a"), Some(StaticValue::Number(0.0)));
+        assert_eq!(eval("true && 2"), Some(StaticValue::Number(2.0)));
+        assert_eq!(eval("true && a"), None);
+        assert_eq!(eval("a || true"), None);
+    }
+}
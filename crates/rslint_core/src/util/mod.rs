@@ -2,6 +2,7 @@
 
 mod const_exprs;
 mod style;
+pub mod whitespace;
 
 pub use const_exprs::*;
 pub use style::*;
@@ -9,10 +10,10 @@ pub use style::*;
 use crate::rule_prelude::*;
 use ast::*;
 use rslint_parser::TextRange;
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::cmp;
 use std::cmp::{Eq, Ord, Reverse};
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
 use std::hash::Hash;
 use SyntaxKind::*;
 
@@ -158,6 +159,36 @@ pub fn levenshtein_distance(a: &str, b: &str) -> usize {
     dcol[t_last + 1]
 }
 
+/// Convert a kebab-case rule name to the PascalCase name of its type, e.g. `no-empty` -> `NoEmpty`.
+pub fn kebab_to_pascal(name: &str) -> std::string::String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| {
+                    first
+                        .to_uppercase()
+                        .chain(chars)
+                        .collect::<std::string::String>()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Convert the PascalCase name of a rule type to its kebab-case name, e.g. `NoEmpty` -> `no-empty`.
+pub fn pascal_to_kebab(name: &str) -> std::string::String {
+    let mut kebab = std::string::String::with_capacity(name.len() + 4);
+    for (idx, c) in name.chars().enumerate() {
+        if c.is_uppercase() && idx != 0 {
+            kebab.push('-');
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
 /// Find the best match for a string in an iterator of strings based on levenshtein distance.
 ///
 /// This considers a case insensitive match and the levenshtein distance with a cutoff.
@@ -248,3 +279,216 @@ pub fn outer_function(node: impl Borrow<SyntaxNode>) -> Option<SyntaxNode> {
         .skip(1)
         .find(|ancestor| matches!(ancestor.kind(), ARROW_EXPR | FN_DECL | FN_EXPR))
 }
+
+/// Check if an expression is `parseInt` or `Number.parseInt`, e.g. the callee of `parseInt("10")`.
+pub fn is_parse_int(callee: &Expr) -> bool {
+    match callee {
+        Expr::NameRef(name) => name.syntax().text() == "parseInt",
+        Expr::DotExpr(dot) => {
+            dot.prop().map(|prop| prop.syntax().text() == "parseInt") == Some(true)
+                && matches!(dot.object(), Some(Expr::NameRef(obj)) if obj.syntax().text() == "Number")
+        }
+        _ => false,
+    }
+}
+
+/// Get the names of every identifier used as a reference in a tree, such as `foo` in `foo.bar()`.
+///
+/// Declarations such as `let foo`, property names such as `bar` in `foo.bar` or `{ bar: 1 }`, and labels
+/// are not references. Shorthand properties such as `{ foo }` and local exports such as `export { foo }`
+/// are references.
+pub fn referenced_identifiers(root: &SyntaxNode) -> BTreeSet<std::string::String> {
+    root.descendants()
+        .filter_map(|node| match node.kind() {
+            NAME_REF => Some(node),
+            IDENT_PROP => node.child_with_kind(NAME),
+            SPECIFIER => {
+                let export = node
+                    .parent()
+                    .filter(|parent| parent.kind() == EXPORT_NAMED)?;
+                if export.token_with_kind(T![from]).is_some() {
+                    return None;
+                }
+                node.child_with_kind(NAME)
+            }
+            _ => None,
+        })
+        .map(|node| node.trimmed_text().to_string())
+        .collect()
+}
+
+/// Collect the binding names declared or assigned by a pattern, such as `a` and `b` in `{ a, b: [b] }`.
+pub fn pattern_names(pattern: Pattern, names: &mut Vec<SyntaxNode>) {
+    match pattern {
+        Pattern::SinglePattern(single) => {
+            if let Some(name) = single.name() {
+                names.push(name.syntax().clone());
+            }
+        }
+        Pattern::RestPattern(rest) => {
+            if let Some(pat) = rest.pat() {
+                pattern_names(pat, names);
+            }
+        }
+        Pattern::AssignPattern(assign) => {
+            if let Some(key) = assign.key() {
+                pattern_names(key, names);
+            } else if let Some(name) = assign.syntax().child_with_kind(NAME) {
+                // a default parameter such as `a = 1` has a name instead of a single pattern
+                names.push(name);
+            }
+        }
+        Pattern::ArrayPattern(array) => {
+            for elem in array.elements() {
+                pattern_names(elem, names);
+            }
+        }
+        Pattern::ObjectPattern(object) => {
+            for prop in object.elements() {
+                match prop {
+                    ObjectPatternProp::AssignPattern(assign) => {
+                        pattern_names(Pattern::AssignPattern(assign), names)
+                    }
+                    ObjectPatternProp::RestPattern(rest) => {
+                        pattern_names(Pattern::RestPattern(rest), names)
+                    }
+                    ObjectPatternProp::SinglePattern(single) => {
+                        pattern_names(Pattern::SinglePattern(single), names)
+                    }
+                    ObjectPatternProp::KeyValuePattern(kv) => {
+                        if let Some(pat) = kv.syntax().children().find_map(|n| n.try_to()) {
+                            pattern_names(pat, names);
+                        }
+                    }
+                }
+            }
+        }
+        Pattern::ExprPattern(expr) => {
+            if let Some(name) = expr.expr().and_then(unwrap_name_ref) {
+                names.push(name);
+            }
+        }
+    }
+}
+
+/// Get the name reference of an expression, ignoring any parentheses around it.
+pub fn unwrap_name_ref(mut expr: Expr) -> Option<SyntaxNode> {
+    loop {
+        match expr {
+            Expr::NameRef(name) => return Some(name.syntax().clone()),
+            Expr::GroupingExpr(group) => expr = group.inner()?,
+            _ => return None,
+        }
+    }
+}
+
+/// Replace every line terminator the lexer recognizes (`\r\n`, `\r`, `U+2028`, and `U+2029`) with `\n`.
+///
+/// Returns the normalized source and a mapping from each byte offset of the normalized source
+/// (including its end) to the byte offset in the original source. The mapping is empty if the source
+/// only uses `\n`, use [`original_offset`] to look up offsets.
+pub fn normalize_eol(source: &str) -> (Cow<'_, str>, Vec<usize>) {
+    if !source.contains(['\r', '\u{2028}', '\u{2029}']) {
+        return (Cow::Borrowed(source), vec![]);
+    }
+
+    let mut normalized = std::string::String::with_capacity(source.len());
+    let mut mapping = Vec::with_capacity(source.len() + 1);
+    let mut chars = source.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\r' | '\u{2028}' | '\u{2029}' => {
+                if c == '\r' {
+                    chars.next_if(|(_, next)| *next == '\n');
+                }
+                normalized.push('\n');
+                mapping.push(idx);
+            }
+            c => {
+                normalized.push(c);
+                mapping.extend((0..c.len_utf8()).map(|offset| idx + offset));
+            }
+        }
+    }
+    mapping.push(source.len());
+    (Cow::Owned(normalized), mapping)
+}
+
+/// Map a byte offset in a source normalized by [`normalize_eol`] back to the original source.
+pub fn original_offset(mapping: &[usize], offset: usize) -> usize {
+    mapping.get(offset).copied().unwrap_or(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rslint_parser::parse_module;
+
+    #[test]
+    fn referenced_identifiers_excludes_declarations() {
+        let root = parse_module(
+            "
+            import a from 'a';
+            let b = c + a;
+            foo: for (const d of e) { break foo; }
+            f.g = { h: i, j, [k]: 1 };
+            function l(m) { return m + n; }
+            export { l, o as p };
+            export { q } from 'r';
+            ",
+            0,
+        )
+        .syntax();
+
+        let expected = ["a", "c", "e", "f", "i", "j", "k", "l", "m", "n", "o"];
+        assert_eq!(
+            referenced_identifiers(&root),
+            expected.iter().map(|name| name.to_string()).collect()
+        );
+    }
+
+    #[test]
+    fn normalize_eol_maps_offsets_to_the_original_source() {
+        let source = "a\r\nb\nc\u{2028}d\re";
+        let (normalized, mapping) = normalize_eol(source);
+        assert_eq!(normalized, "a\nb\nc\nd\ne");
+        assert_eq!(mapping.len(), normalized.len() + 1);
+
+        for (idx, c) in normalized.char_indices() {
+            let original = original_offset(&mapping, idx);
+            if c == '\n' {
+                assert!(rslint_lexer::is_linebreak(
+                    source[original..].chars().next().unwrap()
+                ));
+            } else {
+                assert_eq!(source[original..].chars().next(), Some(c));
+            }
+        }
+        assert_eq!(
+            original_offset(&mapping, normalized.find('e').unwrap()),
+            source.len() - 1
+        );
+        assert_eq!(original_offset(&mapping, normalized.len()), source.len());
+
+        let (unchanged, mapping) = normalize_eol("a\nb");
+        assert!(matches!(unchanged, Cow::Borrowed("a\nb")));
+        assert_eq!(original_offset(&mapping, 2), 2);
+    }
+
+    #[test]
+    fn kebab_and_pascal_round_trip() {
+        let names = [
+            ("no-empty", "NoEmpty"),
+            ("no-duplicate-cases", "NoDuplicateCases"),
+            ("radix", "Radix"),
+            ("no-unsafe-optional-chaining", "NoUnsafeOptionalChaining"),
+        ];
+        for (kebab, pascal) in names.iter() {
+            assert_eq!(kebab_to_pascal(kebab), *pascal);
+            assert_eq!(pascal_to_kebab(pascal), *kebab);
+            assert_eq!(pascal_to_kebab(&kebab_to_pascal(kebab)), *kebab);
+        }
+        assert_eq!(kebab_to_pascal(""), "");
+        assert_eq!(pascal_to_kebab(""), "");
+    }
+}
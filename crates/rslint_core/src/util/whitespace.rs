@@ -0,0 +1,144 @@
+//! Classification of whitespace for rules which care about the kind of whitespace used.
+
+use crate::rule_prelude::*;
+use rslint_lexer::is_linebreak;
+use rslint_parser::{TextRange, TextSize};
+
+/// The kind of a single whitespace character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WhitespaceKind {
+    /// A regular space (`U+0020`).
+    Space,
+    /// A horizontal tab (`\t`).
+    Tab,
+    /// A line terminator (`\n`, `\r`, `U+2028`, or `U+2029`).
+    Linebreak,
+    /// A no-break space (`U+00A0`, `U+202F`, or `U+FEFF`).
+    Nbsp,
+    /// Any other unicode whitespace, such as `\v` or `U+2003` (Em Space).
+    Other,
+}
+
+/// Classify a character, returning `None` if it is not whitespace according to ECMAScript.
+pub fn classify(c: char) -> Option<WhitespaceKind> {
+    Some(match c {
+        ' ' => WhitespaceKind::Space,
+        '\t' => WhitespaceKind::Tab,
+        c if is_linebreak(c) => WhitespaceKind::Linebreak,
+        '\u{00A0}' | '\u{202F}' | '\u{FEFF}' => WhitespaceKind::Nbsp,
+        // the lexer also accepts zero width spaces as whitespace, next line is not whitespace in ECMAScript
+        c if (c.is_whitespace() && c != '\u{0085}') || c == '\u{200B}' => WhitespaceKind::Other,
+        _ => return None,
+    })
+}
+
+/// A run of whitespace characters of the same kind inside of a whitespace token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WhitespaceRun {
+    pub kind: WhitespaceKind,
+    pub range: TextRange,
+    /// Whether this run is part of the indentation of a line, that is, it is only preceded
+    /// by whitespace on its line.
+    pub indentation: bool,
+}
+
+/// Split a whitespace token into runs of whitespace of the same kind.
+///
+/// Returns an empty list if the token is not a whitespace token.
+pub fn runs(token: &SyntaxToken) -> Vec<WhitespaceRun> {
+    if token.kind() != SyntaxKind::WHITESPACE {
+        return vec![];
+    }
+
+    let start = token.text_range().start();
+    let mut indentation = token.prev_token().is_none();
+    let mut runs: Vec<WhitespaceRun> = vec![];
+
+    for (idx, c) in token.text().char_indices() {
+        let kind = match classify(c) {
+            Some(kind) => kind,
+            None => continue,
+        };
+        let range = TextRange::at(start + TextSize::from(idx as u32), TextSize::of(c));
+
+        match runs.last_mut() {
+            Some(run) if run.kind == kind => run.range = run.range.cover(range),
+            _ => runs.push(WhitespaceRun {
+                kind,
+                range,
+                indentation: indentation && kind != WhitespaceKind::Linebreak,
+            }),
+        }
+        if kind == WhitespaceKind::Linebreak {
+            indentation = true;
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use WhitespaceKind::*;
+
+    #[test]
+    fn classify_chars() {
+        let expected = [
+            (' ', Space),
+            ('\t', Tab),
+            ('\n', Linebreak),
+            ('\r', Linebreak),
+            ('\u{2028}', Linebreak),
+            ('\u{2029}', Linebreak),
+            ('\u{00A0}', Nbsp),
+            ('\u{202F}', Nbsp),
+            ('\u{FEFF}', Nbsp),
+            ('\u{000B}', Other),
+            ('\u{000C}', Other),
+            ('\u{1680}', Other),
+            ('\u{2000}', Other),
+            ('\u{2001}', Other),
+            ('\u{2002}', Other),
+            ('\u{2003}', Other),
+            ('\u{2004}', Other),
+            ('\u{2005}', Other),
+            ('\u{2006}', Other),
+            ('\u{2007}', Other),
+            ('\u{2008}', Other),
+            ('\u{2009}', Other),
+            ('\u{200A}', Other),
+            ('\u{200B}', Other),
+            ('\u{205F}', Other),
+            ('\u{3000}', Other),
+        ];
+        for (c, kind) in expected.iter() {
+            assert_eq!(classify(*c), Some(*kind), "{:?}", c);
+        }
+        assert_eq!(classify('a'), None);
+        assert_eq!(classify('\u{0085}'), None);
+    }
+
+    #[test]
+    fn whitespace_runs() {
+        let root = rslint_parser::parse_module("a;  \n\t  b;", 0).syntax();
+        let token = root
+            .descendants_with_tokens()
+            .filter_map(|elem| elem.into_token())
+            .find(|tok| tok.kind() == SyntaxKind::WHITESPACE)
+            .unwrap();
+
+        let runs = runs(&token)
+            .into_iter()
+            .map(|run| (run.kind, usize::from(run.range.len()), run.indentation))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            runs,
+            vec![
+                (Space, 2, false),
+                (Linebreak, 1, false),
+                (Tab, 1, true),
+                (Space, 2, true)
+            ]
+        );
+    }
+}
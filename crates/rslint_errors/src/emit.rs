@@ -6,7 +6,7 @@ use crate::codespan::diagnostic::{Diagnostic as CodespanDiag, Label, LabelStyle,
 use crate::codespan::files::Error;
 use crate::codespan::term::{
     emit,
-    termcolor::{ColorChoice, StandardStream, WriteColor},
+    termcolor::{ColorChoice, NoColor, StandardStream, WriteColor},
     *,
 };
 use crate::{
@@ -268,3 +268,35 @@ impl Emitter<'_> {
         writer.write(b"\n").map(|_| ()).map_err(Error::Io)
     }
 }
+
+/// Render a single diagnostic to a string without colors.
+///
+/// This is useful for logging and tests, which would otherwise have to set up a writer for a single
+/// diagnostic. If the diagnostic cannot be rendered, for example because its file is not in `files`,
+/// the error is rendered instead.
+pub fn render_diagnostic(diagnostic: &Diagnostic, files: &dyn Files) -> String {
+    let mut writer = NoColor::new(vec![]);
+    if let Err(err) = Emitter::new(files).emit_with_writer(diagnostic, &mut writer) {
+        return format!("failed to render diagnostic: {}", err);
+    }
+    String::from_utf8_lossy(&writer.into_inner()).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::SimpleFiles;
+
+    #[test]
+    fn render_diagnostic_includes_message_and_line() {
+        let mut files = SimpleFiles::new();
+        let id = files.add("foo.js".to_string(), "let a = 5;\nlet b = a;\n".to_string());
+        let diagnostic = Diagnostic::error(id, "no-foo", "`b` is bad").primary(15usize..16, "here");
+
+        let rendered = render_diagnostic(&diagnostic, &files);
+        assert!(rendered.contains("error[no-foo]: `b` is bad"));
+        assert!(rendered.contains("foo.js:2:5"));
+        assert!(rendered.contains("let b = a;"));
+        assert!(!rendered.contains('\u{1b}'));
+    }
+}
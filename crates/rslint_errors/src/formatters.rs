@@ -29,6 +29,39 @@ pub trait Formatter {
         files: &dyn Files,
         writer: &mut dyn WriteColor,
     ) -> io::Result<()>;
+
+    /// Emit at most `max_rendered` diagnostics to the writer, followed by a note with the amount
+    /// of diagnostics which were not rendered. All diagnostics are rendered if `max_rendered` is `None`.
+    ///
+    /// This is useful for files with hundreds of problems, where rendering every diagnostic
+    /// would flood the terminal. Returns the total amount of diagnostics.
+    fn emit_limited(
+        &mut self,
+        diagnostics: &[Diagnostic],
+        files: &dyn Files,
+        writer: &mut dyn WriteColor,
+        max_rendered: Option<usize>,
+    ) -> io::Result<usize> {
+        let limit = max_rendered.unwrap_or(diagnostics.len());
+        if diagnostics.len() <= limit {
+            self.emit_with_writer(diagnostics, files, writer)?;
+            return Ok(diagnostics.len());
+        }
+
+        self.emit_with_writer(&diagnostics[..limit], files, writer)?;
+        let remaining = diagnostics.len() - limit;
+        writeln!(
+            writer,
+            "… and {} more {}",
+            remaining,
+            if remaining == 1 {
+                "diagnostic"
+            } else {
+                "diagnostics"
+            }
+        )?;
+        Ok(diagnostics.len())
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -151,3 +184,42 @@ impl Formatter for LongFormatter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::SimpleFile;
+    use crate::termcolor::NoColor;
+
+    fn render(diagnostics: &[Diagnostic], max_rendered: Option<usize>) -> (String, usize) {
+        let file = SimpleFile::new("foo.js".to_string(), "let a = 5;\nlet b = 6;\n".to_string());
+        let mut writer = NoColor::new(vec![]);
+        let total = ShortFormatter
+            .emit_limited(diagnostics, &file, &mut writer, max_rendered)
+            .unwrap();
+        (String::from_utf8(writer.into_inner()).unwrap(), total)
+    }
+
+    #[test]
+    fn emit_limited_caps_rendered_diagnostics() {
+        let diagnostics = (0usize..5)
+            .map(|i| Diagnostic::error(0, "foo", format!("problem {}", i)).primary(i..i + 1, ""))
+            .collect::<Vec<_>>();
+
+        let (out, total) = render(&diagnostics, Some(2));
+        assert_eq!(total, 5);
+        assert!(out.contains("problem 0"));
+        assert!(out.contains("problem 1"));
+        assert!(!out.contains("problem 2"));
+        assert!(out.ends_with("… and 3 more diagnostics\n"));
+
+        let (out, total) = render(&diagnostics, Some(4));
+        assert_eq!(total, 5);
+        assert!(out.ends_with("… and 1 more diagnostic\n"));
+
+        let (out, total) = render(&diagnostics, None);
+        assert_eq!(total, 5);
+        assert!(out.contains("problem 4"));
+        assert!(!out.contains("more diagnostic"));
+    }
+}
@@ -11,7 +11,7 @@ mod formatters;
 mod suggestion;
 
 pub use diagnostic::{Diagnostic, SubDiagnostic};
-pub use emit::Emitter;
+pub use emit::{render_diagnostic, Emitter};
 pub use file::Span;
 pub use formatters::*;
 pub use suggestion::*;
@@ -208,17 +208,36 @@ impl<'src> Lexer<'src> {
         let start = self.cur + 1;
         self.read_hexnumber();
 
+        if self.cur >= self.bytes.len() {
+            let err = Diagnostic::error(self.file_id, "", "unterminated unicode code point escape")
+                .primary(start - 3..self.cur, "")
+                .footer_help("add a `}` after the hex digits of the code point");
+
+            return Err(err);
+        }
+
         if self.bytes.get(self.cur) != Some(&b'}') {
             // We should not yield diagnostics on a unicode char boundary. That wont make codespan panic
             // but it may cause a panic for other crates which just consume the diagnostics
             let invalid = self.get_unicode_char();
             let err = Diagnostic::error(self.file_id, "", "expected hex digits for a unicode code point escape, but encountered an invalid character")
-                .primary(self.cur .. invalid.len_utf8(), "");
+                .primary(self.cur..self.cur + invalid.len_utf8(), "");
 
             self.cur -= 1;
             return Err(err);
         }
 
+        if start == self.cur {
+            let err = Diagnostic::error(
+                self.file_id,
+                "",
+                "expected hex digits for a unicode code point escape, but found none",
+            )
+            .primary(start - 3..self.cur + 1, "");
+
+            return Err(err);
+        }
+
         // Safety: We know for a fact this is in bounds because we must be on the possible char after the } at this point
         // which means its impossible for the range of the digits to be out of bounds.
         // We also know we cant possibly be indexing a unicode char boundary because a unicode char (which cant be a hexdigit)
@@ -666,6 +685,17 @@ impl<'src> Lexer<'src> {
                     }
                 }
             }
+            // `0_1` is not allowed, a numeric separator cannot follow a leading zero
+            Some(b'_') => {
+                let diag = Diagnostic::error(
+                    self.file_id,
+                    "",
+                    "numeric separators are not allowed after a leading `0`",
+                )
+                .primary(self.cur + 1..self.cur + 2, "");
+                self.read_number();
+                Some(diag)
+            }
             // FIXME: many engines actually allow things like `09`, but by the spec, this is not allowed
             // maybe we should not allow it if we want to go fully by the spec
             _ => self.read_number(),
@@ -734,7 +764,7 @@ impl<'src> Lexer<'src> {
                 Some(b'_') => diag = diag.or(self.handle_numeric_separator(10)),
                 Some(b'0'..=b'9') => {},
                 Some(b'.') => {
-                    return self.read_float();
+                    return self.read_float().or(diag);
                 },
                 // TODO: merge this, and read_float's implementation into one so we dont duplicate exponent code
                 Some(b'e') | Some(b'E') => {
@@ -743,12 +773,12 @@ impl<'src> Lexer<'src> {
                         Some(b'-') | Some(b'+') => {
                             if let Some(b'0'..=b'9') = self.bytes.get(self.cur + 2) {
                                 self.next();
-                                return self.read_exponent();
+                                return self.read_exponent().or(diag);
                             } else {
                                 return diag;
                             }
                         },
-                        Some(b'0'..=b'9') => return self.read_exponent(),
+                        Some(b'0'..=b'9') => return self.read_exponent().or(diag),
                         _ => return diag,
                     }
                 },
@@ -767,7 +797,7 @@ impl<'src> Lexer<'src> {
 
         unwind_loop! {
             match self.next_bounded() {
-                Some(b'_') => diag = diag.or(self.handle_numeric_separator(16)),
+                Some(b'_') => diag = diag.or(self.handle_numeric_separator(10)),
                 // LLVM has a hard time optimizing inclusive patterns, perhaps we should check if it makes llvm sad,
                 // and optimize this into a lookup table
                 Some(b'0'..=b'9') => {},
@@ -836,12 +866,23 @@ impl<'src> Lexer<'src> {
         let err_start = self.cur;
         if self.cur < self.bytes.len() && self.cur_is_ident_start() {
             self.consume_ident();
-            let err = Diagnostic::error(
-                self.file_id,
-                "",
-                "numbers cannot be followed by identifiers directly after",
-            )
-            .primary(err_start..self.cur, "an identifier cannot appear here");
+            let number = &self.bytes[start..err_start];
+            // integers consume the `n` themselves, so this is a float or exponent like `1.5n` or `1e3n`
+            let is_float = !number.iter().any(|b| matches!(b, b'x' | b'X'))
+                && number.iter().any(|b| matches!(b, b'.' | b'e' | b'E'));
+            let err = if is_float && &self.bytes[err_start..self.cur] == b"n" {
+                Diagnostic::error(self.file_id, "", "BigInt literals must be integers").primary(
+                    start..self.cur,
+                    "a BigInt cannot have a decimal point or an exponent",
+                )
+            } else {
+                Diagnostic::error(
+                    self.file_id,
+                    "",
+                    "numbers cannot be followed by identifiers directly after",
+                )
+                .primary(err_start..self.cur, "an identifier cannot appear here")
+            };
 
             (
                 Token::new(SyntaxKind::ERROR_TOKEN, self.cur - start),
@@ -1275,7 +1316,12 @@ impl<'src> Lexer<'src> {
                     self.next();
                     let res = if self.bytes.get(self.cur + 1).copied() == Some(b'{') {
                         self.next();
-                        self.read_codepoint_escape()
+                        let res = self.read_codepoint_escape();
+                        // the `}` of an invalid code point is part of the error token
+                        if res.is_err() && self.bytes.get(self.cur) == Some(&b'}') {
+                            self.next();
+                        }
+                        res
                     } else {
                         self.read_unicode_escape(true)
                     };
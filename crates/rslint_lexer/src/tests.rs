@@ -1,7 +1,7 @@
 #![cfg(test)]
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
-use crate::Lexer;
+use crate::{Lexer, SyntaxKind};
 use quickcheck_macros::quickcheck;
 use std::sync::mpsc::channel;
 use std::thread;
@@ -723,6 +723,83 @@ fn number_basic_err() {
     }
 }
 
+#[test]
+fn numeric_separators() {
+    assert_lex! {
+        "1_000",
+        NUMBER:5
+    }
+
+    assert_lex! {
+        "0xFF_FF",
+        NUMBER:7
+    }
+
+    assert_lex! {
+        "0b1010_0101 0o7_7",
+        NUMBER:11,
+        WHITESPACE:1,
+        NUMBER:5
+    }
+
+    assert_lex! {
+        "1_000.5_5e1_0",
+        NUMBER:13
+    }
+
+    assert_lex! {
+        "1_000n",
+        NUMBER:6
+    }
+
+    // `_1` is an identifier, not a number
+    assert_lex! {
+        "_1",
+        IDENT:2
+    }
+
+    for src in &[
+        "1_000", "0xFF_FF", "0b1_0", "0o7_7", "1.5_5", "1e1_0", "1_0n",
+    ] {
+        let tokens = Lexer::from_str(src, 0).collect::<Vec<_>>();
+        assert!(
+            tokens.iter().all(|tok| tok.1.is_none()),
+            "`{}` should not have errors",
+            src
+        );
+    }
+}
+
+#[test]
+fn numeric_separators_err() {
+    assert_lex! {
+        "1__0",
+        NUMBER:4
+    }
+
+    assert_lex! {
+        "1_",
+        NUMBER:2
+    }
+
+    assert_lex! {
+        "0_1",
+        NUMBER:3
+    }
+
+    assert_lex! {
+        "0x_1",
+        ERROR_TOKEN:4
+    }
+
+    for src in &[
+        "1__0", "1_", "0_1", "0x_1", "0xF__F", "0b1_", "1._5", "1_.5", "1e_1",
+    ] {
+        let tokens = Lexer::from_str(src, 0).collect::<Vec<_>>();
+        assert!(tokens[0].1.is_some(), "`{}` should have an error", src);
+    }
+}
+
 #[test]
 fn number_complex() {
     assert_lex! {
@@ -797,6 +874,46 @@ fn bigint_literals() {
         WHITESPACE:1,
         NUMBER:2
     }
+
+    assert_lex! {
+        "0xffn 0o17n 0b101n 1_000n",
+        NUMBER:5,
+        WHITESPACE:1,
+        NUMBER:5,
+        WHITESPACE:1,
+        NUMBER:6,
+        WHITESPACE:1,
+        NUMBER:6
+    }
+}
+
+#[test]
+fn bigint_literals_err() {
+    assert_lex! {
+        "1.5n",
+        ERROR_TOKEN:4
+    }
+
+    assert_lex! {
+        "1e3n",
+        ERROR_TOKEN:4
+    }
+
+    for src in &["1.5n", "1e3n", ".5n", "1.n"] {
+        let tokens = Lexer::from_str(src, 0).collect::<Vec<_>>();
+        assert_eq!(
+            tokens[0].1.as_ref().map(|diag| diag.title.as_str()),
+            Some("BigInt literals must be integers"),
+            "`{}` should be an invalid BigInt",
+            src
+        );
+    }
+
+    let tokens = Lexer::from_str("1nn", 0).collect::<Vec<_>>();
+    assert_eq!(
+        tokens[0].1.as_ref().map(|diag| diag.title.as_str()),
+        Some("numbers cannot be followed by identifiers directly after")
+    );
 }
 
 #[test]
@@ -899,6 +1016,47 @@ fn division() {
     }
 }
 
+#[test]
+fn exponentiation() {
+    assert_lex! {
+        "a ** b",
+        IDENT:1,
+        WHITESPACE:1,
+        STAR2:2,
+        WHITESPACE:1,
+        IDENT:1
+    }
+}
+
+#[test]
+fn exponentiation_assign() {
+    assert_lex! {
+        "a **= b",
+        IDENT:1,
+        WHITESPACE:1,
+        STAR2EQ:3,
+        WHITESPACE:1,
+        IDENT:1
+    }
+}
+
+#[test]
+fn spaced_stars() {
+    assert_lex! {
+        "a * *b *= c",
+        IDENT:1,
+        WHITESPACE:1,
+        STAR:1,
+        WHITESPACE:1,
+        STAR:1,
+        IDENT:1,
+        WHITESPACE:1,
+        STAREQ:2,
+        WHITESPACE:1,
+        IDENT:1
+    }
+}
+
 #[test]
 fn template_escape() {
     assert_lex! {
@@ -1048,3 +1206,206 @@ fn object_expr_getter() {
         R_PAREN:1
     }
 }
+
+#[test]
+fn optional_chaining() {
+    assert_lex! {
+        "a?.b",
+        IDENT:1,
+        QUESTIONDOT:2,
+        IDENT:1
+    }
+
+    assert_lex! {
+        "a?.[b]?.()",
+        IDENT:1,
+        QUESTIONDOT:2,
+        L_BRACK:1,
+        IDENT:1,
+        R_BRACK:1,
+        QUESTIONDOT:2,
+        L_PAREN:1,
+        R_PAREN:1
+    }
+}
+
+#[test]
+fn optional_chaining_ambiguity_with_number() {
+    assert_lex! {
+        "a?.5:b",
+        IDENT:1,
+        QUESTION:1,
+        NUMBER:2,
+        COLON:1,
+        IDENT:1
+    }
+
+    assert_lex! {
+        "a ? .5 : b",
+        IDENT:1,
+        WHITESPACE:1,
+        QUESTION:1,
+        WHITESPACE:1,
+        NUMBER:2,
+        WHITESPACE:1,
+        COLON:1,
+        WHITESPACE:1,
+        IDENT:1
+    }
+}
+
+#[test]
+fn nullish_coalescing() {
+    assert_lex! {
+        "a ?? b",
+        IDENT:1,
+        WHITESPACE:1,
+        QUESTION2:2,
+        WHITESPACE:1,
+        IDENT:1
+    }
+
+    assert_lex! {
+        "a ??= b",
+        IDENT:1,
+        WHITESPACE:1,
+        QUESTION2EQ:3,
+        WHITESPACE:1,
+        IDENT:1
+    }
+}
+
+// Invalid input must never make the lexer stop, the error is attached to a token and lexing continues
+#[test]
+fn unterminated_template_recovery() {
+    let tokens = Lexer::from_str("`${a} b", 0).collect::<Vec<_>>();
+    let kinds = tokens.iter().map(|tok| tok.0.kind).collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::BACKTICK,
+            SyntaxKind::DOLLARCURLY,
+            SyntaxKind::IDENT,
+            SyntaxKind::R_CURLY,
+            SyntaxKind::TEMPLATE_CHUNK,
+            SyntaxKind::EOF
+        ]
+    );
+    assert!(tokens[4].1.is_some());
+    assert!(tokens[..4].iter().all(|tok| tok.1.is_none()));
+}
+
+#[test]
+fn invalid_regex_flag_recovery() {
+    let tokens = Lexer::from_str("/a/gz; foo", 0).collect::<Vec<_>>();
+    let kinds = tokens.iter().map(|tok| tok.0.kind).collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::REGEX,
+            SyntaxKind::SEMICOLON,
+            SyntaxKind::WHITESPACE,
+            SyntaxKind::IDENT,
+            SyntaxKind::EOF
+        ]
+    );
+    assert_eq!(tokens[0].0.len, 5);
+    assert!(tokens[0].1.is_some());
+    assert!(tokens[1..].iter().all(|tok| tok.1.is_none()));
+}
+
+#[test]
+fn invalid_char_recovery() {
+    let tokens = Lexer::from_str("a \u{0001} b", 0).collect::<Vec<_>>();
+    let kinds = tokens.iter().map(|tok| tok.0.kind).collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::IDENT,
+            SyntaxKind::WHITESPACE,
+            SyntaxKind::ERROR_TOKEN,
+            SyntaxKind::WHITESPACE,
+            SyntaxKind::IDENT,
+            SyntaxKind::EOF
+        ]
+    );
+    assert!(tokens[2].1.is_some());
+}
+
+#[test]
+fn unicode_escape_identifiers() {
+    assert_lex! {
+        r"\u0061",
+        IDENT:6
+    }
+
+    assert_lex! {
+        r"\u{61} a\u{62}c",
+        IDENT:6,
+        WHITESPACE:1,
+        IDENT:8
+    }
+
+    assert_lex! {
+        r"a\u0062",
+        IDENT:7
+    }
+
+    // escapes are decoded before checking for keywords
+    assert_lex! {
+        r"\u0069f",
+        IF_KW:7
+    }
+
+    let escaped = Lexer::from_str(r"\u0061 = 5", 0).collect::<Vec<_>>();
+    let plain = Lexer::from_str("a = 5", 0).collect::<Vec<_>>();
+    assert_eq!(
+        escaped.iter().map(|tok| tok.0.kind).collect::<Vec<_>>(),
+        plain.iter().map(|tok| tok.0.kind).collect::<Vec<_>>()
+    );
+    assert!(escaped.iter().all(|tok| tok.1.is_none()));
+}
+
+#[test]
+fn unicode_escape_identifiers_err() {
+    let cases = [
+        (r"\u{61", "unterminated unicode code point escape"),
+        (
+            r"\u{}",
+            "expected hex digits for a unicode code point escape, but found none",
+        ),
+        (
+            r"\u{110000}",
+            "out of bounds codepoint for unicode codepoint escape sequence",
+        ),
+        (r"\u00", "invalid digits after unicode escape sequence"),
+        (r#""\u{61""#, "expected hex digits for a unicode code point escape, but encountered an invalid character"),
+    ];
+    for (src, title) in &cases {
+        let tokens = Lexer::from_str(src, 0).collect::<Vec<_>>();
+        assert_eq!(
+            tokens[0].1.as_ref().map(|diag| diag.title.as_str()),
+            Some(*title),
+            "`{}` should be an invalid escape",
+            src
+        );
+    }
+
+    assert_lex! {
+        r"\u{110000}",
+        ERROR_TOKEN:10
+    }
+
+    assert_lex! {
+        r"\u{61",
+        ERROR_TOKEN:5
+    }
+
+    assert_lex! {
+        r#""\u{61}""#,
+        STRING:8
+    }
+}
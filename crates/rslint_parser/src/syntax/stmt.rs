@@ -34,6 +34,9 @@ pub const STMT_RECOVERY_SET: TokenSet = token_set![
 
 pub const FOLLOWS_LET: TokenSet = token_set![T!['{'], T!['['], T![ident], T![yield], T![await]];
 
+/// The code of the error emitted for a statement which is not ended by an explicit or implicit semicolon.
+pub const MISSING_SEMICOLON: &str = "MissingSemicolon";
+
 /// Consume an explicit semicolon, or try to automatically insert one,
 /// or add an error to the parser if there was none and it could not be inserted
 // test semicolons
@@ -50,15 +53,16 @@ pub fn semi(p: &mut Parser, err_range: Range<usize>) {
         return;
     }
     if !p.has_linebreak_before_n(0) {
-        let err = p
-            .err_builder(
-                "Expected a semicolon or an implicit semicolon after a statement, but found none",
-            )
-            .primary(
-                p.cur_tok().range,
-                "An explicit or implicit semicolon is expected here...",
-            )
-            .secondary(err_range, "...Which is required to end this statement");
+        let err = ParserError::error(
+            p.file_id,
+            MISSING_SEMICOLON,
+            "Expected a semicolon or an implicit semicolon after a statement, but found none",
+        )
+        .primary(
+            p.cur_tok().range,
+            "An explicit or implicit semicolon is expected here...",
+        )
+        .secondary(err_range, "...Which is required to end this statement");
 
         p.error(err);
     }
@@ -359,6 +359,13 @@ pub struct Comment {
     pub token: SyntaxToken,
 }
 
+impl Comment {
+    /// Whether this is a block comment, such as `/* a */` or `/** a */`.
+    pub fn is_block(&self) -> bool {
+        self.kind != CommentKind::Inline
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CommentKind {
     /// A block comment which starts with `/**`